@@ -1,12 +1,33 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use crate::deno_config;
+use crate::validation::json_error;
+use crate::validation::validate_rules;
+use crate::validation::PathSegment;
+use anyhow::anyhow;
 use anyhow::bail;
 use anyhow::Error as AnyError;
+use deno_lint::diagnostic::LintSeverity;
+use deno_lint::file_scope::FileScope;
+use deno_lint::globals::{Environment, GlobalAccess, Globals};
+use deno_lint::linter::LintConfig;
+use deno_lint::presets::is_versioned_preset;
+use deno_lint::presets::preset_rules;
 use deno_lint::rules::get_all_rules;
-use deno_lint::rules::{filtered_rules, LintRule};
+use deno_lint::rules::{
+  filtered_configured_rules, filtered_rules, filtered_rules_by_tag_expr,
+  LintRule, LintRuleOptionsError,
+};
+use deno_lint::tags::TagExpr;
+use globset::GlobBuilder;
 use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 #[derive(Debug, Default, Deserialize)]
 #[serde(default)]
@@ -14,6 +35,13 @@ pub struct RulesConfig {
   pub tags: Vec<String>,
   pub include: Vec<String>,
   pub exclude: Vec<String>,
+  /// Options passed to the rules, keyed by rule code.
+  pub options: HashMap<String, serde_json::Value>,
+  /// Severities overriding the rules' defaults, keyed by rule code.
+  pub severity: HashMap<String, LintSeverity>,
+  /// Files the rules are run on, relative to the directory of the config,
+  /// keyed by rule code.
+  pub files: HashMap<String, FilesConfig>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -21,44 +49,493 @@ pub struct RulesConfig {
 pub struct FilesConfig {
   pub include: Vec<String>,
   pub exclude: Vec<String>,
+  /// Directory the patterns are relative to, instead of the current one.
+  #[serde(skip)]
+  pub base: Option<PathBuf>,
+}
+
+/// JSX settings, in the format of the `compilerOptions` of `deno.json`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CompilerOptionsConfig {
+  pub jsx: Option<String>,
+  pub jsx_factory: Option<String>,
+  pub jsx_fragment_factory: Option<String>,
+}
+
+/// Rule settings for the files matching `files`, relative to the directory
+/// of the config declaring them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct OverrideConfig {
+  pub files: Vec<String>,
+  pub rules: RulesConfig,
+  pub env: Option<Vec<Environment>>,
+  pub globals: BTreeMap<String, GlobalAccess>,
 }
 
 #[derive(Debug, Default, Deserialize)]
 #[serde(default)]
 pub struct Config {
+  /// Configs this one is based on, relative to its directory. Their settings
+  /// apply first, in order.
+  pub extends: Vec<String>,
+  /// Stops looking for configs in parent directories.
+  pub root: bool,
   pub rules: RulesConfig,
   pub files: FilesConfig,
+  pub overrides: Vec<OverrideConfig>,
+  #[serde(rename = "compilerOptions")]
+  pub compiler_options: CompilerOptionsConfig,
+  /// Environments whose globals are available, replacing the ones of
+  /// earlier configs. Files use the globals of Deno by default.
+  pub env: Option<Vec<Environment>>,
+  /// Globals declared in addition to the ones of the environments, merged
+  /// with the ones of earlier configs.
+  pub globals: BTreeMap<String, GlobalAccess>,
+  /// Directory of the config file, which globs are relative to.
+  #[serde(skip)]
+  pub dir: PathBuf,
 }
 
+/// Name of the config files looked up in the directories of linted files and
+/// their parents.
+pub const CONFIG_FILE_NAME: &str = "dlint.json";
+
 impl Config {
-  pub fn get_rules(&self) -> Vec<Box<dyn LintRule>> {
-    filtered_rules(
+  pub fn get_rules(
+    &self,
+  ) -> Result<Vec<Box<dyn LintRule>>, LintRuleOptionsError> {
+    let mut selection = RuleSelection::default();
+    selection.apply(&self.rules, &self.dir);
+    selection.get_rules()
+  }
+
+  pub fn get_files(&self) -> Result<Vec<PathBuf>, AnyError> {
+    resolve_file_paths(&self.files)
+  }
+}
+
+/// Rules enabled by a list of configs, along with their options and
+/// severities.
+///
+/// Each config adds the rules matching its `tags` and `include` and then
+/// removes its `exclude`d ones, so later configs can turn off rules enabled
+/// by earlier ones. Options, severities and files of later configs take
+/// precedence.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RuleSelection {
+  pub codes: BTreeSet<&'static str>,
+  pub options: BTreeMap<String, serde_json::Value>,
+  pub severity: BTreeMap<String, LintSeverity>,
+  /// Included and excluded globs of the rules, made absolute.
+  pub files: BTreeMap<String, (Vec<String>, Vec<String>)>,
+}
+
+impl RuleSelection {
+  /// Applies the rules of a config in `dir`.
+  pub fn apply(&mut self, rules: &RulesConfig, dir: &Path) {
+    let added = filtered_rules(
+      get_all_rules(),
+      Some(vec![]),
+      None,
+      Some(rules.include.clone()),
+    );
+    self.codes.extend(added.iter().map(|rule| rule.code()));
+    // Each entry of `tags` is either a versioned preset, e.g.
+    // `recommended@0.69`, which is a frozen set of rules, or a tag expression,
+    // e.g. `react & !fresh`. Invalid ones are reported by the validation of
+    // the config.
+    for tag in &rules.tags {
+      if is_versioned_preset(tag) {
+        if let Ok(codes) = preset_rules(tag) {
+          self.codes.extend(codes);
+        }
+      } else if let Ok(expr) = TagExpr::parse(tag) {
+        let added = filtered_rules_by_tag_expr(get_all_rules(), &expr);
+        self.codes.extend(added.iter().map(|rule| rule.code()));
+      }
+    }
+    self
+      .codes
+      .retain(|code| !rules.exclude.iter().any(|exclude| exclude == code));
+    self.options.extend(
+      rules
+        .options
+        .iter()
+        .map(|(code, options)| (code.clone(), options.clone())),
+    );
+    self.severity.extend(
+      rules
+        .severity
+        .iter()
+        .map(|(code, severity)| (code.clone(), *severity)),
+    );
+    let absolute = |globs: &[String]| {
+      globs
+        .iter()
+        .map(|glob| absolute_glob(dir, glob))
+        .collect::<Vec<_>>()
+    };
+    self.files.extend(rules.files.iter().map(|(code, files)| {
+      (
+        code.clone(),
+        (absolute(&files.include), absolute(&files.exclude)),
+      )
+    }));
+  }
+
+  /// The selected rules, configured with their options.
+  pub fn get_rules(
+    &self,
+  ) -> Result<Vec<Box<dyn LintRule>>, LintRuleOptionsError> {
+    filtered_configured_rules(
       get_all_rules(),
-      Some(self.rules.tags.clone()),
-      Some(self.rules.exclude.clone()),
-      Some(self.rules.include.clone()),
+      Some(vec![]),
+      None,
+      Some(self.codes.iter().map(|code| code.to_string()).collect()),
+      &self.rule_options(),
     )
   }
 
+  /// The selected rules, to be configured by `Linter::try_new` with
+  /// `RuleSelection::rule_options`.
+  pub fn rules(&self) -> Vec<Box<dyn LintRule>> {
+    let mut rules = get_all_rules();
+    rules.retain(|rule| self.codes.contains(rule.code()));
+    rules.sort_by_key(|rule| rule.code());
+    rules
+  }
+
+  pub fn rule_options(&self) -> HashMap<String, serde_json::Value> {
+    self.options.clone().into_iter().collect()
+  }
+
+  pub fn severity_overrides(&self) -> HashMap<String, LintSeverity> {
+    self.severity.clone().into_iter().collect()
+  }
+
+  pub fn file_scopes(&self) -> Result<HashMap<String, FileScope>, AnyError> {
+    self
+      .files
+      .iter()
+      .map(|(code, (include, exclude))| {
+        Ok((
+          code.clone(),
+          FileScope::new(include.clone(), exclude.clone())?,
+        ))
+      })
+      .collect()
+  }
+}
+
+fn absolute_glob(dir: &Path, glob: &str) -> String {
+  let glob = glob.strip_prefix("./").unwrap_or(glob);
+  if dir.as_os_str().is_empty() {
+    glob.to_string()
+  } else {
+    format!("{}/{}", globset::escape(&dir.to_string_lossy()), glob)
+  }
+}
+
+/// Configs that apply to a file, outermost first, with `extends` already
+/// expanded before the configs extending them.
+#[derive(Debug, Default, Clone)]
+pub struct ConfigChain {
+  configs: Vec<Arc<Config>>,
+}
+
+impl ConfigChain {
+  pub fn is_empty(&self) -> bool {
+    self.configs.is_empty()
+  }
+
+  /// Files to lint, from the last config specifying them.
   pub fn get_files(&self) -> Result<Vec<PathBuf>, AnyError> {
-    resolve_file_paths(&self.files)
+    match self
+      .configs
+      .iter()
+      .rev()
+      .find(|config| !config.files.include.is_empty())
+    {
+      Some(config) => config.get_files(),
+      None => Ok(Vec::new()),
+    }
+  }
+
+  /// Appends the configs of `other`, which take precedence.
+  pub fn extend(&mut self, other: &ConfigChain) {
+    self.configs.extend(other.configs.iter().cloned());
+  }
+
+  /// JSX factories and globals of `file_path`, from the last configs and
+  /// overrides setting them.
+  pub fn lint_config(&self, file_path: &Path) -> Result<LintConfig, AnyError> {
+    let mut jsx = None;
+    let mut jsx_factory = None;
+    let mut jsx_fragment_factory = None;
+    let mut globals = Globals::default();
+    for config in &self.configs {
+      let options = &config.compiler_options;
+      jsx = options.jsx.as_deref().or(jsx);
+      jsx_factory = options.jsx_factory.as_deref().or(jsx_factory);
+      jsx_fragment_factory = options
+        .jsx_fragment_factory
+        .as_deref()
+        .or(jsx_fragment_factory);
+      apply_globals(&mut globals, &config.env, &config.globals);
+      for override_config in matching_overrides(config, file_path)? {
+        apply_globals(
+          &mut globals,
+          &override_config.env,
+          &override_config.globals,
+        );
+      }
+    }
+    Ok(match jsx {
+      // The classic runtime, which is the default.
+      None | Some("react") => LintConfig {
+        default_jsx_factory: Some(
+          jsx_factory.unwrap_or("React.createElement").to_string(),
+        ),
+        default_jsx_fragment_factory: Some(
+          jsx_fragment_factory.unwrap_or("React.Fragment").to_string(),
+        ),
+        globals,
+      },
+      Some(_) => LintConfig {
+        default_jsx_factory: None,
+        default_jsx_fragment_factory: None,
+        globals,
+      },
+    })
+  }
+
+  /// Resolves the rules of `file_path`, applying the overrides matching it.
+  pub fn resolve(&self, file_path: &Path) -> Result<RuleSelection, AnyError> {
+    let mut selection = RuleSelection::default();
+    for config in &self.configs {
+      selection.apply(&config.rules, &config.dir);
+      for override_config in matching_overrides(config, file_path)? {
+        selection.apply(&override_config.rules, &config.dir);
+      }
+    }
+    Ok(selection)
+  }
+}
+
+/// Overrides of `config` whose `files` match `file_path`, in order.
+fn matching_overrides<'a>(
+  config: &'a Config,
+  file_path: &Path,
+) -> Result<Vec<&'a OverrideConfig>, AnyError> {
+  // Directories of configs are canonicalized.
+  let file_path = file_path
+    .canonicalize()
+    .unwrap_or_else(|_| file_path.to_path_buf());
+  let Ok(relative_path) = file_path.strip_prefix(&config.dir) else {
+    return Ok(Vec::new());
+  };
+  let mut overrides = Vec::new();
+  for override_config in &config.overrides {
+    if matches_any(&override_config.files, relative_path)? {
+      overrides.push(override_config);
+    }
+  }
+  Ok(overrides)
+}
+
+fn apply_globals(
+  globals: &mut Globals,
+  env: &Option<Vec<Environment>>,
+  extra: &BTreeMap<String, GlobalAccess>,
+) {
+  if let Some(env) = env {
+    globals.environments.clone_from(env);
   }
+  globals
+    .extra
+    .extend(extra.iter().map(|(name, access)| (name.clone(), *access)));
 }
 
-pub fn load_from_json(config_path: &Path) -> Result<Config, std::io::Error> {
+fn matches_any(patterns: &[String], path: &Path) -> Result<bool, AnyError> {
+  for pattern in patterns {
+    let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+    let matcher =
+      match GlobBuilder::new(pattern).literal_separator(true).build() {
+        Ok(glob) => glob.compile_matcher(),
+        Err(err) => bail!("Invalid glob \"{}\": {}", pattern, err),
+      };
+    if matcher.is_match(path) {
+      return Ok(true);
+    }
+  }
+  Ok(false)
+}
+
+impl From<Config> for ConfigChain {
+  fn from(config: Config) -> Self {
+    ConfigChain {
+      configs: vec![Arc::new(config)],
+    }
+  }
+}
+
+/// Loads a dlint config, or the lint settings of a `deno.json(c)` file.
+///
+/// The config is validated against the known rules and tags, along with
+/// `extra_rule_codes`, e.g. of plugins.
+pub fn load_config(
+  config_path: &Path,
+  extra_rule_codes: &HashSet<&str>,
+) -> Result<Config, AnyError> {
+  if deno_config::is_deno_config(config_path) {
+    deno_config::load_from_deno_json(config_path, extra_rule_codes)
+  } else {
+    load_from_json(config_path, extra_rule_codes)
+  }
+}
+
+pub fn load_from_json(
+  config_path: &Path,
+  extra_rule_codes: &HashSet<&str>,
+) -> Result<Config, AnyError> {
   let json_str = std::fs::read_to_string(config_path)?;
-  let config: Config = serde_json::from_str(&json_str)?;
+  let value: serde_json::Value = serde_json::from_str(&json_str)
+    .map_err(|err| json_error(config_path, err))?;
+  let override_count = value
+    .get("overrides")
+    .and_then(|overrides| overrides.as_array())
+    .map_or(0, |overrides| overrides.len());
+  let rules_paths = std::iter::once(vec![PathSegment::Key("rules".into())])
+    .chain((0..override_count).map(|i| {
+      vec![
+        PathSegment::Key("overrides".into()),
+        PathSegment::Index(i),
+        PathSegment::Key("rules".into()),
+      ]
+    }))
+    .collect::<Vec<_>>();
+  validate_rules(
+    config_path,
+    &json_str,
+    &value,
+    &rules_paths,
+    extra_rule_codes,
+  )?;
+
+  let mut config: Config = serde_json::from_str(&json_str)
+    .map_err(|err| json_error(config_path, err))?;
+  config.dir = config_path
+    .parent()
+    .map(Path::to_path_buf)
+    .unwrap_or_default();
   Ok(config)
 }
 
+/// Loads a config file along with the configs it extends.
+pub fn load_chain(
+  config_path: &Path,
+  extra_rule_codes: &HashSet<&str>,
+) -> Result<ConfigChain, AnyError> {
+  let mut chain = ConfigChain::default();
+  load_extended(config_path, extra_rule_codes, &mut Vec::new(), &mut chain)?;
+  return Ok(chain);
+
+  fn load_extended(
+    config_path: &Path,
+    extra_rule_codes: &HashSet<&str>,
+    visiting: &mut Vec<PathBuf>,
+    chain: &mut ConfigChain,
+  ) -> Result<(), AnyError> {
+    let config_path = config_path.canonicalize().map_err(|err| {
+      anyhow!("Failed to load \"{}\": {}", config_path.display(), err)
+    })?;
+    if visiting.contains(&config_path) {
+      bail!("Config \"{}\" extends itself", config_path.display());
+    }
+    let config = load_config(&config_path, extra_rule_codes)?;
+    visiting.push(config_path);
+    for extended in &config.extends {
+      load_extended(
+        &config.dir.join(extended),
+        extra_rule_codes,
+        visiting,
+        chain,
+      )?;
+    }
+    visiting.pop();
+    chain.configs.push(Arc::new(config));
+    Ok(())
+  }
+}
+
+/// Finds the `dlint.json` configs applying to files, looking in their
+/// directories and the parent directories up to a config with `root` set.
+#[derive(Default)]
+pub struct ConfigDiscovery {
+  dirs: HashMap<PathBuf, ConfigChain>,
+  extra_rule_codes: HashSet<&'static str>,
+}
+
+impl ConfigDiscovery {
+  /// `extra_rule_codes` are codes of rules configs can use besides the
+  /// built-in ones.
+  pub fn new(extra_rule_codes: HashSet<&'static str>) -> Self {
+    ConfigDiscovery {
+      dirs: HashMap::new(),
+      extra_rule_codes,
+    }
+  }
+
+  pub fn configs_for(
+    &mut self,
+    file_path: &Path,
+  ) -> Result<ConfigChain, AnyError> {
+    match file_path.parent() {
+      Some(dir) => self.configs_for_dir(dir),
+      None => Ok(ConfigChain::default()),
+    }
+  }
+
+  fn configs_for_dir(&mut self, dir: &Path) -> Result<ConfigChain, AnyError> {
+    if let Some(chain) = self.dirs.get(dir) {
+      return Ok(chain.clone());
+    }
+
+    let config_path = dir.join(CONFIG_FILE_NAME);
+    let own = if config_path.is_file() {
+      Some(load_chain(&config_path, &self.extra_rule_codes)?)
+    } else {
+      None
+    };
+    let is_root = own
+      .as_ref()
+      .and_then(|chain| chain.configs.last())
+      .is_some_and(|config| config.root);
+    let mut chain = match dir.parent() {
+      Some(parent) if !is_root => self.configs_for_dir(parent)?,
+      _ => ConfigChain::default(),
+    };
+    if let Some(own) = own {
+      chain.extend(&own);
+    }
+    self.dirs.insert(dir.to_path_buf(), chain.clone());
+    Ok(chain)
+  }
+}
+
 // Ported from dprint
 // https://github.com/dprint/dprint/blob/358c91fbf0a545a0c9736cc496dc1d998028ae65/crates/dprint/src/cli/run_cli.rs#L686-L756
 fn resolve_file_paths(config: &FilesConfig) -> Result<Vec<PathBuf>, AnyError> {
   let mut file_patterns = get_file_patterns(config);
   let absolute_paths = take_absolute_paths(&mut file_patterns);
 
-  let cwd = std::env::current_dir()?;
-  let mut file_paths = glob(&cwd, &file_patterns)?;
+  let base = match &config.base {
+    Some(base) => base.clone(),
+    None => std::env::current_dir()?,
+  };
+  let mut file_paths = glob(&base, &file_patterns)?;
   file_paths.extend(absolute_paths);
   return Ok(file_paths);
 
@@ -146,6 +623,7 @@ fn glob(
 #[cfg(test)]
 mod tests {
   use super::*;
+  use deno_ast::ModuleSpecifier;
   use deno_lint::rules::recommended_rules;
   use std::collections::HashSet;
 
@@ -171,22 +649,27 @@ mod tests {
         tags: svec![],
         include: svec![],
         exclude: svec![],
+        ..Default::default()
       },
       ..Default::default()
     };
-    assert!(config.get_rules().is_empty());
+    assert!(config.get_rules().unwrap().is_empty());
 
     let config = Config {
       rules: RulesConfig {
         tags: svec!["recommended"],
         include: svec![],
         exclude: svec![],
+        ..Default::default()
       },
       ..Default::default()
     };
     let recommended_rules_codes =
       into_codes(recommended_rules(get_all_rules()));
-    assert_eq!(into_codes(config.get_rules()), recommended_rules_codes);
+    assert_eq!(
+      into_codes(config.get_rules().unwrap()),
+      recommended_rules_codes
+    );
 
     // even if "recommended" is specified in `tags` and `include` contains a rule
     // code that is in the "recommended" set, we have to make sure that each
@@ -196,12 +679,16 @@ mod tests {
         tags: svec!["recommended"],
         include: svec!["no-empty"], // "no-empty" belongs to "recommended"
         exclude: svec![],
+        ..Default::default()
       },
       ..Default::default()
     };
     let recommended_rules_codes =
       into_codes(recommended_rules(get_all_rules()));
-    assert_eq!(into_codes(config.get_rules()), recommended_rules_codes);
+    assert_eq!(
+      into_codes(config.get_rules().unwrap()),
+      recommended_rules_codes
+    );
 
     // `exclude` has higher precedence over `include`
     let config = Config {
@@ -209,10 +696,11 @@ mod tests {
         tags: svec![],
         include: svec!["eqeqeq"],
         exclude: svec!["eqeqeq"],
+        ..Default::default()
       },
       ..Default::default()
     };
-    assert_eq!(into_codes(config.get_rules()), set![]);
+    assert_eq!(into_codes(config.get_rules().unwrap()), set![]);
 
     // if unknown rule is specified, just ignore it
     let config = Config {
@@ -220,9 +708,103 @@ mod tests {
         tags: svec![],
         include: svec!["this-is-a-totally-unknown-rule"],
         exclude: svec!["this-is-also-another-unknown-rule"],
+        ..Default::default()
+      },
+      ..Default::default()
+    };
+    assert_eq!(into_codes(config.get_rules().unwrap()), set![]);
+
+    // invalid rule options are reported
+    let config = Config {
+      rules: RulesConfig {
+        tags: svec![],
+        include: svec!["eqeqeq"],
+        exclude: svec![],
+        options: HashMap::from([(
+          "eqeqeq".to_string(),
+          serde_json::json!({ "allowNull": 1 }),
+        )]),
+        ..Default::default()
       },
       ..Default::default()
     };
-    assert_eq!(into_codes(config.get_rules()), set![]);
+    assert!(config.get_rules().is_err());
+  }
+
+  fn testdata_config_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+      .join("examples/dlint/testdata/config")
+  }
+
+  #[test]
+  fn test_config_chain() {
+    let dir = testdata_config_path();
+    let chain =
+      load_chain(&dir.join(CONFIG_FILE_NAME), &HashSet::new()).unwrap();
+    let recommended_rules_codes =
+      into_codes(recommended_rules(get_all_rules()));
+
+    // `extends` applies first, then the config itself.
+    let selection = chain.resolve(&dir.join("src/mod.ts")).unwrap();
+    assert!(recommended_rules_codes
+      .iter()
+      .all(|code| selection.codes.contains(code)));
+    assert!(selection.codes.contains("eqeqeq"));
+
+    // Globs of the rules' files are relative to the config.
+    let scope = selection.file_scopes().unwrap().remove("eqeqeq").unwrap();
+    let specifier = |path: &str| {
+      ModuleSpecifier::from_file_path(dir.canonicalize().unwrap().join(path))
+        .unwrap()
+    };
+    assert!(scope.matches(&specifier("src/mod.ts")));
+    assert!(!scope.matches(&specifier("tests/mod_test.ts")));
+
+    // Overrides apply only to the matching files.
+    let selection = chain.resolve(&dir.join("tests/mod_test.ts")).unwrap();
+    assert!(!selection.codes.contains("no-explicit-any"));
+    assert!(selection.codes.contains("eqeqeq"));
+
+    // Environments are replaced, while globals are merged.
+    let globals = chain.lint_config(&dir.join("src/mod.ts")).unwrap().globals;
+    assert_eq!(globals.environments, vec![Environment::Browser]);
+    assert_eq!(globals.get("__APP_VERSION__"), Some(GlobalAccess::Readonly));
+    let globals = chain
+      .lint_config(&dir.join("tests/mod_test.ts"))
+      .unwrap()
+      .globals;
+    assert_eq!(globals.environments, vec![Environment::Node]);
+    assert_eq!(globals.get("__APP_VERSION__"), Some(GlobalAccess::Readonly));
+  }
+
+  #[test]
+  fn test_config_discovery() {
+    let dir = testdata_config_path();
+    let mut discovery = ConfigDiscovery::default();
+
+    let file_path = dir.join("src/mod.ts");
+    let selection = discovery
+      .configs_for(&file_path)
+      .unwrap()
+      .resolve(&file_path)
+      .unwrap();
+    assert!(selection.codes.contains("no-explicit-any"));
+    assert!(selection.codes.contains("eqeqeq"));
+
+    // The config of the nested directory applies last.
+    let file_path = dir.join("tests/mod_test.ts");
+    let selection = discovery
+      .configs_for(&file_path)
+      .unwrap()
+      .resolve(&file_path)
+      .unwrap();
+    assert!(!selection.codes.contains("no-explicit-any"));
+    assert!(!selection.codes.contains("eqeqeq"));
+
+    // Files outside of the directory of the config are not affected.
+    assert!(discovery
+      .configs_for(&dir.parent().unwrap().join("simple.ts"))
+      .unwrap()
+      .is_empty());
   }
 }
@@ -0,0 +1,266 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Reads the lint configuration of `deno.json` and `deno.jsonc` files, so the
+//! same settings are used by `deno lint` and dlint.
+
+use crate::config::CompilerOptionsConfig;
+use crate::config::Config;
+use crate::config::FilesConfig;
+use crate::config::RulesConfig;
+use crate::validation::json_error;
+use crate::validation::validate_rules;
+use crate::validation::PathSegment;
+use anyhow::Error as AnyError;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Extensions of the files linted by `deno lint`.
+const EXTENSIONS: &str = "{ts,tsx,js,jsx,mts,cts,mjs,cjs}";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct DenoConfig {
+  include: Vec<String>,
+  exclude: Vec<String>,
+  lint: DenoLintConfig,
+  compiler_options: CompilerOptionsConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DenoLintConfig {
+  include: Vec<String>,
+  exclude: Vec<String>,
+  /// Deprecated form of `include` and `exclude`.
+  files: FilesConfig,
+  rules: DenoRulesConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DenoRulesConfig {
+  /// `deno lint` uses the `recommended` tag when there are no tags.
+  tags: Option<Vec<String>>,
+  include: Vec<String>,
+  exclude: Vec<String>,
+}
+
+/// Whether the file is a Deno configuration file rather than a dlint one.
+pub fn is_deno_config(config_path: &Path) -> bool {
+  matches!(
+    config_path.file_name().and_then(|name| name.to_str()),
+    Some("deno.json" | "deno.jsonc")
+  )
+}
+
+/// Converts the lint settings of a Deno configuration file.
+pub fn load_from_deno_json(
+  config_path: &Path,
+  extra_rule_codes: &HashSet<&str>,
+) -> Result<Config, AnyError> {
+  let text = strip_jsonc(&std::fs::read_to_string(config_path)?);
+  let value: serde_json::Value =
+    serde_json::from_str(&text).map_err(|err| json_error(config_path, err))?;
+  validate_rules(
+    config_path,
+    &text,
+    &value,
+    &[vec![
+      PathSegment::Key("lint".into()),
+      PathSegment::Key("rules".into()),
+    ]],
+    extra_rule_codes,
+  )?;
+  let deno_config: DenoConfig =
+    serde_json::from_str(&text).map_err(|err| json_error(config_path, err))?;
+  let dir = config_path
+    .parent()
+    .map(Path::to_path_buf)
+    .unwrap_or_default();
+
+  let lint = deno_config.lint;
+  let include = [lint.include, lint.files.include, deno_config.include]
+    .into_iter()
+    .find(|include| !include.is_empty())
+    .unwrap_or_default();
+  let exclude =
+    [deno_config.exclude, lint.exclude, lint.files.exclude].concat();
+
+  Ok(Config {
+    rules: RulesConfig {
+      tags: lint
+        .rules
+        .tags
+        .unwrap_or_else(|| vec!["recommended".to_string()]),
+      include: lint.rules.include,
+      exclude: lint.rules.exclude,
+      ..Default::default()
+    },
+    files: FilesConfig {
+      include: if include.is_empty() {
+        vec![format!("**/*.{}", EXTENSIONS)]
+      } else {
+        include
+          .iter()
+          .map(|path| to_glob(&dir, path, &format!("**/*.{}", EXTENSIONS)))
+          .collect()
+      },
+      exclude: exclude
+        .iter()
+        .map(|path| to_glob(&dir, path, "**"))
+        .collect(),
+      base: Some(dir.clone()),
+    },
+    compiler_options: deno_config.compiler_options,
+    dir,
+    ..Default::default()
+  })
+}
+
+/// Deno's `include` and `exclude` contain paths, which match all the files
+/// below them when they're directories.
+fn to_glob(dir: &Path, path: &str, dir_suffix: &str) -> String {
+  let path = path
+    .strip_prefix("./")
+    .unwrap_or(path)
+    .trim_end_matches('/');
+  if dir.join(path).is_dir() {
+    format!("{}/{}", path, dir_suffix)
+  } else {
+    path.to_string()
+  }
+}
+
+/// Turns JSONC into JSON by blanking out comments and trailing commas, which
+/// keeps the line and column of every other character.
+pub fn strip_jsonc(text: &str) -> String {
+  let chars = text.chars().collect::<Vec<_>>();
+  let mut output = String::with_capacity(text.len());
+  // Position in `output` of a comma that's trailing if the next token closes
+  // an object or array.
+  let mut last_comma = None;
+  let mut i = 0;
+  while i < chars.len() {
+    match (chars[i], chars.get(i + 1)) {
+      ('"', _) => {
+        last_comma = None;
+        output.push('"');
+        i += 1;
+        while i < chars.len() {
+          output.push(chars[i]);
+          match chars[i] {
+            '\\' if i + 1 < chars.len() => {
+              output.push(chars[i + 1]);
+              i += 2;
+            }
+            '"' => {
+              i += 1;
+              break;
+            }
+            _ => i += 1,
+          }
+        }
+        continue;
+      }
+      ('/', Some('/')) => {
+        while i < chars.len() && chars[i] != '\n' {
+          output.push(' ');
+          i += 1;
+        }
+        continue;
+      }
+      ('/', Some('*')) => {
+        output.push_str("  ");
+        i += 2;
+        while i < chars.len()
+          && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/'))
+        {
+          output.push(if chars[i] == '\n' { '\n' } else { ' ' });
+          i += 1;
+        }
+        if i < chars.len() {
+          output.push_str("  ");
+          i += 2;
+        }
+        continue;
+      }
+      (',', _) => {
+        last_comma = Some(output.len());
+        output.push(',');
+      }
+      ('}' | ']', _) => {
+        if let Some(comma) = last_comma.take() {
+          output.replace_range(comma..comma + 1, " ");
+        }
+        output.push(chars[i]);
+      }
+      (c, _) if c.is_whitespace() => output.push(c),
+      (c, _) => {
+        last_comma = None;
+        output.push(c);
+      }
+    }
+    i += 1;
+  }
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::ConfigChain;
+  use std::path::PathBuf;
+
+  #[test]
+  fn test_strip_jsonc() {
+    let text = r#"{
+  // comment
+  "a": [1, 2,], /* block
+  comment */
+  "b": "// not a comment, \" /* */",
+}"#;
+    let json = strip_jsonc(text);
+    assert_eq!(
+      serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+      serde_json::json!({ "a": [1, 2], "b": "// not a comment, \" /* */" })
+    );
+    // Positions in errors are the same as in the original text.
+    assert_eq!(json.len(), text.len());
+    assert_eq!(json.lines().count(), text.lines().count());
+  }
+
+  #[test]
+  fn test_load_from_deno_json() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+      .join("examples/dlint/testdata/deno_config");
+    let config_path = dir.join("deno.jsonc");
+    assert!(is_deno_config(&config_path));
+
+    let config = load_from_deno_json(&config_path, &HashSet::new()).unwrap();
+    assert_eq!(config.rules.tags, vec!["recommended".to_string()]);
+    assert_eq!(config.rules.include, vec!["eqeqeq".to_string()]);
+    assert_eq!(config.rules.exclude, vec!["no-explicit-any".to_string()]);
+    assert_eq!(
+      config.files.include,
+      vec![format!("src/**/*.{}", EXTENSIONS)]
+    );
+    assert_eq!(
+      config.files.exclude,
+      vec!["src/vendor/**".to_string(), "src/generated.ts".to_string()]
+    );
+    assert_eq!(
+      config.get_files().unwrap(),
+      vec![dir.join("src/main.tsx").canonicalize().unwrap()]
+    );
+
+    let lint_config = ConfigChain::from(config)
+      .lint_config(&dir.join("src/main.tsx"))
+      .unwrap();
+    assert_eq!(lint_config.default_jsx_factory.as_deref(), Some("h"));
+    assert_eq!(
+      lint_config.default_jsx_fragment_factory.as_deref(),
+      Some("Fragment")
+    );
+  }
+}
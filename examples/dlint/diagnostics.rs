@@ -2,14 +2,20 @@
 
 use deno_ast::diagnostics::Diagnostic;
 use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::rules::LintRule;
+use deno_lint::sarif::{sarif_log, SarifTool};
 
+/// Prints the diagnostics in the given format. `rules` are only used to
+/// describe the rules in SARIF output.
 pub fn display_diagnostics(
   diagnostics: &[LintDiagnostic],
   format: Option<&str>,
+  rules: &[Box<dyn LintRule>],
 ) {
   match format {
     Some("compact") => print_compact(diagnostics),
     Some("pretty") => print_pretty(diagnostics),
+    Some("sarif") => print_sarif(diagnostics, rules),
     _ => unreachable!("Invalid output format specified"),
   }
 }
@@ -21,10 +27,11 @@ fn print_compact(diagnostics: &[LintDiagnostic]) {
         let display_index =
           range.text_info.line_and_column_display(range.range.start);
         eprintln!(
-          "{}: line {}, col {}, Error - {} ({})",
+          "{}: line {}, col {}, {} - {} ({})",
           diagnostic.specifier,
           display_index.line_number,
           display_index.column_number,
+          diagnostic.details.severity,
           diagnostic.details.message,
           diagnostic.details.code
         )
@@ -46,3 +53,8 @@ fn print_pretty(diagnostics: &[LintDiagnostic]) {
     eprintln!("{}\n", diagnostic.display());
   }
 }
+
+fn print_sarif(diagnostics: &[LintDiagnostic], rules: &[Box<dyn LintRule>]) {
+  let log = sarif_log(&SarifTool::deno_lint(), rules, diagnostics);
+  println!("{}", serde_json::to_string_pretty(&log).unwrap());
+}
@@ -4,27 +4,34 @@ use anyhow::bail;
 use anyhow::Error as AnyError;
 use clap::Arg;
 use clap::Command;
+use config::ConfigDiscovery;
+use config::RuleSelection;
 use core::panic;
-use deno_ast::diagnostics::Diagnostic;
 use deno_ast::MediaType;
 use deno_ast::ModuleSpecifier;
-use deno_lint::linter::LintConfig;
+use deno_lint::diagnostic::LintDiagnostic;
+use deno_lint::diagnostic::LintFixKind;
+use deno_lint::linter::CancellationToken;
 use deno_lint::linter::LintFileOptions;
+use deno_lint::linter::LintFileResult;
 use deno_lint::linter::Linter;
 use deno_lint::linter::LinterOptions;
-use deno_lint::rules::get_all_rules;
+use deno_lint::plugin::PluginHost;
+use deno_lint::presets::{diff_presets, preset_versions};
 use deno_lint::rules::{filtered_rules, recommended_rules};
+use deno_lint::rules::{get_all_rules, RuleRegistry};
+use deno_lint::tags;
+use deno_lint::timing::TimingReport;
 use log::debug;
-use rayon::prelude::*;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::{Arc, Mutex};
 
 mod config;
+mod deno_config;
 mod diagnostics;
 mod rules;
+mod validation;
 
 fn create_cli_app<'a>() -> Command<'a> {
   Command::new("dlint")
@@ -38,6 +45,16 @@ fn create_cli_app<'a>() -> Command<'a> {
         )
         .arg(Arg::new("json").long("json")),
     )
+    .subcommand(
+      Command::new("presets")
+        .about("List presets, or the rules changed between two presets")
+        .arg(Arg::new("FROM").help("Preset to compare, e.g. recommended@0.69"))
+        .arg(
+          Arg::new("TO")
+            .help("Preset to compare with, e.g. recommended")
+            .requires("FROM"),
+        ),
+    )
     .subcommand(
       Command::new("run")
         .arg(
@@ -65,8 +82,24 @@ fn create_cli_app<'a>() -> Command<'a> {
             .validator(|val: &str| match val {
               "compact" => Ok(()),
               "pretty" => Ok(()),
-              _ => Err("Output format must be compact or pretty")
+              "sarif" => Ok(()),
+              _ => Err("Output format must be compact, pretty or sarif")
             }),
+        ).arg(
+          Arg::new("FIX")
+            .long("fix")
+            .help("Apply safe fixes to the linted files"),
+        ).arg(
+          Arg::new("UNSAFE_FIXES")
+            .long("unsafe-fixes")
+            .help("Also apply fixes that might change behavior, and suggestions")
+            .requires("FIX"),
+        ).arg(
+          Arg::new("PLUGIN")
+            .long("plugin")
+            .help("Run the rules of a JavaScript plugin (requires Deno)")
+            .takes_value(true)
+            .multiple_occurrences(true),
         )
     )
 }
@@ -74,91 +107,164 @@ fn create_cli_app<'a>() -> Command<'a> {
 fn run_linter(
   paths: Vec<String>,
   filter_rule_name: Option<&str>,
-  maybe_config: Option<Arc<config::Config>>,
+  maybe_config_path: Option<&str>,
   format: Option<&str>,
+  fix_kinds: &[LintFixKind],
+  plugins: Vec<String>,
 ) -> Result<(), AnyError> {
+  let plugin_host = if plugins.is_empty() {
+    None
+  } else {
+    let runner = concat!(
+      env!("CARGO_MANIFEST_DIR"),
+      "/examples/dlint/plugin_runner.js"
+    );
+    Some(PluginHost::spawn(
+      "deno",
+      ["run", "--allow-read", runner]
+        .into_iter()
+        .map(String::from)
+        .chain(plugins),
+    )?)
+  };
+  let mut all_rule_codes = RuleRegistry::new().all_rule_codes();
+  let mut plugin_rule_codes = HashSet::new();
+  if let Some(host) = &plugin_host {
+    plugin_rule_codes.extend(host.rules()?.iter().map(|rule| rule.code()));
+    all_rule_codes.extend(&plugin_rule_codes);
+  }
+
+  let maybe_config = if let Some(p) = maybe_config_path {
+    let path = PathBuf::from(p);
+    match path.extension().and_then(|s| s.to_str()) {
+      Some("json" | "jsonc") => {
+        Some(config::load_chain(&path, &plugin_rule_codes)?)
+      }
+      ext => bail!(
+        "Unknown extension: \"{:#?}\". Use .json or .jsonc instead.",
+        ext
+      ),
+    }
+  } else {
+    None
+  };
+  debug!("Config: {:#?}", maybe_config);
+
   let cwd = std::env::current_dir()?;
   let mut paths: Vec<PathBuf> =
     paths.iter().map(|path| cwd.join(path)).collect();
 
-  if let Some(config) = maybe_config.clone() {
+  if let Some(config) = &maybe_config {
     paths.extend(config.get_files()?);
   }
 
-  let error_counts = Arc::new(AtomicUsize::new(0));
-
-  let all_rules = get_all_rules();
-  let all_rule_codes = all_rules
+  // Rules used for files without any config.
+  let default_selection = RuleSelection {
+    codes: if let Some(rule_name) = filter_rule_name {
+      let include = vec![rule_name.to_string()];
+      filtered_rules(get_all_rules(), Some(vec![]), None, Some(include))
+    } else {
+      recommended_rules(get_all_rules())
+    }
     .iter()
     .map(|rule| rule.code())
-    .collect::<HashSet<_>>();
-  let rules = if let Some(config) = maybe_config {
-    config.get_rules()
-  } else if let Some(rule_name) = filter_rule_name {
-    let include = vec![rule_name.to_string()];
-    filtered_rules(get_all_rules(), Some(vec![]), None, Some(include))
-  } else {
-    recommended_rules(get_all_rules())
+    .collect(),
+    ..Default::default()
   };
-  if rules.is_empty() {
-    bail!("No lint rules configured");
-  } else {
-    debug!("Configured rules: {}", rules.len());
-  }
-  let file_diagnostics = Arc::new(Mutex::new(BTreeMap::new()));
-  let linter = Linter::new(LinterOptions {
-    rules,
-    all_rule_codes,
-    custom_ignore_file_directive: None,
-    custom_ignore_diagnostic_directive: None,
-  });
 
-  paths
-    .par_iter()
-    .try_for_each(|file_path| -> Result<(), AnyError> {
-      let source_code = std::fs::read_to_string(file_path)?;
-
-      let (parsed_source, diagnostics) = linter.lint_file(LintFileOptions {
-        specifier: ModuleSpecifier::from_file_path(file_path).unwrap_or_else(
-          |_| {
-            panic!(
-              "Failed to convert path to module specifier: {}",
-              file_path.display()
-            )
-          },
-        ),
-        source_code,
-        media_type: MediaType::from_path(file_path),
-        config: LintConfig {
-          default_jsx_factory: Some("React.createElement".to_string()),
-          default_jsx_fragment_factory: Some("React.Fragment".to_string()),
+  // Files are linted in groups sharing the same rules, as each config in
+  // the directory tree and each override can change them.
+  let mut discovery = ConfigDiscovery::new(plugin_rule_codes);
+  let mut groups: Vec<(RuleSelection, Vec<(PathBuf, LintFileOptions)>)> =
+    Vec::new();
+  for file_path in paths {
+    let mut configs = maybe_config.clone().unwrap_or_default();
+    configs.extend(&discovery.configs_for(&file_path)?);
+    let selection = if configs.is_empty() {
+      default_selection.clone()
+    } else {
+      configs.resolve(&file_path)?
+    };
+    let file = LintFileOptions {
+      specifier: ModuleSpecifier::from_file_path(&file_path).unwrap_or_else(
+        |_| {
+          panic!(
+            "Failed to convert path to module specifier: {}",
+            file_path.display()
+          )
         },
-      })?;
-
-      let mut number_of_errors = diagnostics.len();
-      if !parsed_source.diagnostics().is_empty() {
-        number_of_errors += parsed_source.diagnostics().to_vec().len();
-        parsed_source.diagnostics().to_vec().iter().for_each(
-          |parsing_diagnostic| {
-            eprintln!("{}", parsing_diagnostic.display());
-          },
-        );
-      }
+      ),
+      source_code: std::fs::read_to_string(&file_path)?,
+      media_type: MediaType::from_path(&file_path),
+      config: configs.lint_config(&file_path)?,
+    };
+    match groups.iter_mut().find(|(s, _)| *s == selection) {
+      Some((_, files)) => files.push((file_path, file)),
+      None => groups.push((selection, vec![(file_path, file)])),
+    }
+  }
 
-      error_counts.fetch_add(number_of_errors, Ordering::Relaxed);
+  let mut err_count = 0;
+  let mut file_diagnostics = BTreeMap::new();
+  let mut timing_report: Option<TimingReport> = None;
+  for (selection, files) in groups {
+    let mut rules = selection.rules();
+    if let Some(host) = &plugin_host {
+      rules.extend(host.rules()?);
+    }
+    if rules.is_empty() {
+      bail!("No lint rules configured");
+    } else {
+      debug!("Configured rules: {}", rules.len());
+    }
+    let mut linter = Linter::try_new(LinterOptions {
+      rules,
+      rule_options: selection.rule_options(),
+      all_rule_codes: all_rule_codes.clone(),
+      custom_ignore_file_directive: None,
+      custom_ignore_diagnostic_directive: None,
+      severity_overrides: selection.severity_overrides(),
+      file_scopes: selection.file_scopes()?,
+      ..Default::default()
+    })?;
 
-      let mut lock = file_diagnostics.lock().unwrap();
+    // Like ESLint, print how long each rule took when `TIMING` is set.
+    if std::env::var_os("TIMING").is_some() {
+      linter.enable_timing();
+    }
 
-      lock.insert(file_path, diagnostics);
+    let (file_paths, files): (Vec<_>, Vec<_>) = files.into_iter().unzip();
+    let file_results = if fix_kinds.is_empty() {
+      linter.lint_files(files, &CancellationToken::new())?
+    } else {
+      fix_files(&linter, &file_paths, files, fix_kinds)?
+    };
+    for (file_path, file_result) in file_paths.into_iter().zip(file_results) {
+      // Only diagnostics with `error` severity make the run fail.
+      err_count += file_result.error_count();
+      file_diagnostics.insert(file_path, file_result.diagnostics);
+    }
 
-      Ok(())
-    })?;
+    if let Some(report) = linter.timing_report() {
+      timing_report
+        .get_or_insert_with(Default::default)
+        .merge(&report);
+    }
+  }
+
+  // SARIF output describes all the rules that could have been run.
+  let mut all_rules = get_all_rules();
+  if let Some(host) = &plugin_host {
+    all_rules.extend(host.rules()?);
+  }
+  let diagnostics: Vec<LintDiagnostic> =
+    file_diagnostics.into_values().flatten().collect();
+  diagnostics::display_diagnostics(&diagnostics, format, &all_rules);
 
-  for d in file_diagnostics.lock().unwrap().values() {
-    diagnostics::display_diagnostics(d, format);
+  if let Some(report) = timing_report {
+    eprintln!("{}", report);
   }
 
-  let err_count = error_counts.load(Ordering::Relaxed);
   if err_count > 0 {
     eprintln!(
       "Found {} problem{}",
@@ -171,6 +277,61 @@ fn run_linter(
   Ok(())
 }
 
+/// Apply the fixes of the given kinds to the files, writing the ones that
+/// changed, and return the diagnostics that are left.
+fn fix_files(
+  linter: &Linter,
+  file_paths: &[PathBuf],
+  files: Vec<LintFileOptions>,
+  fix_kinds: &[LintFixKind],
+) -> Result<Vec<LintFileResult>, AnyError> {
+  let mut file_results = Vec::with_capacity(files.len());
+  for (file_path, file) in file_paths.iter().zip(files) {
+    let specifier = file.specifier.clone();
+    let source_code = file.source_code.clone();
+    let media_type = file.media_type;
+    let config = file.config.clone();
+    let file_result = match linter.fix_file(file, fix_kinds) {
+      Ok(fixed) => {
+        if **fixed.parsed_source.text() != *source_code {
+          std::fs::write(file_path, fixed.parsed_source.text().as_bytes())?;
+        }
+        for dropped in &fixed.dropped {
+          eprintln!(
+            "Skipped fix \"{}\" ({}) in {}, as {}",
+            dropped.fix.description,
+            dropped.fix.code,
+            file_path.display(),
+            dropped.reason
+          );
+        }
+        LintFileResult {
+          specifier,
+          parsed_source: Some(fixed.parsed_source),
+          diagnostics: fixed.diagnostics,
+        }
+      }
+      // The file can't be parsed, so there's nothing to fix.
+      Err(_) => {
+        let (parsed_source, diagnostics) =
+          linter.lint_file_with_parse_errors(LintFileOptions {
+            specifier: specifier.clone(),
+            source_code,
+            media_type,
+            config,
+          });
+        LintFileResult {
+          specifier,
+          parsed_source,
+          diagnostics,
+        }
+      }
+    };
+    file_results.push(file_result);
+  }
+  Ok(file_results)
+}
+
 fn main() -> Result<(), AnyError> {
   env_logger::init();
 
@@ -179,20 +340,6 @@ fn main() -> Result<(), AnyError> {
 
   match matches.subcommand() {
     Some(("run", run_matches)) => {
-      let maybe_config = if let Some(p) = run_matches.value_of("CONFIG") {
-        let path = PathBuf::from(p);
-
-        let c = match path.extension().and_then(|s| s.to_str()) {
-          Some("json") => config::load_from_json(&path)?,
-          ext => bail!("Unknown extension: \"{:#?}\". Use .json instead.", ext),
-        };
-        Some(Arc::new(c))
-      } else {
-        None
-      };
-
-      debug!("Config: {:#?}", maybe_config);
-
       let paths: Vec<String> = run_matches
         .values_of("FILES")
         .unwrap_or_default()
@@ -201,10 +348,47 @@ fn main() -> Result<(), AnyError> {
       run_linter(
         paths,
         run_matches.value_of("RULE_CODE"),
-        maybe_config,
+        run_matches.value_of("CONFIG"),
         run_matches.value_of("FORMAT"),
+        if run_matches.is_present("UNSAFE_FIXES") {
+          LintFixKind::ALL
+        } else if run_matches.is_present("FIX") {
+          &[LintFixKind::Safe]
+        } else {
+          &[]
+        },
+        run_matches
+          .values_of("PLUGIN")
+          .unwrap_or_default()
+          .map(|p| p.to_string())
+          .collect(),
       )?;
     }
+    Some(("presets", presets_matches)) => {
+      match (
+        presets_matches.value_of("FROM"),
+        presets_matches.value_of("TO"),
+      ) {
+        (Some(from), Some(to)) => {
+          let diff = diff_presets(from, to)?;
+          for code in diff.added {
+            println!("+ {}", code);
+          }
+          for code in diff.removed {
+            println!("- {}", code);
+          }
+        }
+        _ => {
+          for tag in tags::ALL_TAGS {
+            println!(
+              "{} (versions: {})",
+              tag,
+              preset_versions(tag.display()).join(", ")
+            );
+          }
+        }
+      }
+    }
     Some(("rules", rules_matches)) => {
       let rules = if let Some(rule_name) = rules_matches.value_of("RULE_NAME") {
         rules::get_specific_rule_metadata(rule_name)
@@ -445,9 +629,33 @@ mod tests {
     exit_code: 1,
   });
 
+  itest!(config_overrides {
+    args: "run config/src/mod.ts config/tests/mod_test.ts",
+    output: "config.out",
+    exit_code: 1,
+  });
+
+  itest!(deno_config {
+    args: "run --config deno_config/deno.jsonc",
+    output: "deno_config.out",
+    exit_code: 1,
+  });
+
+  itest!(presets_diff {
+    args: "presets jsr@0.69 fresh",
+    output_str: Some("+ button-has-type\n+ fresh-handler-export\n+ fresh-server-event-handlers\n+ jsx-no-children-prop\n+ jsx-no-comment-text-nodes\n+ jsx-no-danger-with-children\n+ jsx-no-unescaped-entities\n+ jsx-no-useless-fragment\n+ jsx-void-dom-elements-no-children\n+ rules-of-hooks\n- verbatim-module-syntax\n"),
+    exit_code: 0,
+  });
+
   itest!(issue1145_no_trailing_newline {
     args: "run issue1145_no_trailing_newline.ts",
     output: "issue1145_no_trailing_newline.out",
     exit_code: 1,
   });
+
+  itest!(sarif {
+    args: "run --format sarif issue1145_no_trailing_newline.ts",
+    output: "sarif.out",
+    exit_code: 1,
+  });
 }
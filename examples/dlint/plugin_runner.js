@@ -0,0 +1,90 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+// Runs lint rules written in JavaScript for `dlint run --plugin`.
+//
+// Usage: deno run --allow-read plugin_runner.js <plugin>...
+//
+// A plugin is a module whose default export is a `Visitor` subclass from
+// `visitor.js`, or an array of them. Each of them is a rule whose code is
+// returned by its static `ruleCode()` method. Diagnostics added with
+// `addDiagnostic` must have the shape described in `src/plugin.rs`.
+
+import { SEPARATOR, toFileUrl } from "jsr:@std/path@1";
+
+// Version of the protocol described in `src/plugin.rs`.
+const PROTOCOL_VERSION = 1;
+
+const cwd = toFileUrl(Deno.cwd() + SEPARATOR);
+const rules = new Map();
+const versions = new Map();
+for (const path of Deno.args) {
+  const url = new URL(path, cwd);
+  const mod = await import(url.href);
+  // Rules are versioned by the source of their plugin, so that cached
+  // results are dropped when it changes.
+  const source = await (await fetch(url)).arrayBuffer();
+  const digest = await crypto.subtle.digest("SHA-256", source);
+  const version = Array.from(
+    new Uint8Array(digest),
+    (byte) => byte.toString(16).padStart(2, "0"),
+  ).join("");
+  const visitors = Array.isArray(mod.default) ? mod.default : [mod.default];
+  for (const visitor of visitors) {
+    rules.set(visitor.ruleCode(), visitor);
+    versions.set(visitor.ruleCode(), version);
+  }
+}
+
+function handle(request) {
+  switch (request.type) {
+    case "getRules":
+      if (request.protocolVersion !== PROTOCOL_VERSION) {
+        return {
+          error: `Unsupported protocol version: ${request.protocolVersion}`,
+        };
+      }
+      return {
+        protocolVersion: PROTOCOL_VERSION,
+        rules: [...rules.keys()].map((code) => ({
+          code,
+          version: versions.get(code),
+        })),
+      };
+    case "lint": {
+      const Rule = rules.get(request.rule);
+      if (!Rule) {
+        return { error: `Unknown rule: ${request.rule}` };
+      }
+      return { diagnostics: new Rule().collectDiagnostics(request.ast) };
+    }
+    default:
+      return { error: `Unknown request: ${request.type}` };
+  }
+}
+
+// `Deno.stdout.write` can write only part of the data.
+async function writeAll(data) {
+  let written = 0;
+  while (written < data.length) {
+    written += await Deno.stdout.write(data.subarray(written));
+  }
+}
+
+const encoder = new TextEncoder();
+let buffer = "";
+const input = Deno.stdin.readable.pipeThrough(new TextDecoderStream());
+for await (const chunk of input) {
+  buffer += chunk;
+  let newline;
+  while ((newline = buffer.indexOf("\n")) !== -1) {
+    const line = buffer.slice(0, newline);
+    buffer = buffer.slice(newline + 1);
+    let response;
+    try {
+      response = handle(JSON.parse(line));
+    } catch (err) {
+      response = { error: String(err?.stack ?? err) };
+    }
+    await writeAll(encoder.encode(JSON.stringify(response) + "\n"));
+  }
+}
@@ -0,0 +1,3 @@
+export function equals(a: any, b: unknown): boolean {
+  return a == b;
+}
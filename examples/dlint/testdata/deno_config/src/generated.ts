@@ -0,0 +1 @@
+export const generated: any = 1;
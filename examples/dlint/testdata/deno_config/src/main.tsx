@@ -0,0 +1,3 @@
+export function App(props: any) {
+  return <>{props.count == 1 ? "one" : "many"}</>;
+}
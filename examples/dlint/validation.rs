@@ -0,0 +1,557 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Validation of configs against the rule and tag schemas, so typos are
+//! reported instead of silently disabling rules.
+
+use deno_lint::diagnostic::PARSE_ERROR_CODE;
+use deno_lint::file_scope::FileScope;
+use deno_lint::presets::is_versioned_preset;
+use deno_lint::presets::preset_rules;
+use deno_lint::rules::get_all_rules;
+use deno_lint::tags::TagExpr;
+use deno_lint::tags::TagExprError;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+use std::path::PathBuf;
+
+const RULES_SCHEMA: &str = include_str!("../../schemas/rules.v1.json");
+const TAGS_SCHEMA: &str = include_str!("../../schemas/tags.v1.json");
+
+/// A problem in a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+  pub path: PathBuf,
+  /// 1-based line of the offending value.
+  pub line: usize,
+  /// 1-based column of the offending value.
+  pub column: usize,
+  pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}\n    at {}:{}:{}",
+      self.message,
+      self.path.display(),
+      self.line,
+      self.column
+    )
+  }
+}
+
+/// All the problems found in a config file.
+#[derive(Debug)]
+pub struct ConfigErrors(pub Vec<ConfigError>);
+
+impl fmt::Display for ConfigErrors {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, error) in self.0.iter().enumerate() {
+      if i > 0 {
+        writeln!(f)?;
+      }
+      write!(f, "{}", error)?;
+    }
+    Ok(())
+  }
+}
+
+impl std::error::Error for ConfigErrors {}
+
+/// Converts an error parsing or deserializing a config, which has the
+/// location of the problem.
+pub fn json_error(path: &Path, err: serde_json::Error) -> ConfigErrors {
+  let message = err.to_string();
+  // The location is reported separately.
+  let message = match message.rfind(" at line ") {
+    Some(index) => &message[..index],
+    None => &message,
+  };
+  ConfigErrors(vec![ConfigError {
+    path: path.to_path_buf(),
+    line: err.line(),
+    column: err.column(),
+    message: format!("Invalid config: {}", message),
+  }])
+}
+
+/// Checks the rule settings found at `rules_paths` in the config, e.g.
+/// `["rules"]`, against the known rules and tags.
+///
+/// `extra_rule_codes` are codes of rules that aren't built-in, e.g. of
+/// plugins.
+pub fn validate_rules(
+  path: &Path,
+  json: &str,
+  value: &Value,
+  rules_paths: &[Vec<PathSegment>],
+  extra_rule_codes: &HashSet<&str>,
+) -> Result<(), ConfigErrors> {
+  let known_rules = schema_enum(RULES_SCHEMA);
+  let known_tags = schema_enum(TAGS_SCHEMA);
+  let is_known_rule =
+    |code: &str| known_rules.contains(code) || extra_rule_codes.contains(code);
+  let locator = JsonLocator::new(json);
+  let mut errors = Vec::new();
+  let mut report = |segments: &[PathSegment], key: bool, message: String| {
+    let offset = locator.offset(segments, key).unwrap_or(0);
+    let (line, column) = line_and_column(json, offset);
+    errors.push(ConfigError {
+      path: path.to_path_buf(),
+      line,
+      column,
+      message,
+    });
+  };
+
+  for rules_path in rules_paths {
+    let Some(rules) = get(value, rules_path) else {
+      continue;
+    };
+    let child = |field: &str, segment: PathSegment| {
+      let mut path = rules_path.clone();
+      path.push(PathSegment::Key(field.to_string()));
+      path.push(segment);
+      path
+    };
+
+    for (i, tag) in strings(rules.get("tags")) {
+      if is_versioned_preset(tag) {
+        if let Err(err) = preset_rules(tag) {
+          report(
+            &child("tags", PathSegment::Index(i)),
+            false,
+            err.to_string(),
+          );
+        }
+      } else {
+        match TagExpr::parse(tag) {
+          Ok(_) => {}
+          Err(TagExprError::UnknownTag(name)) => report(
+            &child("tags", PathSegment::Index(i)),
+            false,
+            with_suggestion(
+              format!("Unknown tag \"{}\"", name),
+              &name,
+              known_tags.iter(),
+            ),
+          ),
+          Err(err) => report(
+            &child("tags", PathSegment::Index(i)),
+            false,
+            err.to_string(),
+          ),
+        }
+      }
+    }
+
+    let excluded = strings(rules.get("exclude"))
+      .map(|(_, code)| code)
+      .collect::<HashSet<_>>();
+    for field in ["include", "exclude"] {
+      for (i, code) in strings(rules.get(field)) {
+        let segments = child(field, PathSegment::Index(i));
+        if !is_known_rule(code) {
+          report(
+            &segments,
+            false,
+            with_suggestion(
+              format!("Unknown rule \"{}\"", code),
+              code,
+              known_rules.iter(),
+            ),
+          );
+        } else if field == "include" && excluded.contains(code) {
+          report(
+            &segments,
+            false,
+            format!(
+              "Rule \"{}\" is both included and excluded, so it's disabled",
+              code
+            ),
+          );
+        }
+      }
+    }
+
+    for field in ["options", "severity", "files"] {
+      let Some(Value::Object(entries)) = rules.get(field) else {
+        continue;
+      };
+      for (code, options) in entries {
+        let segments = child(field, PathSegment::Key(code.clone()));
+        // Syntax errors can be given a severity like the rules.
+        if field == "severity" && code == PARSE_ERROR_CODE {
+          continue;
+        }
+        if !is_known_rule(code) {
+          report(
+            &segments,
+            true,
+            with_suggestion(
+              format!("Unknown rule \"{}\"", code),
+              code,
+              known_rules.iter(),
+            ),
+          );
+          continue;
+        }
+        if field == "files" {
+          for globs_field in ["include", "exclude"] {
+            for (i, glob) in strings(options.get(globs_field)) {
+              if let Err(err) = FileScope::new(vec![glob.to_string()], vec![]) {
+                let mut segments = segments.clone();
+                segments.push(PathSegment::Key(globs_field.to_string()));
+                segments.push(PathSegment::Index(i));
+                report(&segments, false, err.to_string());
+              }
+            }
+          }
+          continue;
+        }
+        if field != "options" {
+          continue;
+        }
+        let Some(mut rule) =
+          get_all_rules().into_iter().find(|rule| rule.code() == code)
+        else {
+          continue;
+        };
+        if let Err(err) = rule.configure(options) {
+          report(&segments, false, err.to_string());
+        }
+      }
+    }
+  }
+
+  if errors.is_empty() {
+    Ok(())
+  } else {
+    Err(ConfigErrors(errors))
+  }
+}
+
+fn strings(value: Option<&Value>) -> impl Iterator<Item = (usize, &str)> {
+  value
+    .and_then(Value::as_array)
+    .into_iter()
+    .flatten()
+    .enumerate()
+    .filter_map(|(i, value)| Some((i, value.as_str()?)))
+}
+
+fn get<'a>(value: &'a Value, segments: &[PathSegment]) -> Option<&'a Value> {
+  segments
+    .iter()
+    .try_fold(value, |value, segment| match segment {
+      PathSegment::Key(key) => value.get(key),
+      PathSegment::Index(index) => value.get(index),
+    })
+}
+
+fn schema_enum(schema: &str) -> HashSet<String> {
+  let schema: Value = serde_json::from_str(schema).unwrap();
+  schema["enum"]
+    .as_array()
+    .unwrap()
+    .iter()
+    .filter_map(|value| value.as_str().map(String::from))
+    .collect()
+}
+
+fn with_suggestion<'a>(
+  message: String,
+  name: &str,
+  candidates: impl Iterator<Item = &'a String>,
+) -> String {
+  let closest = candidates
+    .map(|candidate| (edit_distance(name, candidate), candidate))
+    .filter(|(distance, _)| *distance <= 2)
+    .min();
+  match closest {
+    Some((_, candidate)) => {
+      format!("{}. Did you mean \"{}\"?", message, candidate)
+    }
+    None => message,
+  }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut row = (0..=b.len()).collect::<Vec<_>>();
+  for (i, a_char) in a.chars().enumerate() {
+    let mut previous = row[0];
+    row[0] = i + 1;
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution = previous + usize::from(a_char != *b_char);
+      previous = row[j + 1];
+      row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+    }
+  }
+  row[b.len()]
+}
+
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+  let before = &text[..offset];
+  let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+  (
+    before.matches('\n').count() + 1,
+    before[line_start..].chars().count() + 1,
+  )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+  Key(String),
+  Index(usize),
+}
+
+/// Finds the positions of values in valid JSON text.
+struct JsonLocator<'a> {
+  text: &'a str,
+}
+
+impl<'a> JsonLocator<'a> {
+  fn new(text: &'a str) -> Self {
+    JsonLocator { text }
+  }
+
+  /// Byte offset of the value at `segments`, or of its key if `key` is set.
+  fn offset(&self, segments: &[PathSegment], key: bool) -> Option<usize> {
+    let mut offset = self.skip_whitespace(0);
+    let mut key_offset = None;
+    for segment in segments {
+      match (segment, self.text[offset..].chars().next()?) {
+        (PathSegment::Key(wanted), '{') => {
+          offset = self.skip_whitespace(offset + 1);
+          loop {
+            if self.text[offset..].starts_with('}') {
+              return None;
+            }
+            let key_end = self.skip_value(offset)?;
+            let name: String =
+              serde_json::from_str(&self.text[offset..key_end]).ok()?;
+            let colon = self.skip_whitespace(key_end);
+            let value_start = self.skip_whitespace(colon + 1);
+            if &name == wanted {
+              key_offset = Some(offset);
+              offset = value_start;
+              break;
+            }
+            offset = self.next_item(value_start)?;
+          }
+        }
+        (PathSegment::Index(wanted), '[') => {
+          offset = self.skip_whitespace(offset + 1);
+          for _ in 0..*wanted {
+            offset = self.next_item(offset)?;
+          }
+          key_offset = None;
+        }
+        _ => return None,
+      }
+    }
+    if key {
+      key_offset
+    } else {
+      Some(offset)
+    }
+  }
+
+  /// Skips the value at `offset` and the following comma.
+  fn next_item(&self, offset: usize) -> Option<usize> {
+    let end = self.skip_whitespace(self.skip_value(offset)?);
+    match self.text[end..].chars().next()? {
+      ',' => Some(self.skip_whitespace(end + 1)),
+      _ => None,
+    }
+  }
+
+  fn skip_whitespace(&self, offset: usize) -> usize {
+    let rest = &self.text[offset..];
+    offset + (rest.len() - rest.trim_start().len())
+  }
+
+  /// Returns the offset after the value starting at `offset`.
+  fn skip_value(&self, offset: usize) -> Option<usize> {
+    let bytes = self.text.as_bytes();
+    match bytes.get(offset)? {
+      b'"' => {
+        let mut i = offset + 1;
+        while i < bytes.len() {
+          match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+          }
+        }
+        None
+      }
+      b'{' | b'[' => {
+        let mut depth = 0;
+        let mut i = offset;
+        while i < bytes.len() {
+          match bytes[i] {
+            b'"' => {
+              i = self.skip_value(i)?;
+              continue;
+            }
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => {
+              depth -= 1;
+              if depth == 0 {
+                return Some(i + 1);
+              }
+            }
+            _ => {}
+          }
+          i += 1;
+        }
+        None
+      }
+      _ => {
+        let rest = &self.text[offset..];
+        let len = rest
+          .find(|c: char| c == ',' || c == '}' || c == ']' || c.is_whitespace())
+          .unwrap_or(rest.len());
+        Some(offset + len)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn validate(json: &str) -> Vec<(usize, usize, String)> {
+    let value = serde_json::from_str(json).unwrap();
+    let rules_paths = [
+      vec![PathSegment::Key("rules".to_string())],
+      vec![
+        PathSegment::Key("overrides".to_string()),
+        PathSegment::Index(0),
+        PathSegment::Key("rules".to_string()),
+      ],
+    ];
+    match validate_rules(
+      Path::new("dlint.json"),
+      json,
+      &value,
+      &rules_paths,
+      &HashSet::from(["my-plugin-rule"]),
+    ) {
+      Ok(()) => vec![],
+      Err(errors) => errors
+        .0
+        .into_iter()
+        .map(|error| (error.line, error.column, error.message))
+        .collect(),
+    }
+  }
+
+  #[test]
+  fn valid_config() {
+    assert!(validate(
+      r#"{
+  "rules": {
+    "tags": ["recommended@0.69", "jsr", "react & !fresh"],
+    "include": ["eqeqeq", "my-plugin-rule"],
+    "exclude": ["no-explicit-any"],
+    "options": { "eqeqeq": { "allowNull": true } },
+    "severity": { "no-debugger": "warning", "parse-error": "warning" },
+    "files": { "no-console": { "exclude": ["scripts/**"] } }
+  }
+}"#
+    )
+    .is_empty());
+  }
+
+  #[test]
+  fn invalid_config() {
+    assert_eq!(
+      validate(
+        r#"{
+  "rules": {
+    "tags": ["recomended", "strict", "jsr@0.1", "react & !frsh | ("],
+    "include": ["no-explicit-any", "no-consol"],
+    "exclude": ["no-explicit-any"],
+    "options": { "eqeqeq": { "allowNull": 1 } },
+    "severity": { "no-debuger": "warning" },
+    "files": { "no-console": { "exclude": ["scripts/["] } }
+  },
+  "overrides": [
+    { "files": ["tests/**"], "rules": { "exclude": ["no-explict-any"] } }
+  ]
+}"#
+      ),
+      vec![
+        (
+          3,
+          14,
+          "Unknown tag \"recomended\". Did you mean \"recommended\"?"
+            .to_string()
+        ),
+        (3, 28, "Unknown tag \"strict\"".to_string()),
+        (
+          3,
+          38,
+          "Unknown version \"0.1\" of preset \"jsr\", available versions: 0.69"
+            .to_string()
+        ),
+        (
+          3,
+          49,
+          "Unknown tag \"frsh\". Did you mean \"fresh\"?".to_string()
+        ),
+        (
+          4,
+          17,
+          "Rule \"no-explicit-any\" is both included and excluded, so it's disabled"
+            .to_string()
+        ),
+        (
+          4,
+          36,
+          "Unknown rule \"no-consol\". Did you mean \"no-console\"?".to_string()
+        ),
+        (
+          6,
+          28,
+          "Invalid options for rule \"eqeqeq\": invalid type: integer `1`, expected a boolean"
+            .to_string()
+        ),
+        (
+          7,
+          19,
+          "Unknown rule \"no-debuger\". Did you mean \"no-debugger\"?"
+            .to_string()
+        ),
+        (
+          8,
+          44,
+          "Invalid glob \"scripts/[\": unclosed character class; missing ']'"
+            .to_string()
+        ),
+        (
+          11,
+          53,
+          "Unknown rule \"no-explict-any\". Did you mean \"no-explicit-any\"?"
+            .to_string()
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn json_error_location() {
+    let err =
+      serde_json::from_str::<Value>("{\n  \"rules\": [,]\n}").unwrap_err();
+    let error = &json_error(Path::new("dlint.json"), err).0[0];
+    assert_eq!((error.line, error.column), (2, 13));
+    assert_eq!(error.message, "Invalid config: expected value");
+  }
+}
@@ -1292,3 +1292,5 @@ class Visitor {
     return n;
   }
 }
+
+export { Visitor };
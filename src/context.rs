@@ -3,13 +3,21 @@
 use crate::control_flow::ControlFlow;
 use crate::diagnostic::{
   LintDiagnostic, LintDiagnosticDetails, LintDiagnosticRange, LintFix,
+  LintSeverity, PARSE_ERROR_CODE,
+};
+use crate::globals::{
+  parse_global_directives, Environment, GlobalAccess, GlobalDirective, Globals,
 };
 use crate::ignore_directives::{
   parse_line_ignore_directives, CodeStatus, FileIgnoreDirective,
   LineIgnoreDirective,
 };
-use crate::linter::LinterContext;
-use crate::rules::{self, LintRule};
+use crate::linter::{LinterContext, ModuleResolver};
+use crate::performance_mark::PerformanceMark;
+use crate::rules;
+use crate::timing::{
+  TimingReport, CONTROL_FLOW_PHASE, IGNORE_DIRECTIVES_PHASE, SCOPE_PHASE,
+};
 use deno_ast::swc::ast::Expr;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::util::take::Take;
@@ -20,8 +28,11 @@ use deno_ast::{
 };
 use deno_ast::{MediaType, ModuleSpecifier};
 use deno_ast::{MultiThreadedComments, Scope};
+use once_cell::sync::OnceCell;
+use std::cell::Cell;
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// `Context` stores all data needed to perform linting of a particular file.
 pub struct Context<'a> {
@@ -30,34 +41,60 @@ pub struct Context<'a> {
   program: ast_view::Program<'a>,
   file_ignore_directive: Option<FileIgnoreDirective>,
   line_ignore_directives: HashMap<usize, LineIgnoreDirective>,
-  scope: Scope,
-  control_flow: ControlFlow,
+  scope: Arc<OnceCell<Scope>>,
+  control_flow: Arc<OnceCell<ControlFlow>>,
   traverse_flow: TraverseFlow,
   all_rule_codes: &'a HashSet<&'static str>,
+  severities: &'a HashMap<&'static str, LintSeverity>,
+  timing: Option<&'a Mutex<TimingReport>>,
+  /// Time spent in scope and control-flow analysis, if timing is enabled.
+  analysis_time: Cell<Duration>,
   check_unknown_rules: bool,
   #[allow(clippy::redundant_allocation)] // This type comes from SWC.
   jsx_factory: Option<Arc<Box<Expr>>>,
   #[allow(clippy::redundant_allocation)] // This type comes from SWC.
   jsx_fragment_factory: Option<Arc<Box<Expr>>>,
+  globals: Arc<Globals>,
+  global_directives: Arc<Vec<GlobalDirective>>,
 }
 
-impl<'a> Context<'a> {
+/// Per-file analysis that doesn't depend on the rules being run, so it can be
+/// computed once and reused when the same source is linted again.
+///
+/// Scope and control-flow analysis are only computed when a rule first asks
+/// for them. The cells are shared between clones, so a result computed for
+/// one lint is kept for the next ones.
+#[derive(Clone)]
+pub(crate) struct FileAnalysis {
+  line_ignore_directives: HashMap<usize, LineIgnoreDirective>,
+  scope: Arc<OnceCell<Scope>>,
+  control_flow: Arc<OnceCell<ControlFlow>>,
+  #[allow(clippy::redundant_allocation)] // This type comes from SWC.
+  jsx_factory: Option<Arc<Box<Expr>>>,
+  #[allow(clippy::redundant_allocation)] // This type comes from SWC.
+  jsx_fragment_factory: Option<Arc<Box<Expr>>>,
+  globals: Arc<Globals>,
+  global_directives: Arc<Vec<GlobalDirective>>,
+}
+
+impl FileAnalysis {
   pub(crate) fn new(
-    linter_ctx: &'a LinterContext,
-    parsed_source: ParsedSource,
-    program: ast_view::Program<'a>,
-    file_ignore_directive: Option<FileIgnoreDirective>,
+    linter_ctx: &LinterContext,
+    parsed_source: &ParsedSource,
+    program: ast_view::Program,
     default_jsx_factory: Option<String>,
     default_jsx_fragment_factory: Option<String>,
+    globals: Globals,
   ) -> Self {
-    let line_ignore_directives = parse_line_ignore_directives(
-      linter_ctx.ignore_diagnostic_directive,
-      program,
-    );
-    let scope = Scope::analyze(program);
-    let control_flow =
-      ControlFlow::analyze(program, parsed_source.unresolved_context());
-
+    let line_ignore_directives =
+      linter_ctx.time(IGNORE_DIRECTIVES_PHASE, || {
+        parse_line_ignore_directives(
+          linter_ctx.ignore_diagnostic_directive,
+          program,
+        )
+      });
+    let global_directives = linter_ctx
+      .time(IGNORE_DIRECTIVES_PHASE, || parse_global_directives(program));
     let mut jsx_factory = None;
     let mut jsx_fragment_factory = None;
 
@@ -103,18 +140,43 @@ impl<'a> Context<'a> {
     });
 
     Self {
-      file_ignore_directive,
       line_ignore_directives,
-      scope,
-      control_flow,
+      scope: Default::default(),
+      control_flow: Default::default(),
+      jsx_factory,
+      jsx_fragment_factory,
+      globals: Arc::new(globals),
+      global_directives: Arc::new(global_directives),
+    }
+  }
+}
+
+impl<'a> Context<'a> {
+  pub(crate) fn new(
+    linter_ctx: &'a LinterContext,
+    parsed_source: ParsedSource,
+    program: ast_view::Program<'a>,
+    file_ignore_directive: Option<FileIgnoreDirective>,
+    analysis: FileAnalysis,
+  ) -> Self {
+    Self {
+      file_ignore_directive,
+      line_ignore_directives: analysis.line_ignore_directives,
+      scope: analysis.scope,
+      control_flow: analysis.control_flow,
       program,
       parsed_source,
       diagnostics: Vec::new(),
       traverse_flow: TraverseFlow::default(),
       check_unknown_rules: linter_ctx.check_unknown_rules,
       all_rule_codes: &linter_ctx.all_rule_codes,
-      jsx_factory,
-      jsx_fragment_factory,
+      severities: &linter_ctx.severities,
+      timing: linter_ctx.timing.as_ref(),
+      analysis_time: Cell::new(Duration::ZERO),
+      jsx_factory: analysis.jsx_factory,
+      jsx_fragment_factory: analysis.jsx_fragment_factory,
+      globals: analysis.globals,
+      global_directives: analysis.global_directives,
     }
   }
 
@@ -166,14 +228,43 @@ impl<'a> Context<'a> {
     &self.line_ignore_directives
   }
 
-  /// Scope analysis result
+  /// Scope analysis result, computed on first access
   pub fn scope(&self) -> &Scope {
-    &self.scope
+    self.scope.get_or_init(|| {
+      let _mark = PerformanceMark::new("Scope::analyze");
+      self.time_analysis(SCOPE_PHASE, || Scope::analyze(self.program))
+    })
   }
 
-  /// Control-flow analysis result
+  /// Control-flow analysis result, computed on first access
   pub fn control_flow(&self) -> &ControlFlow {
-    &self.control_flow
+    self.control_flow.get_or_init(|| {
+      let _mark = PerformanceMark::new("ControlFlow::analyze");
+      self.time_analysis(CONTROL_FLOW_PHASE, || {
+        ControlFlow::analyze(
+          self.program,
+          self.parsed_source.unresolved_context(),
+        )
+      })
+    })
+  }
+
+  fn time_analysis<T>(&self, phase: &'static str, f: impl FnOnce() -> T) -> T {
+    let Some(timing) = self.timing else {
+      return f();
+    };
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    self.analysis_time.set(self.analysis_time.get() + elapsed);
+    timing.lock().unwrap().add_phase(phase, elapsed);
+    result
+  }
+
+  /// Total time spent in scope and control-flow analysis so far, if timing
+  /// is enabled.
+  pub(crate) fn analysis_time(&self) -> Duration {
+    self.analysis_time.get()
   }
 
   /// Get the JSX factory expression for this file, if one is specified (via
@@ -192,6 +283,32 @@ impl<'a> Context<'a> {
     self.jsx_fragment_factory.clone()
   }
 
+  /// Environments this file runs in, from the configuration.
+  pub fn environments(&self) -> &[Environment] {
+    &self.globals.environments
+  }
+
+  /// Returns how `name` can be accessed if it's a global of this file's
+  /// environments, or one declared in the configuration or by a
+  /// `/* global */` comment. Only meaningful for unresolved identifiers, as
+  /// declarations shadow globals.
+  pub fn lookup_global(&self, name: &str) -> Option<GlobalAccess> {
+    match self
+      .global_directives
+      .iter()
+      .rev()
+      .find(|directive| directive.name == name)
+    {
+      Some(directive) => directive.access,
+      None => self.globals.get(name),
+    }
+  }
+
+  /// Globals declared by `/* global */` comments in this file.
+  pub(crate) fn global_directives(&self) -> &[GlobalDirective] {
+    &self.global_directives
+  }
+
   /// The `SyntaxContext` of any unresolved identifiers
   pub(crate) fn unresolved_ctxt(&self) -> SyntaxContext {
     self.parsed_source.unresolved_context()
@@ -205,7 +322,9 @@ impl<'a> Context<'a> {
     self.traverse_flow.should_stop()
   }
 
-  pub(crate) fn stop_traverse(&mut self) {
+  /// Stop traversing children of the current node. Used by `Handler`s that
+  /// don't need to look into a subtree.
+  pub fn stop_traverse(&mut self) {
     self.traverse_flow.set_stop_traverse();
   }
 
@@ -266,7 +385,7 @@ impl<'a> Context<'a> {
   /// works for diagnostics reported by other rules.
   pub(crate) fn ban_unused_ignore(
     &self,
-    specified_rules: &[Box<dyn LintRule>],
+    executed_codes: &HashSet<&'static str>,
   ) -> Vec<LintDiagnostic> {
     const CODE: &str = "ban-unused-ignore";
 
@@ -280,10 +399,8 @@ impl<'a> Context<'a> {
       return vec![];
     }
 
-    let executed_builtin_codes: HashSet<&'static str> =
-      specified_rules.iter().map(|r| r.code()).collect();
     let is_unused_code = |&(code, status): &(&String, &CodeStatus)| {
-      let is_unknown = !executed_builtin_codes.contains(code.as_str());
+      let is_unknown = !executed_codes.contains(code.as_str());
       !status.used && !is_unknown
     };
 
@@ -335,8 +452,9 @@ impl<'a> Context<'a> {
   /// Lint rule implementation for `ban-unknown-rule-code`.
   /// This should be run after all normal rules.
   pub(crate) fn ban_unknown_rule_code(&mut self) -> Vec<LintDiagnostic> {
-    let is_unknown_rule =
-      |code: &&String| !self.all_rule_codes.contains(code.as_str());
+    let is_unknown_rule = |code: &&String| {
+      !self.all_rule_codes.contains(code.as_str()) && *code != PARSE_ERROR_CODE
+    };
 
     let mut diagnostics = Vec::new();
 
@@ -441,6 +559,22 @@ impl<'a> Context<'a> {
     );
   }
 
+  /// Adds diagnostics that were already created elsewhere, e.g. by
+  /// project-level rules.
+  pub(crate) fn add_diagnostics(&mut self, diagnostics: Vec<LintDiagnostic>) {
+    self.diagnostics.extend(diagnostics);
+  }
+
+  /// Number of the diagnostics reported so far.
+  pub(crate) fn diagnostic_count(&self) -> usize {
+    self.diagnostics.len()
+  }
+
+  /// Removes and returns the diagnostics reported so far.
+  pub(crate) fn take_diagnostics(&mut self) -> Vec<LintDiagnostic> {
+    std::mem::take(&mut self.diagnostics)
+  }
+
   pub fn add_diagnostic_details(
     &mut self,
     maybe_range: Option<LintDiagnosticRange>,
@@ -470,9 +604,15 @@ impl<'a> Context<'a> {
     maybe_hint: Option<String>,
     fixes: Vec<LintFix>,
   ) -> LintDiagnosticDetails {
+    let code = code.to_string();
     LintDiagnosticDetails {
       message: message.to_string(),
-      code: code.to_string(),
+      severity: self
+        .severities
+        .get(code.as_str())
+        .copied()
+        .unwrap_or_default(),
+      code,
       hint: maybe_hint,
       fixes,
       custom_docs_url: None,
@@ -492,6 +632,116 @@ impl<'a> Context<'a> {
   }
 }
 
+/// `ProjectContext` stores all data needed to run project-level rules over
+/// files that are linted together.
+pub struct ProjectContext<'a> {
+  parsed_sources: &'a [ParsedSource],
+  source_indexes: HashMap<&'a ModuleSpecifier, usize>,
+  resolver: &'a dyn ModuleResolver,
+  severities: &'a HashMap<&'static str, LintSeverity>,
+  diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'a> ProjectContext<'a> {
+  pub(crate) fn new(
+    linter_ctx: &'a LinterContext,
+    parsed_sources: &'a [ParsedSource],
+    resolver: &'a dyn ModuleResolver,
+  ) -> Self {
+    let source_indexes = parsed_sources
+      .iter()
+      .enumerate()
+      .map(|(i, parsed_source)| (parsed_source.specifier(), i))
+      .collect();
+
+    Self {
+      parsed_sources,
+      source_indexes,
+      resolver,
+      severities: &linter_ctx.severities,
+      diagnostics: Vec::new(),
+    }
+  }
+
+  /// All files of the project.
+  pub fn parsed_sources(&self) -> &'a [ParsedSource] {
+    self.parsed_sources
+  }
+
+  /// Get a file of the project by its specifier.
+  pub fn parsed_source(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Option<&'a ParsedSource> {
+    let index = *self.source_indexes.get(specifier)?;
+    Some(&self.parsed_sources[index])
+  }
+
+  /// Resolve `specifier` imported from `referrer` to a file of the project.
+  ///
+  /// Returns `None` if the specifier can't be resolved or points to a module
+  /// that isn't part of the project.
+  pub fn resolve(
+    &self,
+    specifier: &str,
+    referrer: &ModuleSpecifier,
+  ) -> Option<&'a ParsedSource> {
+    let resolved = self.resolver.resolve(specifier, referrer)?;
+    self.parsed_source(&resolved)
+  }
+
+  /// Stores diagnostics that are generated while linting
+  pub fn diagnostics(&self) -> &[LintDiagnostic] {
+    &self.diagnostics
+  }
+
+  pub fn add_diagnostic(
+    &mut self,
+    parsed_source: &ParsedSource,
+    range: SourceRange,
+    code: impl ToString,
+    message: impl ToString,
+  ) {
+    self.add_diagnostic_with_hint(parsed_source, range, code, message, None);
+  }
+
+  pub fn add_diagnostic_with_hint(
+    &mut self,
+    parsed_source: &ParsedSource,
+    range: SourceRange,
+    code: impl ToString,
+    message: impl ToString,
+    hint: Option<String>,
+  ) {
+    let code = code.to_string();
+    self.diagnostics.push(LintDiagnostic {
+      specifier: parsed_source.specifier().clone(),
+      range: Some(LintDiagnosticRange {
+        range,
+        text_info: parsed_source.text_info_lazy().clone(),
+        description: None,
+      }),
+      details: LintDiagnosticDetails {
+        message: message.to_string(),
+        severity: self
+          .severities
+          .get(code.as_str())
+          .copied()
+          .unwrap_or_default(),
+        code,
+        hint,
+        fixes: Vec::new(),
+        custom_docs_url: None,
+        info: vec![],
+      },
+    });
+  }
+
+  pub(crate) fn into_diagnostics(self) -> Vec<LintDiagnostic> {
+    self.diagnostics
+  }
+}
+
 /// A struct containing a boolean value to control whether a node's children
 /// will be traversed or not.
 /// If there's no need to further traverse children nodes, you can call
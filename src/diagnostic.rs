@@ -11,8 +11,35 @@ use deno_ast::diagnostics::DiagnosticSnippetHighlightStyle;
 use deno_ast::diagnostics::DiagnosticSourcePos;
 use deno_ast::diagnostics::DiagnosticSourceRange;
 use deno_ast::ModuleSpecifier;
+use deno_ast::ParseDiagnostic;
 use deno_ast::SourceRange;
 use deno_ast::SourceTextInfo;
+use derive_more::Display;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// How severe a diagnostic reported by a rule is.
+#[derive(
+  Debug,
+  Clone,
+  Copy,
+  Default,
+  PartialEq,
+  Eq,
+  Hash,
+  Display,
+  Serialize,
+  Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+  /// The rule is disabled and isn't run at all.
+  Off,
+  Info,
+  Warning,
+  #[default]
+  Error,
+}
 
 #[derive(Debug, Clone)]
 pub struct LintFixChange {
@@ -20,9 +47,49 @@ pub struct LintFixChange {
   pub range: SourceRange,
 }
 
+/// How safe it is to apply a fix without reviewing it.
+#[derive(
+  Debug,
+  Clone,
+  Copy,
+  Default,
+  PartialEq,
+  Eq,
+  Hash,
+  Display,
+  Serialize,
+  Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LintFixKind {
+  /// Preserves the behavior of the code, e.g. adding a `type` keyword to an
+  /// import only used in types.
+  #[display(fmt = "safe")]
+  Safe,
+  /// Fixes the problem, but might change the behavior of the code, e.g.
+  /// replacing `window` with `globalThis`.
+  #[display(fmt = "unsafe")]
+  Unsafe,
+  /// One of possibly several ways of addressing the problem, which should
+  /// only be applied on request.
+  #[default]
+  #[display(fmt = "suggestion")]
+  Suggestion,
+}
+
+impl LintFixKind {
+  pub const ALL: &'static [LintFixKind] = &[
+    LintFixKind::Safe,
+    LintFixKind::Unsafe,
+    LintFixKind::Suggestion,
+  ];
+}
+
 #[derive(Debug, Clone)]
 pub struct LintFix {
   pub description: Cow<'static, str>,
+  /// Whether the fix is applied by `deno lint --fix` by default.
+  pub kind: LintFixKind,
   pub changes: Vec<LintFixChange>,
 }
 
@@ -38,13 +105,14 @@ pub struct LintDiagnosticRange {
 pub struct LintDiagnosticDetails {
   pub message: String,
   pub code: String,
+  pub severity: LintSeverity,
   pub hint: Option<String>,
   /// Fixes that should be shown in the Deno LSP and also
   /// used for the `deno lint --fix` flag.
   ///
   /// Note: If there are multiple fixes for a diagnostic then
-  /// only the first fix will be used for the `--fix` flag, but
-  /// multiple will be shown in the LSP.
+  /// only the first fix of the enabled kinds will be used for the
+  /// `--fix` flag, but multiple will be shown in the LSP.
   pub fixes: Vec<LintFix>,
   /// URL to the lint rule documentation. By default, the url uses the
   /// code to link to lint.deno.land
@@ -64,9 +132,52 @@ pub struct LintDiagnostic {
   pub details: LintDiagnosticDetails,
 }
 
+/// Code of diagnostics created from syntax errors.
+pub const PARSE_ERROR_CODE: &str = "parse-error";
+
+/// URL of the documentation of a built-in rule.
+pub fn rule_docs_url(code: &str) -> String {
+  format!("https://docs.deno.com/lint/rules/{}", code)
+}
+
+impl LintDiagnostic {
+  /// Create a diagnostic with the `parse-error` code from a syntax error, so
+  /// it can be reported along with diagnostics of the lint rules.
+  pub fn from_parse_diagnostic(diagnostic: &ParseDiagnostic) -> Self {
+    let text_info = match diagnostic.location() {
+      DiagnosticLocation::ModulePosition { text_info, .. } => {
+        Some(text_info.into_owned())
+      }
+      _ => None,
+    };
+    LintDiagnostic {
+      specifier: diagnostic.specifier.clone(),
+      range: text_info.map(|text_info| LintDiagnosticRange {
+        text_info,
+        range: diagnostic.range,
+        description: None,
+      }),
+      details: LintDiagnosticDetails {
+        message: diagnostic.message().into_owned(),
+        code: PARSE_ERROR_CODE.to_string(),
+        severity: LintSeverity::Error,
+        hint: None,
+        fixes: Vec::new(),
+        custom_docs_url: None,
+        info: Vec::new(),
+      },
+    }
+  }
+}
+
 impl Diagnostic for LintDiagnostic {
   fn level(&self) -> DiagnosticLevel {
-    DiagnosticLevel::Error
+    match self.details.severity {
+      LintSeverity::Error => DiagnosticLevel::Error,
+      LintSeverity::Off | LintSeverity::Info | LintSeverity::Warning => {
+        DiagnosticLevel::Warning
+      }
+    }
   }
 
   fn code(&self) -> Cow<'_, str> {
@@ -113,8 +224,50 @@ impl Diagnostic for LintDiagnostic {
       .map(|s| Cow::Borrowed(s.as_str()))
   }
 
+  /// The source after applying the first fix, with the changed text
+  /// highlighted. The fix may be of any kind, so it's not necessarily one
+  /// `--fix` applies, as that only applies `Safe` fixes.
   fn snippet_fixed(&self) -> Option<DiagnosticSnippet<'_>> {
-    None // todo
+    let range = self.range.as_ref()?;
+    let fix = self.details.fixes.first()?;
+    let source = range.text_info.text_str();
+    let source_start = range.text_info.range().start;
+    let mut changes = fix
+      .changes
+      .iter()
+      .map(|change| {
+        (change.range.as_byte_range(source_start), &change.new_text)
+      })
+      .collect::<Vec<_>>();
+    changes.sort_by_key(|(range, _)| (range.start, range.end));
+
+    let mut fixed_text = String::with_capacity(source.len());
+    let mut highlights = Vec::with_capacity(changes.len());
+    let mut last_index = 0;
+    for (range, new_text) in changes {
+      // Fixes with overlapping changes can't be applied.
+      if range.start < last_index || range.end > source.len() {
+        return None;
+      }
+      fixed_text.push_str(&source[last_index..range.start]);
+      let start = fixed_text.len();
+      fixed_text.push_str(new_text);
+      highlights.push(DiagnosticSnippetHighlight {
+        range: DiagnosticSourceRange {
+          start: DiagnosticSourcePos::ByteIndex(start),
+          end: DiagnosticSourcePos::ByteIndex(fixed_text.len()),
+        },
+        style: DiagnosticSnippetHighlightStyle::Hint,
+        description: None,
+      });
+      last_index = range.end;
+    }
+    fixed_text.push_str(&source[last_index..]);
+
+    Some(DiagnosticSnippet {
+      source: Cow::Owned(SourceTextInfo::from_string(fixed_text)),
+      highlights,
+    })
   }
 
   fn info(&self) -> Cow<'_, [std::borrow::Cow<'_, str>]> {
@@ -122,13 +275,12 @@ impl Diagnostic for LintDiagnostic {
   }
 
   fn docs_url(&self) -> Option<Cow<'_, str>> {
-    if let Some(custom_docs_url) = &self.details.custom_docs_url {
+    if self.details.code == PARSE_ERROR_CODE {
+      None
+    } else if let Some(custom_docs_url) = &self.details.custom_docs_url {
       Some(Cow::Borrowed(custom_docs_url))
     } else {
-      Some(Cow::Owned(format!(
-        "https://docs.deno.com/lint/rules/{}",
-        &self.details.code
-      )))
+      Some(Cow::Owned(rule_docs_url(&self.details.code)))
     }
   }
 }
@@ -0,0 +1,157 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::ModuleSpecifier;
+use derive_more::Display;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// Files a rule is run on, as globs matched against the path of the linted
+/// file.
+///
+/// A file is in scope if it matches any of the `include` globs, or there are
+/// none, and none of the `exclude` globs. Relative globs match anywhere in
+/// the path, e.g. `routes/**` matches `file:///app/routes/index.tsx`.
+#[derive(Clone)]
+pub struct FileScope {
+  include: Vec<String>,
+  exclude: Vec<String>,
+  include_set: GlobSet,
+  exclude_set: GlobSet,
+}
+
+/// Error returned when a glob of a `FileScope` is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "Invalid glob \"{}\": {}", glob, message)]
+pub struct FileScopeError {
+  pub glob: String,
+  pub message: String,
+}
+
+impl std::error::Error for FileScopeError {}
+
+impl FileScope {
+  pub fn new(
+    include: Vec<String>,
+    exclude: Vec<String>,
+  ) -> Result<Self, FileScopeError> {
+    Ok(Self {
+      include_set: build_glob_set(&include)?,
+      exclude_set: build_glob_set(&exclude)?,
+      include,
+      exclude,
+    })
+  }
+
+  pub fn include(&self) -> &[String] {
+    &self.include
+  }
+
+  pub fn exclude(&self) -> &[String] {
+    &self.exclude
+  }
+
+  pub fn matches(&self, specifier: &ModuleSpecifier) -> bool {
+    let path = match specifier.to_file_path() {
+      Ok(path) => path,
+      Err(()) => PathBuf::from(specifier.path()),
+    };
+    (self.include.is_empty() || self.include_set.is_match(&path))
+      && !self.exclude_set.is_match(&path)
+  }
+}
+
+fn build_glob_set(globs: &[String]) -> Result<GlobSet, FileScopeError> {
+  let mut builder = GlobSetBuilder::new();
+  for glob in globs {
+    let pattern = if glob.starts_with('/')
+      || glob.starts_with("**/")
+      || Path::new(glob).is_absolute()
+    {
+      glob.clone()
+    } else {
+      format!("**/{}", glob.strip_prefix("./").unwrap_or(glob))
+    };
+    let glob = GlobBuilder::new(&pattern)
+      .literal_separator(true)
+      .build()
+      .map_err(|err| FileScopeError {
+        glob: glob.clone(),
+        message: err.kind().to_string(),
+      })?;
+    builder.add(glob);
+  }
+  builder.build().map_err(|err| FileScopeError {
+    glob: err.glob().unwrap_or_default().to_string(),
+    message: err.kind().to_string(),
+  })
+}
+
+impl fmt::Debug for FileScope {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("FileScope")
+      .field("include", &self.include)
+      .field("exclude", &self.exclude)
+      .finish()
+  }
+}
+
+impl PartialEq for FileScope {
+  fn eq(&self, other: &Self) -> bool {
+    self.include == other.include && self.exclude == other.exclude
+  }
+}
+
+impl Eq for FileScope {}
+
+impl Hash for FileScope {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.include.hash(state);
+    self.exclude.hash(state);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn scope(include: &[&str], exclude: &[&str]) -> FileScope {
+    FileScope::new(
+      include.iter().map(|glob| glob.to_string()).collect(),
+      exclude.iter().map(|glob| glob.to_string()).collect(),
+    )
+    .unwrap()
+  }
+
+  fn matches(scope: &FileScope, specifier: &str) -> bool {
+    scope.matches(&ModuleSpecifier::parse(specifier).unwrap())
+  }
+
+  #[test]
+  fn file_scope() {
+    let all = scope(&[], &[]);
+    assert!(matches(&all, "file:///app/main.ts"));
+
+    let routes = scope(&["routes/**"], &["**/(_islands)/**"]);
+    assert!(matches(&routes, "file:///app/routes/index.tsx"));
+    assert!(matches(&routes, "file:///routes/foo/bar.tsx"));
+    assert!(!matches(&routes, "file:///app/main.ts"));
+    assert!(!matches(
+      &routes,
+      "file:///app/routes/(_islands)/counter.tsx"
+    ));
+    assert!(matches(&routes, "https://example.com/routes/index.tsx"));
+
+    // `*` doesn't match across directories.
+    let scripts = scope(&[], &["scripts/*.ts"]);
+    assert!(!matches(&scripts, "file:///app/scripts/build.ts"));
+    assert!(matches(&scripts, "file:///app/scripts/lib/build.ts"));
+  }
+
+  #[test]
+  fn invalid_glob() {
+    let err = FileScope::new(vec!["routes/[".to_string()], vec![]).unwrap_err();
+    assert_eq!(err.glob, "routes/[");
+  }
+}
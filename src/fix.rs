@@ -0,0 +1,465 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Applying the fixes attached to diagnostics.
+//!
+//! `apply_fixes` does a single pass over the diagnostics of a file, while
+//! `Linter::fix_file` repeats passes, linting the file again after each one,
+//! until there's nothing left to fix.
+
+use crate::diagnostic::{LintDiagnostic, LintFix, LintFixKind};
+use deno_ast::{ParsedSource, SourceRanged};
+use derive_more::Display;
+use std::ops::Range;
+
+/// Maximum number of passes of `Linter::fix_file`, as fixes might keep
+/// enabling new ones.
+pub const MAX_FIX_ITERATIONS: usize = 10;
+
+/// Fix of a diagnostic that was applied or dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixSummary {
+  /// Code of the rule reporting the diagnostic.
+  pub code: String,
+  pub description: String,
+  pub kind: LintFixKind,
+  /// Byte range of the diagnostic in the text the fix was applied to, which
+  /// isn't the original text for fixes applied by later passes.
+  pub range: Range<usize>,
+}
+
+impl FixSummary {
+  /// Summary of the fix of `diagnostic` that would be applied, if it has one
+  /// of the given kinds.
+  pub(crate) fn new(
+    diagnostic: &LintDiagnostic,
+    kinds: &[LintFixKind],
+  ) -> Option<Self> {
+    let range = diagnostic.range.as_ref()?;
+    let fix = first_fix(diagnostic, kinds)?;
+    Some(Self {
+      code: diagnostic.details.code.clone(),
+      description: fix.description.to_string(),
+      kind: fix.kind,
+      range: range.range.as_byte_range(range.text_info.range().start),
+    })
+  }
+}
+
+/// The first of the fixes of `diagnostic` having one of the given kinds.
+pub(crate) fn first_fix<'a>(
+  diagnostic: &'a LintDiagnostic,
+  kinds: &[LintFixKind],
+) -> Option<&'a LintFix> {
+  diagnostic
+    .details
+    .fixes
+    .iter()
+    .find(|fix| kinds.contains(&fix.kind))
+}
+
+/// Identifies the fix of a diagnostic across passes of `Linter::fix_file`,
+/// which shift its range.
+pub(crate) fn fix_key(
+  diagnostic: &LintDiagnostic,
+  kinds: &[LintFixKind],
+) -> Option<(String, String)> {
+  let range = diagnostic.range.as_ref()?;
+  let fix = first_fix(diagnostic, kinds)?;
+  let text = range.range.text_fast(&range.text_info);
+  Some((
+    diagnostic.details.code.clone(),
+    format!("{}\n{}", fix.description, text),
+  ))
+}
+
+/// Why a fix wasn't applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum FixDropReason {
+  #[display(fmt = "it overlaps with another fix")]
+  Conflict,
+  #[display(fmt = "the fixed code doesn't parse")]
+  InvalidSyntax,
+  #[display(fmt = "the maximum number of fix passes was reached")]
+  IterationLimit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DroppedFix {
+  pub fix: FixSummary,
+  pub reason: FixDropReason,
+}
+
+/// Result of applying fixes to a file.
+#[derive(Debug, Clone)]
+pub struct FixResult {
+  pub text: String,
+  pub applied: Vec<FixSummary>,
+  pub dropped: Vec<DroppedFix>,
+}
+
+/// Result of `Linter::fix_file`.
+pub struct FixFileResult {
+  /// The fixed file.
+  pub parsed_source: ParsedSource,
+  /// Diagnostics of the fixed file, including syntax errors like the ones
+  /// of `Linter::lint_file_with_parse_errors`.
+  pub diagnostics: Vec<LintDiagnostic>,
+  pub applied: Vec<FixSummary>,
+  pub dropped: Vec<DroppedFix>,
+}
+
+/// Applies the first fix of the given kinds of each of the diagnostics to
+/// `source`, the text the diagnostics were reported for.
+///
+/// Fixes are applied in the order of their first change. All changes of a
+/// fix are applied or none are, so a fix that overlaps with one applied
+/// before it is dropped. Linting the result again usually reports it again,
+/// so it can be applied by another pass.
+///
+/// The result isn't parsed, so it can have syntax errors when fixes conflict
+/// in ways that don't overlap. `Linter::fix_file` checks that each pass
+/// still parses.
+pub fn apply_fixes(
+  source: &str,
+  diagnostics: &[LintDiagnostic],
+  kinds: &[LintFixKind],
+) -> FixResult {
+  let mut fixes = diagnostics
+    .iter()
+    .filter_map(|diagnostic| {
+      let summary = FixSummary::new(diagnostic, kinds)?;
+      let range = diagnostic.range.as_ref()?;
+      let fix = first_fix(diagnostic, kinds)?;
+      let source_start = range.text_info.range().start;
+      let mut changes = fix
+        .changes
+        .iter()
+        .map(|change| {
+          (change.range.as_byte_range(source_start), &change.new_text)
+        })
+        .collect::<Vec<_>>();
+      changes.sort_by_key(|(range, _)| (range.start, range.end));
+      Some((summary, changes))
+    })
+    .collect::<Vec<_>>();
+  fixes.sort_by_key(|(summary, changes)| {
+    let start = changes.first().map(|(range, _)| range.start);
+    (start, summary.range.start)
+  });
+
+  // Ranges of the applied changes, which are sorted as they don't overlap.
+  let mut applied_ranges: Vec<Range<usize>> = Vec::new();
+  let mut changes_to_apply = Vec::new();
+  let mut applied = Vec::new();
+  let mut dropped = Vec::new();
+  for (summary, changes) in fixes {
+    let is_valid = changes
+      .windows(2)
+      .all(|pair| pair[0].0.end <= pair[1].0.start)
+      && changes.iter().all(|(range, _)| {
+        range.start <= range.end
+          && range.end <= source.len()
+          && !applied_ranges
+            .iter()
+            .any(|applied| overlaps(applied, range))
+      });
+    if !is_valid {
+      dropped.push(DroppedFix {
+        fix: summary,
+        reason: FixDropReason::Conflict,
+      });
+      continue;
+    }
+    for (range, new_text) in changes {
+      let index = applied_ranges.partition_point(|r| r.start < range.start);
+      applied_ranges.insert(index, range.clone());
+      changes_to_apply.push((range, new_text));
+    }
+    applied.push(summary);
+  }
+
+  changes_to_apply.sort_by_key(|(range, _)| (range.start, range.end));
+  let mut text = String::with_capacity(source.len());
+  let mut last_index = 0;
+  for (range, new_text) in changes_to_apply {
+    text.push_str(&source[last_index..range.start]);
+    text.push_str(new_text);
+    last_index = range.end;
+  }
+  text.push_str(&source[last_index..]);
+
+  FixResult {
+    text,
+    applied,
+    dropped,
+  }
+}
+
+/// Whether two changes conflict. Insertions at the same position conflict,
+/// as the order they'd be applied in is ambiguous, while an insertion at the
+/// boundary of a replaced range doesn't.
+fn overlaps(a: &Range<usize>, b: &Range<usize>) -> bool {
+  if a.is_empty() && b.is_empty() {
+    a.start == b.start
+  } else {
+    a.start < b.end && b.start < a.end
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::context::Context;
+  use crate::diagnostic::LintFixChange;
+  use crate::handler::Handler;
+  use crate::rules::LintRule;
+  use crate::test_util::{lint_file_options, linter};
+  use deno_ast::view::{self as ast_view, NodeKind};
+  use deno_ast::{SourceRange, SourceRanged};
+
+  /// Replaces `!!x` with `Boolean(x)`.
+  #[derive(Debug)]
+  struct NoDoubleNot;
+
+  impl LintRule for NoDoubleNot {
+    fn code(&self) -> &'static str {
+      "no-double-not"
+    }
+
+    fn handler(&self) -> Option<Box<dyn Handler>> {
+      Some(Box::new(NoDoubleNotHandler))
+    }
+  }
+
+  struct NoDoubleNotHandler;
+
+  impl Handler for NoDoubleNotHandler {
+    fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+      Some(&[NodeKind::UnaryExpr])
+    }
+
+    fn unary_expr(&mut self, expr: &ast_view::UnaryExpr, ctx: &mut Context) {
+      let ast_view::Expr::Unary(inner) = expr.arg else {
+        return;
+      };
+      if expr.op() != ast_view::UnaryOp::Bang
+        || inner.op() != ast_view::UnaryOp::Bang
+      {
+        return;
+      }
+      let arg = inner.arg.text_fast(ctx.text_info());
+      let new_text = format!("Boolean({})", arg);
+      add_fix(
+        ctx,
+        "no-double-not",
+        expr.range(),
+        new_text,
+        LintFixKind::Safe,
+      );
+    }
+  }
+
+  /// Wraps identifiers in parentheses, which never converges.
+  #[derive(Debug)]
+  struct WrapIdents;
+
+  impl LintRule for WrapIdents {
+    fn code(&self) -> &'static str {
+      "wrap-idents"
+    }
+
+    fn handler(&self) -> Option<Box<dyn Handler>> {
+      Some(Box::new(WrapIdentsHandler))
+    }
+  }
+
+  struct WrapIdentsHandler;
+
+  impl Handler for WrapIdentsHandler {
+    fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+      Some(&[NodeKind::Ident])
+    }
+
+    fn ident(&mut self, ident: &ast_view::Ident, ctx: &mut Context) {
+      let text = format!("({})", ident.sym());
+      add_fix(ctx, "wrap-idents", ident.range(), text, LintFixKind::Safe);
+    }
+  }
+
+  /// Has a fix producing invalid code.
+  #[derive(Debug)]
+  struct BreakDebugger;
+
+  impl LintRule for BreakDebugger {
+    fn code(&self) -> &'static str {
+      "break-debugger"
+    }
+
+    fn handler(&self) -> Option<Box<dyn Handler>> {
+      Some(Box::new(BreakDebuggerHandler))
+    }
+  }
+
+  struct BreakDebuggerHandler;
+
+  impl Handler for BreakDebuggerHandler {
+    fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+      Some(&[NodeKind::DebuggerStmt])
+    }
+
+    fn debugger_stmt(
+      &mut self,
+      stmt: &ast_view::DebuggerStmt,
+      ctx: &mut Context,
+    ) {
+      let new_text = "debugger(".to_string();
+      add_fix(
+        ctx,
+        "break-debugger",
+        stmt.range(),
+        new_text,
+        LintFixKind::Unsafe,
+      );
+    }
+  }
+
+  fn add_fix(
+    ctx: &mut Context,
+    code: &'static str,
+    range: SourceRange,
+    new_text: String,
+    kind: LintFixKind,
+  ) {
+    ctx.add_diagnostic_with_fixes(
+      range,
+      code,
+      "Fixable",
+      None,
+      vec![LintFix {
+        description: format!("Replace with {}", new_text).into(),
+        kind,
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range,
+        }],
+      }],
+    );
+  }
+
+  #[test]
+  fn apply_fixes_skips_conflicts() {
+    let source = "!!!!a;\n!!b;";
+    let (_, diagnostics) =
+      linter(vec![Box::new(NoDoubleNot)], Default::default())
+        .lint_file(lint_file_options("file:///fix_test.ts", source))
+        .unwrap();
+    assert_eq!(diagnostics.len(), 4);
+
+    let result = apply_fixes(source, &diagnostics, &[LintFixKind::Safe]);
+    assert_eq!(result.text, "Boolean(!!a);\nBoolean(b);");
+    assert_eq!(
+      result
+        .applied
+        .iter()
+        .map(|fix| fix.range.clone())
+        .collect::<Vec<_>>(),
+      vec![0..5, 7..10]
+    );
+    assert_eq!(
+      result.dropped,
+      vec![
+        DroppedFix {
+          fix: FixSummary {
+            code: "no-double-not".to_string(),
+            description: "Replace with Boolean(!a)".to_string(),
+            kind: LintFixKind::Safe,
+            range: 1..5,
+          },
+          reason: FixDropReason::Conflict,
+        },
+        DroppedFix {
+          fix: FixSummary {
+            code: "no-double-not".to_string(),
+            description: "Replace with Boolean(a)".to_string(),
+            kind: LintFixKind::Safe,
+            range: 2..5,
+          },
+          reason: FixDropReason::Conflict,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn fix_file_converges() {
+    let result = linter(vec![Box::new(NoDoubleNot)], Default::default())
+      .fix_file(
+        lint_file_options("file:///fix_test.ts", "!!!!a;"),
+        &[LintFixKind::Safe],
+      )
+      .unwrap();
+    assert_eq!(&**result.parsed_source.text(), "Boolean(Boolean(a));");
+    assert_eq!(result.applied.len(), 2);
+    assert!(result.dropped.is_empty());
+    assert!(result.diagnostics.is_empty());
+  }
+
+  #[test]
+  fn fix_file_iteration_limit() {
+    let result = linter(vec![Box::new(WrapIdents)], Default::default())
+      .fix_file(
+        lint_file_options("file:///fix_test.ts", "a;"),
+        &[LintFixKind::Safe],
+      )
+      .unwrap();
+    assert_eq!(
+      result.parsed_source.text().to_string(),
+      format!(
+        "{}a{};",
+        "(".repeat(MAX_FIX_ITERATIONS),
+        ")".repeat(MAX_FIX_ITERATIONS)
+      )
+    );
+    assert_eq!(result.applied.len(), MAX_FIX_ITERATIONS);
+    assert_eq!(result.dropped.len(), 1);
+    assert_eq!(result.dropped[0].reason, FixDropReason::IterationLimit);
+  }
+
+  #[test]
+  fn fix_file_invalid_syntax() {
+    let result = linter(
+      vec![Box::new(BreakDebugger), Box::new(NoDoubleNot)],
+      Default::default(),
+    )
+    .fix_file(
+      lint_file_options("file:///fix_test.ts", "debugger;\n!!a;"),
+      LintFixKind::ALL,
+    )
+    .unwrap();
+    // The fixes of a pass producing invalid code are applied one at a time,
+    // keeping the valid ones.
+    assert_eq!(&**result.parsed_source.text(), "debugger;\nBoolean(a);");
+    assert_eq!(result.applied.len(), 1);
+    assert_eq!(result.dropped.len(), 1);
+    assert_eq!(result.dropped[0].fix.code, "break-debugger");
+    assert_eq!(result.dropped[0].reason, FixDropReason::InvalidSyntax);
+    assert_eq!(result.diagnostics.len(), 1);
+  }
+
+  #[test]
+  fn fix_file_kinds() {
+    let result = linter(
+      vec![Box::new(BreakDebugger), Box::new(NoDoubleNot)],
+      Default::default(),
+    )
+    .fix_file(
+      lint_file_options("file:///fix_test.ts", "debugger;\n!!a;"),
+      &[LintFixKind::Safe],
+    )
+    .unwrap();
+    assert_eq!(&**result.parsed_source.text(), "debugger;\nBoolean(a);");
+    assert_eq!(result.applied.len(), 1);
+    assert!(result.dropped.is_empty());
+    assert_eq!(result.diagnostics.len(), 1);
+  }
+}
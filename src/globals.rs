@@ -1,11 +1,227 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use deno_ast::swc::common::comments::CommentKind;
+use deno_ast::view as ast_view;
+use deno_ast::RootNode;
+use deno_ast::SourceRange;
+use deno_ast::SourceRangedForSpanned;
+use derive_more::Display;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Runtime a file is written for, which decides the globals it can use
+/// without declaring them.
+#[derive(
+  Debug,
+  Clone,
+  Copy,
+  PartialEq,
+  Eq,
+  PartialOrd,
+  Ord,
+  Hash,
+  Display,
+  Serialize,
+  Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Environment {
+  #[display(fmt = "deno")]
+  Deno,
+  #[display(fmt = "browser")]
+  Browser,
+  /// Dedicated web workers.
+  #[display(fmt = "web-worker")]
+  WebWorker,
+  #[display(fmt = "service-worker")]
+  ServiceWorker,
+  #[display(fmt = "node")]
+  Node,
+  /// Bun, which has the globals of Node in addition to its own.
+  #[display(fmt = "bun")]
+  Bun,
+}
+
+impl Environment {
+  pub const ALL: &'static [Environment] = &[
+    Environment::Deno,
+    Environment::Browser,
+    Environment::WebWorker,
+    Environment::ServiceWorker,
+    Environment::Node,
+    Environment::Bun,
+  ];
+
+  fn globals(self) -> &'static [&'static [(&'static str, bool)]] {
+    match self {
+      Environment::Deno => &[DENO_GLOBALS],
+      Environment::Browser => &[ES_GLOBALS, WEB_GLOBALS, BROWSER_GLOBALS],
+      Environment::WebWorker => &[
+        ES_GLOBALS,
+        WEB_GLOBALS,
+        WORKER_GLOBALS,
+        DEDICATED_WORKER_GLOBALS,
+      ],
+      Environment::ServiceWorker => &[
+        ES_GLOBALS,
+        WEB_GLOBALS,
+        WORKER_GLOBALS,
+        SERVICE_WORKER_GLOBALS,
+      ],
+      Environment::Node => &[ES_GLOBALS, WEB_GLOBALS, NODE_GLOBALS],
+      Environment::Bun => &[ES_GLOBALS, WEB_GLOBALS, NODE_GLOBALS, BUN_GLOBALS],
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[display(
+  fmt = "Unknown environment \"{}\", expected one of: deno, browser, web-worker, service-worker, node, bun",
+  _0
+)]
+pub struct UnknownEnvironment(pub String);
+
+impl std::error::Error for UnknownEnvironment {}
+
+impl FromStr for Environment {
+  type Err = UnknownEnvironment;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Environment::ALL
+      .iter()
+      .find(|env| env.to_string() == s)
+      .copied()
+      .ok_or_else(|| UnknownEnvironment(s.to_string()))
+  }
+}
+
+/// Whether code may assign to a global.
+#[derive(
+  Debug, Clone, Copy, PartialEq, Eq, Hash, Display, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum GlobalAccess {
+  #[display(fmt = "readonly")]
+  Readonly,
+  #[display(fmt = "writable")]
+  Writable,
+}
+
+/// Globals a file can use without declaring them, used by rules such as
+/// `no-undef` and `no-global-assign`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Globals {
+  /// Environments whose globals are all available, e.g. both `browser` and
+  /// `node` for code shared between them.
+  pub environments: Vec<Environment>,
+  /// Globals declared by the user, e.g. ones injected by a bundler. They take
+  /// precedence over the globals of the environments.
+  pub extra: BTreeMap<String, GlobalAccess>,
+}
+
+impl Default for Globals {
+  fn default() -> Self {
+    Self {
+      environments: vec![Environment::Deno],
+      extra: BTreeMap::new(),
+    }
+  }
+}
+
+impl Globals {
+  /// Returns how `name` can be accessed if it's a global. It's writable if
+  /// any of the environments allows writing to it.
+  pub fn get(&self, name: &str) -> Option<GlobalAccess> {
+    if let Some(access) = self.extra.get(name) {
+      return Some(*access);
+    }
+    self
+      .environments
+      .iter()
+      .flat_map(|env| env.globals().iter().copied())
+      .flatten()
+      .filter(|(global, _)| *global == name)
+      .map(|(_, writable)| {
+        if *writable {
+          GlobalAccess::Writable
+        } else {
+          GlobalAccess::Readonly
+        }
+      })
+      .max_by_key(|access| *access == GlobalAccess::Writable)
+  }
+}
+
+/// A global declared by a `/* global foo, bar: writable */` comment, which
+/// can also be spelled `/* globals ... */`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalDirective {
+  pub name: String,
+  /// `None` if the declaration is `off`, which disables the global.
+  pub access: Option<GlobalAccess>,
+  /// Range of the declaration in the comment.
+  pub range: SourceRange,
+}
+
+static GLOBAL_DIRECTIVE_RE: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^\s*globals?\s").unwrap());
+static GLOBAL_DECLARATION_RE: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"([^\s,:]+)(?:\s*:\s*([^\s,]+))?").unwrap());
+
+/// Parses the globals declared by the block comments of the file.
+///
+/// Like in ESLint, globals are readonly unless they're declared `writable`,
+/// and `true` and `false` are accepted for `writable` and `readonly`. Globals
+/// declared `off` aren't available, even if the environment has them.
+/// Declarations with any other value are ignored.
+pub(crate) fn parse_global_directives(
+  program: ast_view::Program,
+) -> Vec<GlobalDirective> {
+  let mut directives = Vec::new();
+  for comment in program.comment_container().all_comments() {
+    if comment.kind != CommentKind::Block {
+      continue;
+    }
+    let Some(directive) = GLOBAL_DIRECTIVE_RE.find(&comment.text) else {
+      continue;
+    };
+    // The text of the comment starts after `/*`.
+    let text_start = comment.range().start + 2;
+    for captures in
+      GLOBAL_DECLARATION_RE.captures_iter(&comment.text[directive.end()..])
+    {
+      let access = match captures.get(2).map(|value| value.as_str()) {
+        None | Some("readonly" | "readable" | "false") => {
+          Some(GlobalAccess::Readonly)
+        }
+        Some("writable" | "writeable" | "true") => Some(GlobalAccess::Writable),
+        Some("off") => None,
+        Some(_) => continue,
+      };
+      let declaration = captures.get(0).unwrap();
+      directives.push(GlobalDirective {
+        name: captures[1].to_string(),
+        access,
+        range: SourceRange::new(
+          text_start + directive.end() + declaration.start(),
+          text_start + directive.end() + declaration.end(),
+        ),
+      });
+    }
+  }
+  directives
+}
+
 /// List of globals available in Deno environment.
 ///
 /// Boolean tells if global can be overwritten
 ///
 /// Adapted from https://www.npmjs.com/package/globals
-pub static GLOBALS: &[(&str, bool)] = &[
+const DENO_GLOBALS: &[(&str, bool)] = &[
   ("AbortController", false),
   ("AbortSignal", false),
   ("addEventListener", false),
@@ -218,3 +434,444 @@ pub static GLOBALS: &[(&str, bool)] = &[
   ("WritableStreamDefaultController", false),
   ("WritableStreamDefaultWriter", false),
 ];
+
+/// Globals defined by ECMAScript and WebAssembly, available everywhere.
+const ES_GLOBALS: &[(&str, bool)] = &[
+  ("AggregateError", false),
+  ("Array", false),
+  ("ArrayBuffer", false),
+  ("Atomics", false),
+  ("BigInt", false),
+  ("BigInt64Array", false),
+  ("BigUint64Array", false),
+  ("Boolean", false),
+  ("DataView", false),
+  ("Date", false),
+  ("decodeURI", false),
+  ("decodeURIComponent", false),
+  ("encodeURI", false),
+  ("encodeURIComponent", false),
+  ("Error", false),
+  ("escape", false),
+  ("eval", false),
+  ("EvalError", false),
+  ("FinalizationRegistry", false),
+  ("Float16Array", false),
+  ("Float32Array", false),
+  ("Float64Array", false),
+  ("Function", false),
+  ("globalThis", false),
+  ("Infinity", false),
+  ("Int16Array", false),
+  ("Int32Array", false),
+  ("Int8Array", false),
+  ("Intl", false),
+  ("isFinite", false),
+  ("isNaN", false),
+  ("JSON", false),
+  ("Map", false),
+  ("Math", false),
+  ("NaN", false),
+  ("Number", false),
+  ("Object", false),
+  ("parseFloat", false),
+  ("parseInt", false),
+  ("Promise", false),
+  ("Proxy", false),
+  ("RangeError", false),
+  ("ReferenceError", false),
+  ("Reflect", false),
+  ("RegExp", false),
+  ("Set", false),
+  ("SharedArrayBuffer", false),
+  ("String", false),
+  ("Symbol", false),
+  ("SyntaxError", false),
+  ("TypeError", false),
+  ("Uint16Array", false),
+  ("Uint32Array", false),
+  ("Uint8Array", false),
+  ("Uint8ClampedArray", false),
+  ("undefined", false),
+  ("unescape", false),
+  ("URIError", false),
+  ("WeakMap", false),
+  ("WeakRef", false),
+  ("WeakSet", false),
+  ("WebAssembly", false),
+];
+
+/// Web APIs implemented by browsers, workers and server-side runtimes alike.
+const WEB_GLOBALS: &[(&str, bool)] = &[
+  ("AbortController", false),
+  ("AbortSignal", false),
+  ("atob", false),
+  ("Blob", false),
+  ("BroadcastChannel", false),
+  ("btoa", false),
+  ("ByteLengthQueuingStrategy", false),
+  ("clearInterval", false),
+  ("clearTimeout", false),
+  ("CompressionStream", false),
+  ("console", false),
+  ("CountQueuingStrategy", false),
+  ("crypto", false),
+  ("Crypto", false),
+  ("CryptoKey", false),
+  ("CustomEvent", false),
+  ("DecompressionStream", false),
+  ("DOMException", false),
+  ("Event", false),
+  ("EventTarget", false),
+  ("fetch", false),
+  ("File", false),
+  ("FormData", false),
+  ("Headers", false),
+  ("MessageChannel", false),
+  ("MessageEvent", false),
+  ("MessagePort", false),
+  ("navigator", false),
+  ("Navigator", false),
+  ("performance", false),
+  ("Performance", false),
+  ("PerformanceEntry", false),
+  ("PerformanceMark", false),
+  ("PerformanceMeasure", false),
+  ("queueMicrotask", false),
+  ("ReadableByteStreamController", false),
+  ("ReadableStream", false),
+  ("ReadableStreamBYOBReader", false),
+  ("ReadableStreamBYOBRequest", false),
+  ("ReadableStreamDefaultController", false),
+  ("ReadableStreamDefaultReader", false),
+  ("Request", false),
+  ("Response", false),
+  ("setInterval", false),
+  ("setTimeout", false),
+  ("structuredClone", false),
+  ("SubtleCrypto", false),
+  ("TextDecoder", false),
+  ("TextDecoderStream", false),
+  ("TextEncoder", false),
+  ("TextEncoderStream", false),
+  ("TransformStream", false),
+  ("TransformStreamDefaultController", false),
+  ("URL", false),
+  ("URLSearchParams", false),
+  ("WebSocket", false),
+  ("WritableStream", false),
+  ("WritableStreamDefaultController", false),
+  ("WritableStreamDefaultWriter", false),
+];
+
+/// Globals of the browser main thread. The DOM is large, so only its most
+/// commonly used interfaces are listed.
+const BROWSER_GLOBALS: &[(&str, bool)] = &[
+  ("addEventListener", false),
+  ("alert", false),
+  ("Audio", false),
+  ("blur", false),
+  ("Cache", false),
+  ("caches", false),
+  ("CacheStorage", false),
+  ("cancelAnimationFrame", false),
+  ("cancelIdleCallback", false),
+  ("CanvasRenderingContext2D", false),
+  ("close", false),
+  ("closed", false),
+  ("CloseEvent", false),
+  ("Comment", false),
+  ("confirm", false),
+  ("createImageBitmap", false),
+  ("customElements", false),
+  ("devicePixelRatio", false),
+  ("dispatchEvent", false),
+  ("document", false),
+  ("Document", false),
+  ("DocumentFragment", false),
+  ("DOMParser", false),
+  ("Element", false),
+  ("ErrorEvent", false),
+  ("EventSource", false),
+  ("FileReader", false),
+  ("focus", false),
+  ("FocusEvent", false),
+  ("frames", false),
+  ("getComputedStyle", false),
+  ("getSelection", false),
+  ("history", false),
+  ("History", false),
+  ("HTMLCanvasElement", false),
+  ("HTMLElement", false),
+  ("HTMLInputElement", false),
+  ("Image", false),
+  ("ImageBitmap", false),
+  ("ImageData", false),
+  ("indexedDB", false),
+  ("innerHeight", false),
+  ("innerWidth", false),
+  ("InputEvent", false),
+  ("IntersectionObserver", false),
+  ("isSecureContext", false),
+  ("KeyboardEvent", false),
+  ("localStorage", false),
+  ("location", true),
+  ("Location", false),
+  ("matchMedia", false),
+  ("MouseEvent", false),
+  ("MutationObserver", false),
+  ("name", true),
+  ("Node", false),
+  ("NodeList", false),
+  ("Notification", false),
+  ("onbeforeunload", true),
+  ("onclick", true),
+  ("onerror", true),
+  ("onhashchange", true),
+  ("onkeydown", true),
+  ("onkeyup", true),
+  ("onload", true),
+  ("onmessage", true),
+  ("onmessageerror", true),
+  ("onpopstate", true),
+  ("onresize", true),
+  ("onscroll", true),
+  ("onunhandledrejection", true),
+  ("onunload", true),
+  ("open", false),
+  ("opener", true),
+  ("origin", false),
+  ("parent", false),
+  ("PointerEvent", false),
+  ("postMessage", false),
+  ("ProgressEvent", false),
+  ("PromiseRejectionEvent", false),
+  ("prompt", false),
+  ("removeEventListener", false),
+  ("reportError", false),
+  ("requestAnimationFrame", false),
+  ("requestIdleCallback", false),
+  ("ResizeObserver", false),
+  ("screen", false),
+  ("scroll", false),
+  ("scrollBy", false),
+  ("scrollTo", false),
+  ("scrollX", false),
+  ("scrollY", false),
+  ("self", false),
+  ("sessionStorage", false),
+  ("ServiceWorker", false),
+  ("SharedWorker", false),
+  ("status", true),
+  ("Storage", false),
+  ("Text", false),
+  ("top", false),
+  ("window", false),
+  ("Window", false),
+  ("Worker", false),
+  ("XMLHttpRequest", false),
+];
+
+/// Globals shared by all kinds of web workers.
+const WORKER_GLOBALS: &[(&str, bool)] = &[
+  ("addEventListener", false),
+  ("Cache", false),
+  ("caches", false),
+  ("CacheStorage", false),
+  ("CloseEvent", false),
+  ("createImageBitmap", false),
+  ("dispatchEvent", false),
+  ("ErrorEvent", false),
+  ("EventSource", false),
+  ("FileReader", false),
+  ("FileReaderSync", false),
+  ("ImageBitmap", false),
+  ("ImageData", false),
+  ("importScripts", false),
+  ("indexedDB", false),
+  ("isSecureContext", false),
+  ("location", false),
+  ("Notification", false),
+  ("OffscreenCanvas", false),
+  ("onerror", true),
+  ("onunhandledrejection", true),
+  ("origin", false),
+  ("ProgressEvent", false),
+  ("PromiseRejectionEvent", false),
+  ("removeEventListener", false),
+  ("reportError", false),
+  ("self", false),
+  ("WorkerGlobalScope", false),
+  ("WorkerLocation", false),
+  ("WorkerNavigator", false),
+  ("XMLHttpRequest", false),
+];
+
+/// Globals of dedicated web workers, in addition to `WORKER_GLOBALS`.
+const DEDICATED_WORKER_GLOBALS: &[(&str, bool)] = &[
+  ("cancelAnimationFrame", false),
+  ("close", false),
+  ("DedicatedWorkerGlobalScope", false),
+  ("name", false),
+  ("onmessage", true),
+  ("onmessageerror", true),
+  ("postMessage", false),
+  ("requestAnimationFrame", false),
+];
+
+/// Globals of service workers, in addition to `WORKER_GLOBALS`.
+const SERVICE_WORKER_GLOBALS: &[(&str, bool)] = &[
+  ("Client", false),
+  ("clients", false),
+  ("Clients", false),
+  ("ExtendableEvent", false),
+  ("ExtendableMessageEvent", false),
+  ("FetchEvent", false),
+  ("NotificationEvent", false),
+  ("onactivate", true),
+  ("onfetch", true),
+  ("oninstall", true),
+  ("onmessage", true),
+  ("onmessageerror", true),
+  ("onnotificationclick", true),
+  ("onnotificationclose", true),
+  ("onpush", true),
+  ("onsync", true),
+  ("PushEvent", false),
+  ("registration", false),
+  ("serviceWorker", false),
+  ("ServiceWorkerGlobalScope", false),
+  ("skipWaiting", false),
+  ("WindowClient", false),
+];
+
+/// Globals of Node.js, including the ones of CommonJS modules.
+const NODE_GLOBALS: &[(&str, bool)] = &[
+  ("__dirname", false),
+  ("__filename", false),
+  ("Buffer", false),
+  ("clearImmediate", false),
+  ("exports", true),
+  ("global", false),
+  ("module", false),
+  ("process", false),
+  ("require", false),
+  ("setImmediate", false),
+];
+
+/// Globals of Bun, in addition to `NODE_GLOBALS`.
+const BUN_GLOBALS: &[(&str, bool)] = &[
+  ("addEventListener", false),
+  ("alert", false),
+  ("BuildMessage", false),
+  ("Bun", false),
+  ("confirm", false),
+  ("dispatchEvent", false),
+  ("HTMLRewriter", false),
+  ("prompt", false),
+  ("removeEventListener", false),
+  ("reportError", false),
+  ("ResolveMessage", false),
+  ("self", false),
+];
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util;
+  use deno_ast::{SourceRanged, SourceTextInfoProvider};
+
+  #[test]
+  fn environment_globals() {
+    let globals = |environments: &[Environment]| Globals {
+      environments: environments.to_vec(),
+      extra: BTreeMap::new(),
+    };
+
+    let deno = Globals::default();
+    assert_eq!(deno.get("Deno"), Some(GlobalAccess::Readonly));
+    assert_eq!(deno.get("onload"), Some(GlobalAccess::Writable));
+    assert_eq!(deno.get("document"), None);
+    assert_eq!(deno.get("process"), None);
+
+    let browser = globals(&[Environment::Browser]);
+    assert_eq!(browser.get("document"), Some(GlobalAccess::Readonly));
+    assert_eq!(browser.get("Deno"), None);
+    assert_eq!(browser.get("require"), None);
+
+    let worker = globals(&[Environment::WebWorker]);
+    assert_eq!(worker.get("importScripts"), Some(GlobalAccess::Readonly));
+    assert_eq!(worker.get("document"), None);
+    assert_eq!(worker.get("skipWaiting"), None);
+
+    let service_worker = globals(&[Environment::ServiceWorker]);
+    assert_eq!(service_worker.get("clients"), Some(GlobalAccess::Readonly));
+    assert_eq!(service_worker.get("postMessage"), None);
+
+    let bun = globals(&[Environment::Bun]);
+    assert_eq!(bun.get("Bun"), Some(GlobalAccess::Readonly));
+    assert_eq!(bun.get("process"), Some(GlobalAccess::Readonly));
+    assert_eq!(bun.get("Array"), Some(GlobalAccess::Readonly));
+
+    // Globals of all environments are available, and writable if any of
+    // them allows it.
+    let mut shared = globals(&[Environment::Node, Environment::Browser]);
+    assert_eq!(shared.get("process"), Some(GlobalAccess::Readonly));
+    assert_eq!(shared.get("name"), Some(GlobalAccess::Writable));
+    assert_eq!(shared.get("exports"), Some(GlobalAccess::Writable));
+
+    shared
+      .extra
+      .insert("process".to_string(), GlobalAccess::Writable);
+    shared
+      .extra
+      .insert("__APP_VERSION__".to_string(), GlobalAccess::Readonly);
+    assert_eq!(shared.get("process"), Some(GlobalAccess::Writable));
+    assert_eq!(shared.get("__APP_VERSION__"), Some(GlobalAccess::Readonly));
+  }
+
+  #[test]
+  fn parse_environment() {
+    for env in Environment::ALL {
+      assert_eq!(env.to_string().parse::<Environment>(), Ok(*env));
+    }
+    assert_eq!(
+      "worker".parse::<Environment>(),
+      Err(UnknownEnvironment("worker".to_string()))
+    );
+  }
+
+  #[test]
+  fn test_parse_global_directives() {
+    let source_code = r#"
+/* global foo, bar: writable */
+/*globals baz:false qux : true,quux:off corge:maybe */
+// global notADirective
+/* not global */
+"#;
+
+    test_util::parse_and_then(source_code, |program| {
+      let directives = parse_global_directives(program);
+      let declared = directives
+        .iter()
+        .map(|d| {
+          (
+            d.name.as_str(),
+            d.access,
+            d.range.text_fast(program.text_info()),
+          )
+        })
+        .collect::<Vec<_>>();
+      assert_eq!(
+        declared,
+        vec![
+          ("foo", Some(GlobalAccess::Readonly), "foo"),
+          ("bar", Some(GlobalAccess::Writable), "bar: writable"),
+          ("baz", Some(GlobalAccess::Readonly), "baz:false"),
+          ("qux", Some(GlobalAccess::Writable), "qux : true"),
+          ("quux", None, "quux:off"),
+        ]
+      );
+    });
+  }
+}
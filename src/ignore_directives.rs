@@ -15,7 +15,9 @@ use std::collections::HashMap;
 pub type LineIgnoreDirective = IgnoreDirective<Line>;
 pub type FileIgnoreDirective = IgnoreDirective<File>;
 
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Line {}
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum File {}
 pub trait DirectiveKind {}
 impl DirectiveKind for Line {}
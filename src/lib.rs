@@ -14,54 +14,62 @@ pub mod ast_parser;
 pub mod context;
 mod control_flow;
 pub mod diagnostic;
-mod globals;
-mod handler;
+pub mod file_scope;
+pub mod fix;
+pub mod globals;
+pub mod handler;
 mod ignore_directives;
 mod js_regex;
+pub mod lint_cache;
 pub mod linter;
 mod performance_mark;
+#[cfg(feature = "plugins")]
+pub mod plugin;
+pub mod presets;
 pub mod rules;
+pub mod sarif;
 pub mod swc_util;
 pub mod tags;
+pub mod timing;
 
 pub use deno_ast::view::Program;
 pub use deno_ast::view::ProgramRef;
 
 #[cfg(test)]
 mod lint_tests {
-  use std::collections::HashSet;
+  use std::collections::{HashMap, HashSet};
 
-  use crate::diagnostic::LintDiagnostic;
+  use crate::diagnostic::{LintDiagnostic, LintSeverity, PARSE_ERROR_CODE};
   use crate::linter::*;
   use crate::rules::{get_all_rules, recommended_rules, LintRule};
-  use crate::test_util::{assert_diagnostic, parse};
+  use crate::test_util::{
+    assert_diagnostic, lint_file, lint_file_options, linter, parse,
+  };
   use deno_ast::ParsedSource;
-  use deno_ast::{MediaType, ModuleSpecifier};
 
   fn lint(
     source: &str,
     rules: Vec<Box<dyn LintRule>>,
     all_rule_codes: HashSet<&'static str>,
   ) -> Vec<LintDiagnostic> {
-    let linter = Linter::new(LinterOptions {
+    lint_with_severities(source, rules, all_rule_codes, HashMap::new())
+  }
+
+  fn lint_with_severities(
+    source: &str,
+    rules: Vec<Box<dyn LintRule>>,
+    all_rule_codes: HashSet<&'static str>,
+    severity_overrides: HashMap<String, LintSeverity>,
+  ) -> Vec<LintDiagnostic> {
+    let linter = linter(
       rules,
-      all_rule_codes,
-      custom_ignore_diagnostic_directive: None,
-      custom_ignore_file_directive: None,
-    });
-
-    let (_, diagnostics) = linter
-      .lint_file(LintFileOptions {
-        specifier: ModuleSpecifier::parse("file:///lint_test.ts").unwrap(),
-        source_code: source.to_string(),
-        media_type: MediaType::TypeScript,
-        config: LintConfig {
-          default_jsx_factory: None,
-          default_jsx_fragment_factory: None,
-        },
-      })
-      .expect("Failed to lint");
-    diagnostics
+      LinterOptions {
+        all_rule_codes,
+        severity_overrides,
+        ..Default::default()
+      },
+    );
+    lint_file(&linter, source, Default::default())
   }
 
   fn lint_with_ast(
@@ -69,17 +77,19 @@ mod lint_tests {
     rules: Vec<Box<dyn LintRule>>,
     all_rule_codes: HashSet<&'static str>,
   ) -> Vec<LintDiagnostic> {
-    let linter = Linter::new(LinterOptions {
+    let linter = linter(
       rules,
-      all_rule_codes,
-      custom_ignore_diagnostic_directive: None,
-      custom_ignore_file_directive: None,
-    });
+      LinterOptions {
+        all_rule_codes,
+        ..Default::default()
+      },
+    );
     linter.lint_with_ast(
       parsed_source,
       LintConfig {
         default_jsx_factory: None,
         default_jsx_fragment_factory: None,
+        globals: Default::default(),
       },
     )
   }
@@ -293,10 +303,600 @@ const _foo = 42;
     assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 4, 1, src);
   }
 
+  #[test]
+  fn severity_overrides() {
+    use deno_ast::diagnostics::{Diagnostic, DiagnosticLevel};
+
+    let src = "a == b; debugger;";
+    let diagnostics = lint_with_severities(
+      src,
+      recommended_rules(get_all_rules()),
+      HashSet::new(),
+      HashMap::from([("no-debugger".to_string(), LintSeverity::Warning)]),
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].details.code, "no-debugger");
+    assert_eq!(diagnostics[0].details.severity, LintSeverity::Warning);
+    assert!(matches!(diagnostics[0].level(), DiagnosticLevel::Warning));
+
+    let diagnostics = lint_with_severities(
+      src,
+      recommended_rules(get_all_rules()),
+      HashSet::new(),
+      HashMap::from([("no-debugger".to_string(), LintSeverity::Off)]),
+    );
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = lint_recommended_rules(src);
+    assert_eq!(diagnostics[0].details.severity, LintSeverity::Error);
+  }
+
   #[test]
   fn empty_file_with_ast() {
     let parsed_source = parse("");
     let diagnostics = lint_recommended_rules_with_ast(&parsed_source);
     assert!(diagnostics.is_empty());
   }
+
+  #[test]
+  fn incremental_lint_reuses_cached_results() {
+    use crate::context::Context;
+    use crate::rules::eqeqeq::Eqeqeq;
+    use deno_ast::SourceRanged;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug)]
+    struct CountingRule;
+
+    impl LintRule for CountingRule {
+      fn code(&self) -> &'static str {
+        "counting-rule"
+      }
+
+      fn lint_program_with_ast_view(
+        &self,
+        context: &mut Context,
+        program: crate::Program,
+      ) {
+        RUNS.fetch_add(1, Ordering::SeqCst);
+        context.add_diagnostic(program.range(), "counting-rule", "counted");
+      }
+    }
+
+    let new_linter =
+      |rules: Vec<Box<dyn LintRule>>| linter(rules, Default::default());
+    let lint =
+      |linter: &Linter, cache: &mut IncrementalLintCache, source: &str| {
+        let (_, diagnostics) = linter
+          .lint_file_incremental(
+            cache,
+            lint_file_options("file:///lint_test.ts", source),
+          )
+          .unwrap();
+        diagnostics
+          .into_iter()
+          .map(|d| d.details.code)
+          .collect::<Vec<_>>()
+      };
+
+    let mut cache = IncrementalLintCache::new();
+    let linter = new_linter(vec![Box::new(CountingRule)]);
+    assert_eq!(lint(&linter, &mut cache, "a == b;"), vec!["counting-rule"]);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // Same source, nothing is run again.
+    assert_eq!(lint(&linter, &mut cache, "a == b;"), vec!["counting-rule"]);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // Same source with a different rule set, only the new rule is run.
+    let linter_with_eqeqeq =
+      new_linter(vec![Box::new(CountingRule), Box::new(Eqeqeq::default())]);
+    assert_eq!(
+      lint(&linter_with_eqeqeq, &mut cache, "a == b;"),
+      vec!["counting-rule", "eqeqeq"]
+    );
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // Cached diagnostics still go through ignore directives.
+    assert_eq!(
+      lint(
+        &linter_with_eqeqeq,
+        &mut cache,
+        "// deno-lint-ignore eqeqeq\na == b;"
+      ),
+      vec!["counting-rule"]
+    );
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+
+    // The source changed, so the rule is run again.
+    assert_eq!(lint(&linter, &mut cache, "a === b;"), vec!["counting-rule"]);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 3);
+    assert_eq!(cache.len(), 1);
+
+    // Undoing the edit reuses the results of the previous source.
+    assert_eq!(lint(&linter, &mut cache, "a == b;"), vec!["counting-rule"]);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 3);
+
+    // Only the last versions are kept.
+    for source in ["a;", "b;", "c;", "d;"] {
+      lint(&linter, &mut cache, source);
+    }
+    assert_eq!(RUNS.load(Ordering::SeqCst), 7);
+    lint(&linter, &mut cache, "a == b;");
+    assert_eq!(RUNS.load(Ordering::SeqCst), 8);
+    assert_eq!(cache.len(), 1);
+  }
+
+  #[test]
+  fn lint_file_incremental_foreign_codes() {
+    use crate::context::Context;
+    use crate::handler::Handler;
+    use crate::rules::eqeqeq::Eqeqeq;
+    use deno_ast::view as ast_view;
+    use deno_ast::SourceRanged;
+
+    // Reports diagnostics with a code other than its own.
+    #[derive(Debug)]
+    struct ForeignCodeRule;
+
+    impl LintRule for ForeignCodeRule {
+      fn code(&self) -> &'static str {
+        "foreign-code-rule"
+      }
+
+      fn handler(&self) -> Option<Box<dyn Handler>> {
+        Some(Box::new(ForeignCodeHandler))
+      }
+    }
+
+    struct ForeignCodeHandler;
+
+    impl Handler for ForeignCodeHandler {
+      fn ident(&mut self, n: &ast_view::Ident, ctx: &mut Context) {
+        ctx.add_diagnostic(n.range(), "foreign-code", "foreign");
+      }
+    }
+
+    let linter = linter(
+      vec![Box::new(ForeignCodeRule), Box::new(Eqeqeq::default())],
+      Default::default(),
+    );
+    let mut cache = IncrementalLintCache::new();
+    let mut lint = || {
+      let (_, diagnostics) = linter
+        .lint_file_incremental(
+          &mut cache,
+          lint_file_options("file:///lint_test.ts", "a == b;"),
+        )
+        .unwrap();
+      diagnostics
+        .into_iter()
+        .map(|d| d.details.code)
+        .collect::<Vec<_>>()
+    };
+
+    let expected = vec!["eqeqeq", "foreign-code", "foreign-code"];
+    assert_eq!(lint(), expected);
+    // Cached diagnostics are attributed to the rule reporting them.
+    assert_eq!(lint(), expected);
+  }
+
+  #[cfg(feature = "parallel")]
+  #[test]
+  fn lint_files_in_parallel() {
+    let linter = linter(
+      recommended_rules(get_all_rules()),
+      LinterOptions {
+        all_rule_codes: get_all_rules()
+          .into_iter()
+          .map(|rule| rule.code())
+          .collect(),
+        ..Default::default()
+      },
+    );
+    let files = || {
+      [
+        "debugger;",
+        "let = ;",
+        "const _a = 1;",
+        "// deno-lint-ignore parse-error no-with\nwith ({}) {\n  0;\n}",
+      ]
+      .iter()
+      .enumerate()
+      .map(|(i, source)| {
+        lint_file_options(&format!("file:///{}.ts", i), source)
+      })
+      .collect::<Vec<_>>()
+    };
+
+    let results = linter
+      .lint_files(files(), &CancellationToken::new())
+      .unwrap();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].specifier.as_str(), "file:///0.ts");
+    assert_eq!(results[0].diagnostics.len(), 1);
+    assert!(results[1].parsed_source.is_none());
+    assert_eq!(results[1].diagnostics[0].details.code, PARSE_ERROR_CODE);
+    assert!(results[2].diagnostics.is_empty());
+    assert_eq!(
+      results.iter().map(|r| r.error_count()).collect::<Vec<_>>(),
+      vec![1, 1, 0, 0]
+    );
+
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    assert!(matches!(
+      linter.lint_files(files(), &cancellation),
+      Err(LintCancelled)
+    ));
+  }
+
+  #[test]
+  fn parse_errors_as_diagnostics() {
+    let linter = linter(
+      vec![Box::new(crate::rules::no_debugger::NoDebugger)],
+      LinterOptions {
+        all_rule_codes: get_all_rules()
+          .into_iter()
+          .map(|rule| rule.code())
+          .collect(),
+        ..Default::default()
+      },
+    );
+    let lint = |source: &str| {
+      linter.lint_file_with_parse_errors(lint_file_options(
+        "file:///lint_test.ts",
+        source,
+      ))
+    };
+
+    let src = "let = ;";
+    let (parsed_source, diagnostics) = lint(src);
+    assert!(parsed_source.is_none());
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], PARSE_ERROR_CODE, 1, 6, src);
+
+    let src = "with (a) {}\ndebugger;";
+    let (parsed_source, diagnostics) = lint(src);
+    assert!(parsed_source.is_some());
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], PARSE_ERROR_CODE, 1, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-debugger", 2, 0, src);
+
+    let (_, diagnostics) = lint("// deno-lint-ignore parse-error\nwith (a) {}");
+    assert!(diagnostics.is_empty());
+
+    let src = "// deno-lint-ignore parse-error\na;";
+    let (_, diagnostics) = lint(src);
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "ban-unused-ignore", 1, 0, src);
+  }
+
+  #[test]
+  fn parse_error_severity() {
+    let lint = |severity: LintSeverity, source: &str| {
+      let linter = linter(
+        vec![],
+        LinterOptions {
+          severity_overrides: HashMap::from([(
+            PARSE_ERROR_CODE.to_string(),
+            severity,
+          )]),
+          ..Default::default()
+        },
+      );
+      let (_, diagnostics) = linter.lint_file_with_parse_errors(
+        lint_file_options("file:///lint_test.ts", source),
+      );
+      diagnostics
+        .into_iter()
+        .map(|d| d.details.severity)
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+      lint(LintSeverity::Warning, "with (a) {}"),
+      vec![LintSeverity::Warning]
+    );
+    assert_eq!(
+      lint(LintSeverity::Warning, "let = ;"),
+      vec![LintSeverity::Warning]
+    );
+    assert!(lint(LintSeverity::Off, "with (a) {}").is_empty());
+    assert!(lint(LintSeverity::Off, "let = ;").is_empty());
+  }
+
+  #[test]
+  fn rule_options() {
+    let linter_options = |rule_options: serde_json::Value| LinterOptions {
+      rules: vec![Box::new(crate::rules::eqeqeq::Eqeqeq::default())],
+      rule_options: serde_json::from_value(rule_options).unwrap(),
+      all_rule_codes: get_all_rules()
+        .into_iter()
+        .map(|rule| rule.code())
+        .collect(),
+      ..Default::default()
+    };
+    let lint =
+      |linter: &Linter| lint_file(linter, "a == null;", Default::default());
+
+    let linter = Linter::try_new(linter_options(serde_json::json!({})));
+    assert_eq!(lint(&linter.unwrap()).len(), 1);
+
+    // Options of known rules that aren't enabled are ignored.
+    let linter = Linter::try_new(linter_options(serde_json::json!({
+      "eqeqeq": { "allowNull": true },
+      "no-console": { "allow": ["error"] },
+    })));
+    assert!(lint(&linter.unwrap()).is_empty());
+
+    let err = Linter::try_new(linter_options(serde_json::json!({
+      "eqeqeq": { "allowNull": 1 },
+    })))
+    .unwrap_err();
+    assert_eq!(err.code, "eqeqeq");
+
+    let err = Linter::try_new(linter_options(serde_json::json!({
+      "no-such-rule": null,
+    })))
+    .unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Invalid options for rule \"no-such-rule\": unknown rule"
+    );
+  }
+
+  #[test]
+  fn timing_report() {
+    use crate::timing::{PARSE_PHASE, RULES_PHASE, SCOPE_PHASE};
+
+    let mut linter = linter(
+      vec![
+        Box::new(crate::rules::no_debugger::NoDebugger),
+        Box::new(crate::rules::no_undef::NoUndef),
+      ],
+      Default::default(),
+    );
+    assert!(linter.timing_report().is_none());
+    linter.enable_timing();
+
+    for source in ["debugger;", "a + b;"] {
+      lint_file(&linter, source, Default::default());
+    }
+
+    let report = linter.timing_report().unwrap();
+    assert_eq!(report.files(), 2);
+    let phases = report
+      .phases()
+      .into_iter()
+      .map(|(phase, _)| phase)
+      .collect::<HashSet<_>>();
+    assert!(phases.contains(PARSE_PHASE));
+    assert!(phases.contains(SCOPE_PHASE));
+    assert!(phases.contains(RULES_PHASE));
+    let mut rules = report
+      .rules()
+      .into_iter()
+      .map(|(code, _)| code)
+      .collect::<Vec<_>>();
+    rules.sort();
+    assert_eq!(rules, vec!["no-debugger", "no-undef"]);
+  }
+
+  #[test]
+  fn timing_report_without_analysis() {
+    use crate::timing::{CONTROL_FLOW_PHASE, RULES_PHASE, SCOPE_PHASE};
+
+    let mut linter = linter(
+      vec![Box::new(crate::rules::no_debugger::NoDebugger)],
+      Default::default(),
+    );
+    linter.enable_timing();
+    lint_file(
+      &linter,
+      "function f() { debugger; return 1; }",
+      Default::default(),
+    );
+
+    // Scope and control-flow analysis are only done when a rule needs them.
+    let report = linter.timing_report().unwrap();
+    let phases = report
+      .phases()
+      .into_iter()
+      .map(|(phase, _)| phase)
+      .collect::<HashSet<_>>();
+    assert!(phases.contains(RULES_PHASE));
+    assert!(!phases.contains(SCOPE_PHASE));
+    assert!(!phases.contains(CONTROL_FLOW_PHASE));
+  }
+
+  #[test]
+  fn globals_of_environments() {
+    use crate::globals::{Environment, GlobalAccess, Globals};
+
+    let linter = linter(
+      vec![
+        Box::new(crate::rules::no_undef::NoUndef),
+        Box::new(crate::rules::no_global_assign::NoGlobalAssign),
+        Box::new(crate::rules::no_node_globals::NoNodeGlobals),
+      ],
+      Default::default(),
+    );
+    let lint =
+      |source: &str, globals: Globals| lint_file(&linter, source, globals);
+
+    let src = "document.title = Deno.version.deno;\nBuffer.from(\"\");";
+    let diagnostics = lint(src, Globals::default());
+    assert_eq!(diagnostics.len(), 3);
+    assert_diagnostic(&diagnostics[0], "no-undef", 1, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-node-globals", 2, 0, src);
+    assert_diagnostic(&diagnostics[2], "no-undef", 2, 0, src);
+
+    // Code shared between the browser and Node.
+    let globals = Globals {
+      environments: vec![Environment::Browser, Environment::Node],
+      extra: [
+        ("__DEV__".to_string(), GlobalAccess::Readonly),
+        ("__state__".to_string(), GlobalAccess::Writable),
+      ]
+      .into_iter()
+      .collect(),
+    };
+    let diagnostics = lint(src, globals.clone());
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-undef", 1, 17, src);
+
+    let src = "__state__ = __DEV__;\n__DEV__ = false;\nprocess = null;";
+    let diagnostics = lint(src, globals);
+    assert_eq!(diagnostics.len(), 2);
+    assert_diagnostic(&diagnostics[0], "no-global-assign", 2, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-global-assign", 3, 0, src);
+  }
+
+  #[test]
+  fn deno_rules_in_other_environments() {
+    use crate::globals::{Environment, Globals};
+
+    let linter = linter(
+      vec![
+        Box::new(crate::rules::no_window::NoWindow),
+        Box::new(crate::rules::no_window_prefix::NoWindowPrefix),
+        Box::new(crate::rules::no_process_global::NoProcessGlobal),
+      ],
+      Default::default(),
+    );
+    let lint = |environments: Vec<Environment>| {
+      let globals = Globals {
+        environments,
+        extra: Default::default(),
+      };
+      lint_file(
+        &linter,
+        "window.fetch(\"\");\nwindow;\nprocess.exit();",
+        globals,
+      )
+      .iter()
+      .map(|d| d.details.code.clone())
+      .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+      lint(vec![Environment::Deno]),
+      vec![
+        "no-window",
+        "no-window-prefix",
+        "no-window",
+        "no-process-global"
+      ]
+    );
+    assert_eq!(lint(vec![Environment::Browser]), vec!["no-process-global"]);
+    assert_eq!(
+      lint(vec![Environment::Node]),
+      vec!["no-window", "no-window-prefix", "no-window"]
+    );
+    assert!(lint(vec![Environment::Browser, Environment::Node]).is_empty());
+  }
+
+  #[test]
+  fn global_directives() {
+    let src = r#"/* global $, ga: writable, Deno: off */
+$("body");
+ga = null;
+$ = null;
+jQuery;
+Deno.exit();"#;
+    let diagnostics = lint(
+      src,
+      vec![
+        Box::new(crate::rules::no_undef::NoUndef),
+        Box::new(crate::rules::no_global_assign::NoGlobalAssign),
+      ],
+      HashSet::new(),
+    );
+    assert_eq!(diagnostics.len(), 3);
+    assert_diagnostic(&diagnostics[0], "no-global-assign", 4, 0, src);
+    assert_diagnostic(&diagnostics[1], "no-undef", 5, 0, src);
+    assert_diagnostic(&diagnostics[2], "no-undef", 6, 0, src);
+  }
+
+  #[test]
+  fn file_scopes() {
+    use crate::file_scope::FileScope;
+
+    let linter = linter(
+      vec![
+        Box::new(crate::rules::no_debugger::NoDebugger),
+        Box::new(crate::rules::fresh_handler_export::FreshHandlerExport),
+      ],
+      LinterOptions {
+        file_scopes: HashMap::from([
+          (
+            "no-debugger".to_string(),
+            FileScope::new(vec![], vec!["scripts/**".to_string()]).unwrap(),
+          ),
+          (
+            "fresh-handler-export".to_string(),
+            FileScope::new(vec![], vec!["**/_*.ts".to_string()]).unwrap(),
+          ),
+        ]),
+        ..Default::default()
+      },
+    );
+    let lint = |specifier: &str| {
+      let (_, diagnostics) = linter
+        .lint_file(lint_file_options(
+          specifier,
+          "debugger;\nexport const handlers = {};",
+        ))
+        .unwrap();
+      diagnostics
+        .iter()
+        .map(|d| d.details.code.clone())
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(lint("file:///app/main.ts"), vec!["no-debugger"]);
+    assert!(lint("file:///app/scripts/build.ts").is_empty());
+    // Rules can also have a default scope, which is narrowed by the
+    // configured one.
+    assert_eq!(
+      lint("file:///app/routes/index.ts"),
+      vec!["no-debugger", "fresh-handler-export"]
+    );
+    assert_eq!(lint("file:///app/routes/_app.ts"), vec!["no-debugger"]);
+    assert_eq!(lint("file:///app/lib/index.ts"), vec!["no-debugger"]);
+  }
+
+  #[test]
+  fn snippet_fixed() {
+    use deno_ast::diagnostics::{Diagnostic, DiagnosticSourcePos};
+
+    let src = "const a = 1;\nwindow.fetch(window.location);\n";
+    let diagnostics = lint(
+      src,
+      vec![Box::new(crate::rules::no_window::NoWindow)],
+      HashSet::new(),
+    );
+    assert_eq!(diagnostics.len(), 2);
+
+    let snippet = diagnostics[0].snippet_fixed().unwrap();
+    assert_eq!(
+      snippet.source.text_str(),
+      "const a = 1;\nglobalThis.fetch(window.location);\n"
+    );
+    assert_eq!(snippet.highlights.len(), 1);
+    let range = &snippet.highlights[0].range;
+    assert!(matches!(range.start, DiagnosticSourcePos::ByteIndex(13)));
+    assert!(matches!(range.end, DiagnosticSourcePos::ByteIndex(23)));
+
+    // Diagnostics without fixes have no preview.
+    let diagnostics = lint(
+      "debugger;",
+      vec![Box::new(crate::rules::no_debugger::NoDebugger)],
+      HashSet::new(),
+    );
+    assert!(diagnostics[0].snippet_fixed().is_none());
+  }
 }
@@ -0,0 +1,407 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! On-disk cache of lint results, used by `Linter::lint_file_cached` to skip
+//! files that didn't change since they were last linted with the same
+//! configuration.
+
+use crate::diagnostic::{
+  LintDiagnostic, LintDiagnosticDetails, LintDiagnosticRange, LintFix,
+  LintFixChange, LintFixKind, LintSeverity,
+};
+use crate::file_scope::FileScope;
+use crate::rules::LintRule;
+use deno_ast::{ModuleSpecifier, SourceRange, SourceTextInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Version of the crate, part of every cache key so that results produced by
+/// a different version of the rules are never reused.
+const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Cache of lint results keyed by the content of each file and the
+/// configuration of the linter.
+///
+/// The cache can be saved to and restored from a JSON file, e.g. between CI
+/// runs. Entries of a cache written by another version of the crate are
+/// discarded when it's loaded.
+#[derive(Debug, Default)]
+pub struct LintCache {
+  files: Mutex<HashMap<String, CachedFile>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFileContents {
+  version: String,
+  files: HashMap<String, CachedFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedFile {
+  /// Hash of the file contents and the configuration it was linted with,
+  /// as a hex string.
+  hash: String,
+  diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedDiagnostic {
+  range: Option<CachedRange>,
+  message: String,
+  code: String,
+  severity: LintSeverity,
+  hint: Option<String>,
+  fixes: Vec<CachedFix>,
+  custom_docs_url: Option<String>,
+  info: Vec<String>,
+}
+
+/// Byte offsets of a range within the file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedRange {
+  start: usize,
+  end: usize,
+  description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedFix {
+  description: String,
+  #[serde(default)]
+  kind: LintFixKind,
+  changes: Vec<CachedFixChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedFixChange {
+  start: usize,
+  end: usize,
+  new_text: String,
+}
+
+impl LintCache {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Restore a cache saved with `LintCache::save`. A missing file results in
+  /// an empty cache.
+  pub fn load(path: &Path) -> std::io::Result<Self> {
+    match std::fs::read_to_string(path) {
+      Ok(text) => Self::from_json(&text),
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
+      Err(err) => Err(err),
+    }
+  }
+
+  /// Write the cache to `path`, replacing the file if it exists.
+  pub fn save(&self, path: &Path) -> std::io::Result<()> {
+    std::fs::write(path, self.to_json()?)
+  }
+
+  pub fn from_json(text: &str) -> std::io::Result<Self> {
+    let contents: CacheFileContents = serde_json::from_str(text)?;
+    if contents.version != CRATE_VERSION {
+      return Ok(Self::new());
+    }
+    Ok(Self {
+      files: Mutex::new(contents.files),
+    })
+  }
+
+  pub fn to_json(&self) -> std::io::Result<String> {
+    let contents = CacheFileContents {
+      version: CRATE_VERSION.to_string(),
+      files: self.files.lock().unwrap().clone(),
+    };
+    Ok(serde_json::to_string(&contents)?)
+  }
+
+  /// Number of files in the cache.
+  pub fn len(&self) -> usize {
+    self.files.lock().unwrap().len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.files.lock().unwrap().is_empty()
+  }
+
+  /// Drop entries of files that don't satisfy `predicate`, e.g. files that
+  /// were deleted since the cache was saved.
+  pub fn retain(&self, mut predicate: impl FnMut(&ModuleSpecifier) -> bool) {
+    self.files.lock().unwrap().retain(|specifier, _| {
+      ModuleSpecifier::parse(specifier).map_or(false, |s| predicate(&s))
+    });
+  }
+
+  /// Get the diagnostics of a file if it was linted with the same `hash`.
+  pub(crate) fn get(
+    &self,
+    specifier: &ModuleSpecifier,
+    hash: u64,
+    text_info: &SourceTextInfo,
+  ) -> Option<Vec<LintDiagnostic>> {
+    let files = self.files.lock().unwrap();
+    let cached = files.get(specifier.as_str())?;
+    if cached.hash != format!("{:016x}", hash) {
+      return None;
+    }
+
+    let start = text_info.range().start;
+    let range = |start_offset: usize, end_offset: usize| {
+      SourceRange::new(start + start_offset, start + end_offset)
+    };
+    Some(
+      cached
+        .diagnostics
+        .iter()
+        .map(|d| LintDiagnostic {
+          specifier: specifier.clone(),
+          range: d.range.as_ref().map(|r| LintDiagnosticRange {
+            text_info: text_info.clone(),
+            range: range(r.start, r.end),
+            description: r.description.clone(),
+          }),
+          details: LintDiagnosticDetails {
+            message: d.message.clone(),
+            code: d.code.clone(),
+            severity: d.severity,
+            hint: d.hint.clone(),
+            fixes: d
+              .fixes
+              .iter()
+              .map(|fix| LintFix {
+                description: fix.description.clone().into(),
+                kind: fix.kind,
+                changes: fix
+                  .changes
+                  .iter()
+                  .map(|change| LintFixChange {
+                    new_text: change.new_text.clone().into(),
+                    range: range(change.start, change.end),
+                  })
+                  .collect(),
+              })
+              .collect(),
+            custom_docs_url: d.custom_docs_url.clone(),
+            info: d.info.iter().map(|i| i.clone().into()).collect(),
+          },
+        })
+        .collect(),
+    )
+  }
+
+  pub(crate) fn insert(
+    &self,
+    specifier: &ModuleSpecifier,
+    hash: u64,
+    text_info: &SourceTextInfo,
+    diagnostics: &[LintDiagnostic],
+  ) {
+    let start = text_info.range().start;
+    let cached = CachedFile {
+      hash: format!("{:016x}", hash),
+      diagnostics: diagnostics
+        .iter()
+        .map(|d| CachedDiagnostic {
+          range: d.range.as_ref().map(|r| CachedRange {
+            start: r.range.start - start,
+            end: r.range.end - start,
+            description: r.description.clone(),
+          }),
+          message: d.details.message.clone(),
+          code: d.details.code.clone(),
+          severity: d.details.severity,
+          hint: d.details.hint.clone(),
+          fixes: d
+            .details
+            .fixes
+            .iter()
+            .map(|fix| CachedFix {
+              description: fix.description.to_string(),
+              kind: fix.kind,
+              changes: fix
+                .changes
+                .iter()
+                .map(|change| CachedFixChange {
+                  start: change.range.start - start,
+                  end: change.range.end - start,
+                  new_text: change.new_text.to_string(),
+                })
+                .collect(),
+            })
+            .collect(),
+          custom_docs_url: d.details.custom_docs_url.clone(),
+          info: d.details.info.iter().map(|i| i.to_string()).collect(),
+        })
+        .collect(),
+    };
+    self
+      .files
+      .lock()
+      .unwrap()
+      .insert(specifier.to_string(), cached);
+  }
+}
+
+/// FNV-1a hasher. Cache keys have to be the same between runs, which the
+/// hasher of the standard library doesn't guarantee.
+pub(crate) struct StableHasher(u64);
+
+impl Default for StableHasher {
+  fn default() -> Self {
+    Self(0xcbf29ce484222325)
+  }
+}
+
+impl Hasher for StableHasher {
+  fn finish(&self) -> u64 {
+    self.0
+  }
+
+  fn write(&mut self, bytes: &[u8]) {
+    for byte in bytes {
+      self.0 ^= *byte as u64;
+      self.0 = self.0.wrapping_mul(0x100000001b3);
+    }
+  }
+
+  fn write_usize(&mut self, i: usize) {
+    self.write(&(i as u64).to_le_bytes());
+  }
+}
+
+/// Hash of everything in the linter configuration that affects the
+/// diagnostics of a file: the crate version, the rules with their options,
+/// severities and file scopes, the known rule codes and the ignore
+/// directives.
+pub(crate) fn rule_set_hash(
+  rules: &[Box<dyn LintRule>],
+  severities: &HashMap<&'static str, LintSeverity>,
+  file_scopes: &HashMap<&'static str, Vec<FileScope>>,
+  all_rule_codes: &std::collections::HashSet<&'static str>,
+  ignore_directives: &[&str],
+) -> u64 {
+  let mut hasher = StableHasher::default();
+  CRATE_VERSION.hash(&mut hasher);
+
+  let mut rules = rules
+    .iter()
+    .map(|rule| {
+      let severity = severities.get(rule.code()).copied().unwrap_or_default();
+      format!(
+        "{} {} {:?}",
+        rule.cache_key(),
+        severity,
+        file_scopes.get(rule.code())
+      )
+    })
+    .collect::<Vec<_>>();
+  rules.sort();
+  rules.hash(&mut hasher);
+
+  let mut all_rule_codes = all_rule_codes.iter().collect::<Vec<_>>();
+  all_rule_codes.sort();
+  all_rule_codes.hash(&mut hasher);
+
+  ignore_directives.hash(&mut hasher);
+  hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::context::Context;
+  use crate::rules::no_debugger::NoDebugger;
+  use crate::test_util::{lint_file_options, linter};
+  use crate::Program;
+  use deno_ast::SourceRanged;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+  #[derive(Debug)]
+  struct CountingRule;
+
+  impl LintRule for CountingRule {
+    fn code(&self) -> &'static str {
+      "counting-rule"
+    }
+
+    fn lint_program_with_ast_view(
+      &self,
+      context: &mut Context,
+      program: Program,
+    ) {
+      RUNS.fetch_add(1, Ordering::SeqCst);
+      context.add_diagnostic(program.range(), "counting-rule", "counted");
+    }
+  }
+
+  fn lint(
+    rules: Vec<Box<dyn LintRule>>,
+    cache: &LintCache,
+    source: &str,
+  ) -> Vec<(String, usize, usize)> {
+    linter(rules, Default::default())
+      .lint_file_cached(cache, lint_file_options("file:///a.ts", source))
+      .unwrap()
+      .into_iter()
+      .map(|d| {
+        let range = d.range.unwrap();
+        let byte_range =
+          range.range.as_byte_range(range.text_info.range().start);
+        (d.details.code, byte_range.start, byte_range.end)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn lint_cache() {
+    let source = "const a = 1;\ndebugger;";
+    let expected = vec![
+      ("counting-rule".to_string(), 0, 22),
+      ("no-debugger".to_string(), 13, 22),
+    ];
+    let rules = || -> Vec<Box<dyn LintRule>> {
+      vec![Box::new(CountingRule), Box::new(NoDebugger)]
+    };
+
+    let cache = LintCache::new();
+    assert_eq!(lint(rules(), &cache, source), expected);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // Restored cache gives the same diagnostics without linting the file.
+    let cache = LintCache::from_json(&cache.to_json().unwrap()).unwrap();
+    assert_eq!(cache.len(), 1);
+    assert_eq!(lint(rules(), &cache, source), expected);
+    assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+    // Different rules or source invalidate the entry.
+    assert_eq!(
+      lint(vec![Box::new(CountingRule)], &cache, source),
+      vec![("counting-rule".to_string(), 0, 22)]
+    );
+    assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+    assert_eq!(
+      lint(vec![Box::new(CountingRule)], &cache, "debugger;"),
+      vec![("counting-rule".to_string(), 0, 9)]
+    );
+    assert_eq!(RUNS.load(Ordering::SeqCst), 3);
+
+    // Caches written by other versions are discarded.
+    let json = cache
+      .to_json()
+      .unwrap()
+      .replace(CRATE_VERSION, "0.0.0-other");
+    assert!(LintCache::from_json(&json).unwrap().is_empty());
+  }
+}
@@ -1,26 +1,62 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use crate::ast_parser::parse_program;
-use crate::context::Context;
-use crate::diagnostic::LintDiagnostic;
+use crate::context::{Context, FileAnalysis, ProjectContext};
+use crate::diagnostic::{
+  LintDiagnostic, LintFixKind, LintSeverity, PARSE_ERROR_CODE,
+};
+use crate::file_scope::FileScope;
+use crate::fix::{
+  apply_fixes, first_fix, fix_key, DroppedFix, FixDropReason, FixFileResult,
+  FixSummary, MAX_FIX_ITERATIONS,
+};
+use crate::globals::Globals;
+use crate::handler::CombinedTraverse;
 use crate::ignore_directives::parse_file_ignore_directives;
+use crate::lint_cache::{rule_set_hash, LintCache, StableHasher};
 use crate::performance_mark::PerformanceMark;
-use crate::rules::{ban_unknown_rule_code::BanUnknownRuleCode, LintRule};
+use crate::rules::{
+  ban_unknown_rule_code::BanUnknownRuleCode, configure_rules, LintRule,
+  LintRuleOptionsError, ProjectLintRule,
+};
+use crate::timing::{
+  TimingReport, IGNORE_DIRECTIVES_PHASE, PARSE_PHASE, RULES_PHASE,
+};
+use crate::Program;
 use deno_ast::diagnostics::Diagnostic;
 use deno_ast::MediaType;
 use deno_ast::ParsedSource;
+use deno_ast::SourceTextInfo;
 use deno_ast::{ModuleSpecifier, ParseDiagnostic};
-use std::collections::HashSet;
+#[cfg(feature = "parallel")]
+use derive_more::Display;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::time::Instant;
 
+#[derive(Default)]
 pub struct LinterOptions {
   /// Rules to lint with.
   pub rules: Vec<Box<dyn LintRule>>,
+  /// Options passed to `LintRule::configure`, keyed by rule code. Options
+  /// of rules in `all_rule_codes` that aren't in `rules` are ignored, those
+  /// of unknown rules are rejected.
+  pub rule_options: HashMap<String, serde_json::Value>,
+  /// Project-level rules, only run by `Linter::lint_project`.
+  pub project_rules: Vec<Box<dyn ProjectLintRule>>,
   /// Collection of all the lint rule codes.
   pub all_rule_codes: HashSet<&'static str>,
   /// Defaults to "deno-lint-ignore-file"
   pub custom_ignore_file_directive: Option<&'static str>,
   /// Defaults to "deno-lint-ignore"
   pub custom_ignore_diagnostic_directive: Option<&'static str>,
+  /// Severities that take precedence over `LintRule::default_severity`,
+  /// keyed by rule code. Rules set to `LintSeverity::Off` are not run.
+  pub severity_overrides: HashMap<String, LintSeverity>,
+  /// Files each rule is run on, keyed by rule code. A rule with its own
+  /// `LintRule::file_scope` is only run on the files in both scopes.
+  pub file_scopes: HashMap<String, FileScope>,
 }
 
 /// A linter instance.
@@ -40,28 +76,111 @@ pub(crate) struct LinterContext {
   pub check_unknown_rules: bool,
   /// Rules are sorted by priority
   pub rules: Vec<Box<dyn LintRule>>,
+  pub project_rules: Vec<Box<dyn ProjectLintRule>>,
   pub all_rule_codes: HashSet<&'static str>,
+  /// Severity of each of the rules to run and of syntax errors.
+  pub severities: HashMap<&'static str, LintSeverity>,
+  /// Files the rules are run on, for rules that aren't run on all files. A
+  /// file has to be in all the scopes of a rule.
+  pub file_scopes: HashMap<&'static str, Vec<FileScope>>,
+  /// Hash of the configuration, used as part of `LintCache` keys.
+  pub rule_set_hash: u64,
+  /// Timings collected when profiling is enabled.
+  pub timing: Option<Mutex<TimingReport>>,
 }
 
 impl LinterContext {
-  fn new(options: LinterOptions) -> Self {
+  fn new(options: LinterOptions) -> Result<Self, LintRuleOptionsError> {
     let mut rules = options.rules;
+    let rule_options = options
+      .rule_options
+      .into_iter()
+      .filter(|(code, _)| {
+        rules.iter().any(|rule| rule.code() == code.as_str())
+          || !options.all_rule_codes.contains(code.as_str())
+      })
+      .collect();
+    configure_rules(&mut rules, &rule_options)?;
+
+    let mut severities = HashMap::new();
+    let mut is_enabled = |code: &'static str, default: LintSeverity| {
+      let severity = options
+        .severity_overrides
+        .get(code)
+        .copied()
+        .unwrap_or(default);
+      severities.insert(code, severity);
+      severity != LintSeverity::Off
+    };
+    // Syntax errors can be given a severity like the rules.
+    is_enabled(PARSE_ERROR_CODE, LintSeverity::Error);
+    let mut rules = rules
+      .into_iter()
+      .filter(|rule| is_enabled(rule.code(), rule.default_severity()))
+      .collect::<Vec<_>>();
+    let project_rules = options
+      .project_rules
+      .into_iter()
+      .filter(|rule| is_enabled(rule.code(), rule.default_severity()))
+      .collect::<Vec<_>>();
     crate::rules::sort_rules_by_priority(&mut rules);
+    let mut file_scopes = options.file_scopes;
+    let file_scopes = rules
+      .iter()
+      .filter_map(|rule| {
+        let scopes = rule
+          .file_scope()
+          .into_iter()
+          .chain(file_scopes.remove(rule.code()))
+          .collect::<Vec<_>>();
+        (!scopes.is_empty()).then(|| (rule.code(), scopes))
+      })
+      .collect::<HashMap<_, _>>();
     let check_unknown_rules = rules
       .iter()
       .any(|a| a.code() == (BanUnknownRuleCode).code());
 
-    LinterContext {
-      ignore_file_directive: options
-        .custom_ignore_file_directive
-        .unwrap_or("deno-lint-ignore-file"),
-      ignore_diagnostic_directive: options
-        .custom_ignore_file_directive
-        .unwrap_or("deno-lint-ignore"),
+    let ignore_file_directive = options
+      .custom_ignore_file_directive
+      .unwrap_or("deno-lint-ignore-file");
+    let ignore_diagnostic_directive = options
+      .custom_ignore_file_directive
+      .unwrap_or("deno-lint-ignore");
+    let rule_set_hash = rule_set_hash(
+      &rules,
+      &severities,
+      &file_scopes,
+      &options.all_rule_codes,
+      &[ignore_file_directive, ignore_diagnostic_directive],
+    );
+
+    Ok(LinterContext {
+      ignore_file_directive,
+      ignore_diagnostic_directive,
       check_unknown_rules,
       rules,
+      project_rules,
       all_rule_codes: options.all_rule_codes,
-    }
+      severities,
+      file_scopes,
+      rule_set_hash,
+      timing: None,
+    })
+  }
+
+  /// Runs `f`, adding the time it took to `phase` if timing is enabled.
+  pub(crate) fn time<T>(
+    &self,
+    phase: &'static str,
+    f: impl FnOnce() -> T,
+  ) -> T {
+    let Some(timing) = &self.timing else {
+      return f();
+    };
+    let start = Instant::now();
+    let result = f();
+    timing.lock().unwrap().add_phase(phase, start.elapsed());
+    result
   }
 }
 
@@ -76,13 +195,199 @@ pub struct LintFileOptions {
 pub struct LintConfig {
   pub default_jsx_factory: Option<String>,
   pub default_jsx_fragment_factory: Option<String>,
+  /// Globals available in the file, which default to the ones of Deno.
+  pub globals: Globals,
+}
+
+/// Token used to cancel a `Linter::lint_files` call from another thread.
+#[cfg(feature = "parallel")]
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+#[cfg(feature = "parallel")]
+impl CancellationToken {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn cancel(&self) {
+    self.0.store(true, std::sync::atomic::Ordering::Relaxed);
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.0.load(std::sync::atomic::Ordering::Relaxed)
+  }
+}
+
+/// Error returned by `Linter::lint_files` when it was cancelled.
+#[cfg(feature = "parallel")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[display(fmt = "Linting was cancelled")]
+pub struct LintCancelled;
+
+#[cfg(feature = "parallel")]
+impl std::error::Error for LintCancelled {}
+
+/// Result of linting one of the files passed to `Linter::lint_files`.
+#[cfg(feature = "parallel")]
+pub struct LintFileResult {
+  pub specifier: ModuleSpecifier,
+  /// `None` if the file couldn't be parsed at all.
+  pub parsed_source: Option<ParsedSource>,
+  /// Diagnostics of the rules and syntax errors, as returned by
+  /// `Linter::lint_file_with_parse_errors`.
+  pub diagnostics: Vec<LintDiagnostic>,
+}
+
+#[cfg(feature = "parallel")]
+impl LintFileResult {
+  /// Number of problems that should fail the lint run: diagnostics with
+  /// `error` severity, including syntax errors that weren't ignored.
+  pub fn error_count(&self) -> usize {
+    self
+      .diagnostics
+      .iter()
+      .filter(|d| d.details.severity == LintSeverity::Error)
+      .count()
+  }
+}
+
+/// Cache used by `Linter::lint_file_incremental` to reuse work between lints
+/// of the same files, e.g. on every keystroke in an editor.
+///
+/// For each file, the parsed source, scope and control-flow analysis and the
+/// diagnostics reported by each of the rules are kept for the last
+/// `CACHED_FILE_VERSIONS` versions of its source and `LintConfig`, so undoing
+/// and redoing an edit reuse them. Entries are per file specifier, so a
+/// single cache can be shared by linters with different rule sets.
+#[derive(Default)]
+pub struct IncrementalLintCache {
+  /// Versions of each file, the most recently linted one last.
+  files: HashMap<ModuleSpecifier, Vec<CachedFile>>,
+}
+
+/// Number of versions of a file kept by `IncrementalLintCache`.
+const CACHED_FILE_VERSIONS: usize = 4;
+
+struct CachedFile {
+  /// Hash of the source text, media type and configuration used to analyze
+  /// the file.
+  hash: u64,
+  parsed_source: ParsedSource,
+  analysis: Option<FileAnalysis>,
+  /// Diagnostics reported by each of the rules that were run on the file,
+  /// before ignore directives are applied.
+  rule_diagnostics: HashMap<RuleKey, Vec<LintDiagnostic>>,
+}
+
+/// Identifies a rule run on a file in `CachedFile::rule_diagnostics`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct RuleKey {
+  /// See `LintRule::cache_key`.
+  cache_key: String,
+  /// Included and excluded globs of the scopes of the files the rule is run
+  /// on, empty if it's run on all files.
+  file_scopes: Vec<(Vec<String>, Vec<String>)>,
+}
+
+impl IncrementalLintCache {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Drop the cached data of a file, e.g. when it's closed in the editor.
+  pub fn remove(&mut self, specifier: &ModuleSpecifier) {
+    self.files.remove(specifier);
+  }
+
+  pub fn clear(&mut self) {
+    self.files.clear();
+  }
+
+  /// Number of files in the cache.
+  pub fn len(&self) -> usize {
+    self.files.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.files.is_empty()
+  }
+}
+
+/// Resolves import specifiers to modules, so project-level rules can follow
+/// imports between the linted files.
+pub trait ModuleResolver {
+  /// Returns the specifier of the module `specifier` points to when
+  /// imported from `referrer`, or `None` if it can't be resolved.
+  fn resolve(
+    &self,
+    specifier: &str,
+    referrer: &ModuleSpecifier,
+  ) -> Option<ModuleSpecifier>;
+}
+
+impl<F> ModuleResolver for F
+where
+  F: Fn(&str, &ModuleSpecifier) -> Option<ModuleSpecifier>,
+{
+  fn resolve(
+    &self,
+    specifier: &str,
+    referrer: &ModuleSpecifier,
+  ) -> Option<ModuleSpecifier> {
+    self(specifier, referrer)
+  }
 }
 
 impl Linter {
+  /// Creates a linter, configuring its rules with `LinterOptions::rule_options`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the rule options are invalid, use `Linter::try_new` to handle
+  /// the error instead.
   pub fn new(options: LinterOptions) -> Self {
-    let ctx = LinterContext::new(options);
+    match Self::try_new(options) {
+      Ok(linter) => linter,
+      Err(err) => panic!("{}", err),
+    }
+  }
+
+  /// Creates a linter, or returns an error if `LinterOptions::rule_options`
+  /// are invalid or contain options of unknown rules.
+  pub fn try_new(options: LinterOptions) -> Result<Self, LintRuleOptionsError> {
+    let ctx = LinterContext::new(options)?;
 
-    Linter { ctx }
+    Ok(Linter { ctx })
+  }
+
+  /// Start recording how long each phase of linting and each rule takes.
+  /// Timings are aggregated over all files linted afterwards, see
+  /// `Linter::timing_report`.
+  pub fn enable_timing(&mut self) {
+    self.ctx.timing = Some(Mutex::default());
+  }
+
+  /// Timings recorded since `Linter::enable_timing` was called, or `None` if
+  /// timing isn't enabled.
+  pub fn timing_report(&self) -> Option<TimingReport> {
+    self
+      .ctx
+      .timing
+      .as_ref()
+      .map(|timing| timing.lock().unwrap().clone())
+  }
+
+  fn parse(
+    &self,
+    specifier: ModuleSpecifier,
+    media_type: MediaType,
+    source_code: String,
+  ) -> Result<ParsedSource, ParseDiagnostic> {
+    let _mark = PerformanceMark::new("ast_parser.parse_program");
+    self.ctx.time(PARSE_PHASE, || {
+      parse_program(specifier, media_type, source_code)
+    })
   }
 
   /// Lint a single file.
@@ -97,21 +402,444 @@ impl Linter {
   ) -> Result<(ParsedSource, Vec<LintDiagnostic>), ParseDiagnostic> {
     let _mark = PerformanceMark::new("Linter::lint");
 
-    let parse_result = {
-      let _mark = PerformanceMark::new("ast_parser.parse_program");
-      parse_program(options.specifier, options.media_type, options.source_code)
-    };
+    let parse_result =
+      self.parse(options.specifier, options.media_type, options.source_code);
 
     let parsed_source = parse_result?;
     let diagnostics = self.lint_inner(
       &parsed_source,
       options.config.default_jsx_factory,
       options.config.default_jsx_fragment_factory,
+      options.config.globals,
+      Vec::new(),
+      &[],
     );
 
     Ok((parsed_source, diagnostics))
   }
 
+  /// Lint a single file, reporting syntax errors as diagnostics with the
+  /// `parse-error` code along with the diagnostics of the rules.
+  ///
+  /// Recoverable syntax errors go through ignore directives like any other
+  /// diagnostic, and their severity can be overridden in `LinterOptions`. If
+  /// the file can't be parsed at all, no `ParsedSource` is returned and the
+  /// only diagnostic is the fatal syntax error.
+  pub fn lint_file_with_parse_errors(
+    &self,
+    options: LintFileOptions,
+  ) -> (Option<ParsedSource>, Vec<LintDiagnostic>) {
+    let _mark = PerformanceMark::new("Linter::lint_file_with_parse_errors");
+
+    let parse_result =
+      self.parse(options.specifier, options.media_type, options.source_code);
+
+    match parse_result {
+      Ok(parsed_source) => {
+        let diagnostics = self.lint_inner(
+          &parsed_source,
+          options.config.default_jsx_factory,
+          options.config.default_jsx_fragment_factory,
+          options.config.globals,
+          self.parse_error_diagnostics(parsed_source.diagnostics()),
+          self.parse_error_codes(),
+        );
+        (Some(parsed_source), diagnostics)
+      }
+      Err(diagnostic) => (
+        None,
+        self.parse_error_diagnostics(std::slice::from_ref(&diagnostic)),
+      ),
+    }
+  }
+
+  /// Diagnostics with the `parse-error` code for syntax errors, with the
+  /// severity of `parse-error`.
+  fn parse_error_diagnostics(
+    &self,
+    diagnostics: &[ParseDiagnostic],
+  ) -> Vec<LintDiagnostic> {
+    let severity = self.ctx.severities[PARSE_ERROR_CODE];
+    if severity == LintSeverity::Off {
+      return Vec::new();
+    }
+    diagnostics
+      .iter()
+      .map(|diagnostic| {
+        let mut diagnostic = LintDiagnostic::from_parse_diagnostic(diagnostic);
+        diagnostic.details.severity = severity;
+        diagnostic
+      })
+      .collect()
+  }
+
+  /// Codes of the diagnostics of `parse_error_diagnostics`, whose unused
+  /// ignore directives are reported.
+  fn parse_error_codes(&self) -> &'static [&'static str] {
+    if self.ctx.severities[PARSE_ERROR_CODE] == LintSeverity::Off {
+      &[]
+    } else {
+      &[PARSE_ERROR_CODE]
+    }
+  }
+
+  /// Lint a single file and apply the fixes of the diagnostics that have one
+  /// of the given kinds, linting it again after each pass of `apply_fixes`
+  /// until no fixable diagnostics are left or `MAX_FIX_ITERATIONS` passes
+  /// were done.
+  ///
+  /// A pass is only kept if the fixed source parses without more syntax
+  /// errors than the original one. Otherwise the fixes producing invalid
+  /// code are dropped and the pass is retried without them.
+  pub fn fix_file(
+    &self,
+    options: LintFileOptions,
+    kinds: &[LintFixKind],
+  ) -> Result<FixFileResult, ParseDiagnostic> {
+    let _mark = PerformanceMark::new("Linter::fix_file");
+
+    let specifier = options.specifier;
+    let media_type = options.media_type;
+    let config = options.config;
+    let lint = |parsed_source: &ParsedSource| {
+      self.lint_inner(
+        parsed_source,
+        config.default_jsx_factory.clone(),
+        config.default_jsx_fragment_factory.clone(),
+        config.globals.clone(),
+        self.parse_error_diagnostics(parsed_source.diagnostics()),
+        self.parse_error_codes(),
+      )
+    };
+    let parse_fixed = |text: &str, max_errors: usize| {
+      self
+        .parse(specifier.clone(), media_type, text.to_string())
+        .ok()
+        .filter(|parsed_source| parsed_source.diagnostics().len() <= max_errors)
+    };
+
+    let mut parsed_source =
+      self.parse(specifier.clone(), media_type, options.source_code)?;
+    let max_errors = parsed_source.diagnostics().len();
+    let mut diagnostics = lint(&parsed_source);
+    let mut applied = Vec::new();
+    let mut dropped = Vec::new();
+    // Fixes known to produce invalid code, which aren't tried again.
+    let mut rejected = HashSet::new();
+    let mut passes = 0;
+
+    loop {
+      let fixable = diagnostics
+        .iter()
+        .filter(|diagnostic| {
+          first_fix(diagnostic, kinds).is_some()
+            && !rejected.contains(&fix_key(diagnostic, kinds))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+      if fixable.is_empty() {
+        break;
+      }
+      if passes == MAX_FIX_ITERATIONS {
+        dropped.extend(fixable.iter().filter_map(|diagnostic| {
+          Some(DroppedFix {
+            fix: FixSummary::new(diagnostic, kinds)?,
+            reason: FixDropReason::IterationLimit,
+          })
+        }));
+        break;
+      }
+      passes += 1;
+
+      let text = parsed_source.text();
+      let result = apply_fixes(text, &fixable, kinds);
+      if result.applied.is_empty() {
+        // Only fixes with overlapping changes of their own are left.
+        dropped.extend(result.dropped);
+        break;
+      }
+      if let Some(fixed) = parse_fixed(&result.text, max_errors) {
+        applied.extend(result.applied);
+        parsed_source = fixed;
+        diagnostics = lint(&parsed_source);
+        continue;
+      }
+
+      // Find the fixes that produce invalid code by themselves. If there
+      // are none, it's their combination, so all of them are dropped.
+      let mut invalid = fixable
+        .iter()
+        .filter(|diagnostic| {
+          let result =
+            apply_fixes(text, std::slice::from_ref(*diagnostic), kinds);
+          !result.applied.is_empty()
+            && parse_fixed(&result.text, max_errors).is_none()
+        })
+        .collect::<Vec<_>>();
+      if invalid.is_empty() {
+        invalid = fixable
+          .iter()
+          .filter(|diagnostic| {
+            let summary = FixSummary::new(diagnostic, kinds);
+            result
+              .applied
+              .iter()
+              .any(|fix| Some(fix) == summary.as_ref())
+          })
+          .collect();
+      }
+      for diagnostic in invalid {
+        rejected.insert(fix_key(diagnostic, kinds));
+        dropped.extend(FixSummary::new(diagnostic, kinds).map(|fix| {
+          DroppedFix {
+            fix,
+            reason: FixDropReason::InvalidSyntax,
+          }
+        }));
+      }
+    }
+
+    Ok(FixFileResult {
+      parsed_source,
+      diagnostics,
+      applied,
+      dropped,
+    })
+  }
+
+  /// Lint a single file, unless it was already linted with the same
+  /// configuration and its diagnostics are stored in `cache`.
+  ///
+  /// The cache is consulted before the file is parsed, so unlike
+  /// `Linter::lint_file` this doesn't return the `ParsedSource`. Results of
+  /// files that have syntax errors are never cached.
+  pub fn lint_file_cached(
+    &self,
+    cache: &LintCache,
+    options: LintFileOptions,
+  ) -> Result<Vec<LintDiagnostic>, ParseDiagnostic> {
+    let _mark = PerformanceMark::new("Linter::lint_file_cached");
+
+    let hash = {
+      let mut hasher = StableHasher::default();
+      self.ctx.rule_set_hash.hash(&mut hasher);
+      options.source_code.hash(&mut hasher);
+      options.media_type.to_string().hash(&mut hasher);
+      options.config.default_jsx_factory.hash(&mut hasher);
+      options
+        .config
+        .default_jsx_fragment_factory
+        .hash(&mut hasher);
+      options.config.globals.hash(&mut hasher);
+      hasher.finish()
+    };
+    let text_info = SourceTextInfo::new(options.source_code.clone().into());
+    if let Some(diagnostics) = cache.get(&options.specifier, hash, &text_info) {
+      return Ok(diagnostics);
+    }
+
+    let specifier = options.specifier.clone();
+    let (parsed_source, diagnostics) = self.lint_file(options)?;
+    if parsed_source.diagnostics().is_empty() {
+      cache.insert(&specifier, hash, &text_info, &diagnostics);
+    }
+    Ok(diagnostics)
+  }
+
+  /// Lint many files in parallel.
+  ///
+  /// Results are returned in the same order as `files`, including files that
+  /// failed to parse. Files that haven't been started when `cancellation` is
+  /// cancelled are skipped, and `LintCancelled` is returned instead.
+  #[cfg(feature = "parallel")]
+  pub fn lint_files(
+    &self,
+    files: Vec<LintFileOptions>,
+    cancellation: &CancellationToken,
+  ) -> Result<Vec<LintFileResult>, LintCancelled> {
+    use rayon::prelude::*;
+
+    let _mark = PerformanceMark::new("Linter::lint_files");
+    files
+      .into_par_iter()
+      .map(|options| {
+        if cancellation.is_cancelled() {
+          return Err(LintCancelled);
+        }
+        let specifier = options.specifier.clone();
+        let (parsed_source, diagnostics) =
+          self.lint_file_with_parse_errors(options);
+        Ok(LintFileResult {
+          specifier,
+          parsed_source,
+          diagnostics,
+        })
+      })
+      .collect()
+  }
+
+  /// Lint a single file, reusing the work done by previous calls with the same
+  /// `cache`.
+  ///
+  /// If the file was linted with the same source recently, e.g. before an
+  /// edit that was undone, it's not parsed or analyzed again, and only rules
+  /// that weren't run on this source before are executed. Diagnostics of the
+  /// other rules are taken from the cache. Otherwise the file is linted from
+  /// scratch, replacing the least recently linted version once there are
+  /// `CACHED_FILE_VERSIONS` of them. Work isn't shared between different
+  /// sources, so every rule is run again after an edit.
+  pub fn lint_file_incremental(
+    &self,
+    cache: &mut IncrementalLintCache,
+    options: LintFileOptions,
+  ) -> Result<(ParsedSource, Vec<LintDiagnostic>), ParseDiagnostic> {
+    let _mark = PerformanceMark::new("Linter::lint_file_incremental");
+
+    let hash = {
+      let mut hasher = std::collections::hash_map::DefaultHasher::new();
+      options.source_code.hash(&mut hasher);
+      options.media_type.to_string().hash(&mut hasher);
+      options.config.default_jsx_factory.hash(&mut hasher);
+      options
+        .config
+        .default_jsx_fragment_factory
+        .hash(&mut hasher);
+      options.config.globals.hash(&mut hasher);
+      self.ctx.ignore_diagnostic_directive.hash(&mut hasher);
+      hasher.finish()
+    };
+
+    let versions = cache.files.entry(options.specifier.clone()).or_default();
+    let cached = match versions.iter().position(|v| v.hash == hash) {
+      Some(index) => versions.remove(index),
+      None => {
+        let parsed_source = match self.parse(
+          options.specifier.clone(),
+          options.media_type,
+          options.source_code,
+        ) {
+          Ok(parsed_source) => parsed_source,
+          Err(err) => {
+            if versions.is_empty() {
+              cache.files.remove(&options.specifier);
+            }
+            return Err(err);
+          }
+        };
+        if versions.len() == CACHED_FILE_VERSIONS {
+          versions.remove(0);
+        }
+        CachedFile {
+          hash,
+          parsed_source,
+          analysis: None,
+          rule_diagnostics: HashMap::new(),
+        }
+      }
+    };
+    versions.push(cached);
+    let cached = versions.last_mut().unwrap();
+
+    let parsed_source = cached.parsed_source.clone();
+    let diagnostics = parsed_source.with_view(|pg| {
+      let file_ignore_directive =
+        self.ctx.time(IGNORE_DIRECTIVES_PHASE, || {
+          parse_file_ignore_directives(self.ctx.ignore_file_directive, pg)
+        });
+      if let Some(ignore_directive) = file_ignore_directive.as_ref() {
+        if ignore_directive.ignore_all() {
+          return vec![];
+        }
+      }
+
+      let analysis = cached
+        .analysis
+        .get_or_insert_with(|| {
+          FileAnalysis::new(
+            &self.ctx,
+            &parsed_source,
+            pg,
+            options.config.default_jsx_factory,
+            options.config.default_jsx_fragment_factory,
+            options.config.globals,
+          )
+        })
+        .clone();
+      let mut context = Context::new(
+        &self.ctx,
+        parsed_source.clone(),
+        pg,
+        file_ignore_directive,
+        analysis,
+      );
+
+      let rule_keys = self
+        .ctx
+        .rules
+        .iter()
+        .map(|rule| RuleKey {
+          cache_key: rule.cache_key(),
+          file_scopes: self
+            .ctx
+            .file_scopes
+            .get(rule.code())
+            .into_iter()
+            .flatten()
+            .map(|scope| (scope.include().to_vec(), scope.exclude().to_vec()))
+            .collect(),
+        })
+        .collect::<Vec<_>>();
+      let (rules_to_run, keys_to_run): (Vec<_>, Vec<_>) = self
+        .ctx
+        .rules
+        .iter()
+        .zip(&rule_keys)
+        .filter(|(_, key)| !cached.rule_diagnostics.contains_key(*key))
+        .unzip();
+      let mut owners = Vec::new();
+      self.run_rules(
+        &mut context,
+        pg,
+        rules_to_run.into_iter(),
+        Some(&mut owners),
+      );
+
+      let mut new_diagnostics = vec![Vec::new(); keys_to_run.len()];
+      for (diagnostic, owner) in
+        context.take_diagnostics().into_iter().zip(owners)
+      {
+        new_diagnostics[owner].push(diagnostic);
+      }
+      for (key, rule_diagnostics) in
+        keys_to_run.into_iter().zip(new_diagnostics)
+      {
+        cached
+          .rule_diagnostics
+          .insert(key.clone(), rule_diagnostics);
+      }
+
+      for key in &rule_keys {
+        let mut diagnostics = cached.rule_diagnostics[key].clone();
+        // Severities might differ between linters sharing the cache.
+        for diagnostic in diagnostics.iter_mut() {
+          diagnostic.details.severity = self
+            .ctx
+            .severities
+            .get(diagnostic.details.code.as_str())
+            .copied()
+            .unwrap_or_default();
+        }
+        context.add_diagnostics(diagnostics);
+      }
+
+      self.ctx.time(IGNORE_DIRECTIVES_PHASE, || {
+        self.collect_diagnostics(context, &[])
+      })
+    });
+
+    Ok((parsed_source, diagnostics))
+  }
+
   /// Lint an already parsed file.
   ///
   /// This method is useful in context where the file is already parsed for other
@@ -126,20 +854,97 @@ impl Linter {
       parsed_source,
       config.default_jsx_factory,
       config.default_jsx_fragment_factory,
+      config.globals,
+      Vec::new(),
+      &[],
     )
   }
 
+  /// Lint already parsed files together as a single project.
+  ///
+  /// In addition to the regular rules run on each file, this runs the
+  /// project-level rules which can report problems spanning multiple files,
+  /// with imports resolved by `resolver`. Diagnostics from project-level rules
+  /// respect ignore directives of the file they are reported in.
+  ///
+  /// Returns diagnostics keyed by specifier of each of the files.
+  pub fn lint_project(
+    &self,
+    parsed_sources: &[ParsedSource],
+    resolver: &dyn ModuleResolver,
+    config: LintConfig,
+  ) -> HashMap<ModuleSpecifier, Vec<LintDiagnostic>> {
+    let _mark = PerformanceMark::new("Linter::lint_project");
+
+    let mut project_diagnostics: HashMap<ModuleSpecifier, Vec<LintDiagnostic>> =
+      HashMap::new();
+    if !self.ctx.project_rules.is_empty() {
+      let mut context =
+        ProjectContext::new(&self.ctx, parsed_sources, resolver);
+      for rule in self.ctx.project_rules.iter() {
+        let start = Instant::now();
+        rule.lint_project(&mut context);
+        if let Some(timing) = &self.ctx.timing {
+          timing
+            .lock()
+            .unwrap()
+            .add_rule(rule.code(), start.elapsed());
+        }
+      }
+      for diagnostic in context.into_diagnostics() {
+        project_diagnostics
+          .entry(diagnostic.specifier.clone())
+          .or_default()
+          .push(diagnostic);
+      }
+    }
+
+    let project_codes = self
+      .ctx
+      .project_rules
+      .iter()
+      .map(|rule| rule.code())
+      .collect::<Vec<_>>();
+    parsed_sources
+      .iter()
+      .map(|parsed_source| {
+        let diagnostics = self.lint_inner(
+          parsed_source,
+          config.default_jsx_factory.clone(),
+          config.default_jsx_fragment_factory.clone(),
+          config.globals.clone(),
+          project_diagnostics
+            .remove(parsed_source.specifier())
+            .unwrap_or_default(),
+          &project_codes,
+        );
+        (parsed_source.specifier().clone(), diagnostics)
+      })
+      .collect()
+  }
+
   // TODO(bartlomieju): this struct does too much - not only it checks for ignored
   // lint rules, it also runs 2 additional rules. These rules should be rewritten
   // to use a regular way of writing a rule and not live on the `Context` struct.
-  fn collect_diagnostics(&self, mut context: Context) -> Vec<LintDiagnostic> {
+  fn collect_diagnostics(
+    &self,
+    mut context: Context,
+    extra_codes: &[&'static str],
+  ) -> Vec<LintDiagnostic> {
     let _mark = PerformanceMark::new("Linter::collect_diagnostics");
 
     let mut diagnostics = context.check_ignore_directive_usage();
     // Run `ban-unknown-rule-code`
     diagnostics.extend(context.ban_unknown_rule_code());
     // Run `ban-unused-ignore`
-    diagnostics.extend(context.ban_unused_ignore(&self.ctx.rules));
+    let executed_codes = self
+      .ctx
+      .rules
+      .iter()
+      .map(|rule| rule.code())
+      .chain(extra_codes.iter().copied())
+      .collect();
+    diagnostics.extend(context.ban_unused_ignore(&executed_codes));
 
     // Finally sort by position the diagnostics originates on then by code
     diagnostics.sort_by(|a, b| {
@@ -159,6 +964,9 @@ impl Linter {
     parsed_source: &ParsedSource,
     default_jsx_factory: Option<String>,
     default_jsx_fragment_factory: Option<String>,
+    globals: Globals,
+    extra_diagnostics: Vec<LintDiagnostic>,
+    extra_codes: &[&'static str],
   ) -> Vec<LintDiagnostic> {
     let _mark = PerformanceMark::new("Linter::lint_inner");
 
@@ -177,7 +985,9 @@ impl Linter {
       // we're gonna check if the file should be ignored, before performing
       // other expensive work like scope or control-flow analysis.
       let file_ignore_directive =
-        parse_file_ignore_directives(self.ctx.ignore_file_directive, pg);
+        self.ctx.time(IGNORE_DIRECTIVES_PHASE, || {
+          parse_file_ignore_directives(self.ctx.ignore_file_directive, pg)
+        });
       if let Some(ignore_directive) = file_ignore_directive.as_ref() {
         if ignore_directive.ignore_all() {
           return vec![];
@@ -191,18 +1001,118 @@ impl Linter {
         parsed_source.clone(),
         pg,
         file_ignore_directive,
-        default_jsx_factory,
-        default_jsx_fragment_factory,
+        FileAnalysis::new(
+          &self.ctx,
+          parsed_source,
+          pg,
+          default_jsx_factory,
+          default_jsx_fragment_factory,
+          globals,
+        ),
       );
 
-      // Run configured lint rules.
-      for rule in self.ctx.rules.iter() {
-        rule.lint_program_with_ast_view(&mut context, pg);
-      }
+      self.run_rules(&mut context, pg, self.ctx.rules.iter(), None);
+
+      // Diagnostics reported for this file by project-level rules or the
+      // parser go through ignore directives like the ones from regular rules.
+      context.add_diagnostics(extra_diagnostics);
 
-      self.collect_diagnostics(context)
+      self.ctx.time(IGNORE_DIRECTIVES_PHASE, || {
+        self.collect_diagnostics(context, extra_codes)
+      })
     });
 
     diagnostics
   }
+
+  /// Run configured lint rules. Rules implemented as handlers are all run
+  /// in a single traversal of the program.
+  ///
+  /// If `owners` is given, the index in `rules` of the rule reporting each of
+  /// the diagnostics added to `context` is pushed to it, in the order they
+  /// were added.
+  fn run_rules<'a, 'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: Program<'view>,
+    rules: impl Iterator<Item = &'a Box<dyn LintRule>>,
+    mut owners: Option<&mut Vec<usize>>,
+  ) {
+    let start = Instant::now();
+    let mut rule_timings = Vec::new();
+    let mut handlers = Vec::new();
+    let mut handler_rules = Vec::new();
+    let initial_count = context.diagnostic_count();
+    let specifier = context.specifier().clone();
+    let rules = rules.enumerate().filter(|(_, rule)| {
+      self
+        .ctx
+        .file_scopes
+        .get(rule.code())
+        .map_or(true, |scopes| {
+          scopes.iter().all(|scope| scope.matches(&specifier))
+        })
+    });
+    for (i, rule) in rules {
+      match rule.handler() {
+        Some(handler) => {
+          handlers.push(handler);
+          handler_rules.push((i, rule.code()));
+        }
+        None if self.ctx.timing.is_some() => {
+          let start = Instant::now();
+          let analysis_time = context.analysis_time();
+          rule.lint_program_with_ast_view(context, program);
+          let elapsed = start
+            .elapsed()
+            .saturating_sub(context.analysis_time() - analysis_time);
+          rule_timings.push((rule.code(), elapsed));
+        }
+        None => rule.lint_program_with_ast_view(context, program),
+      }
+      if let Some(owners) = owners.as_deref_mut() {
+        owners.resize(context.diagnostic_count() - initial_count, i);
+      }
+    }
+    if !handlers.is_empty() {
+      let _mark = PerformanceMark::new("Linter::combined_traverse");
+      let mut traverse = CombinedTraverse::new(handlers);
+      if self.ctx.timing.is_some() {
+        traverse.enable_timing();
+      }
+      if owners.is_some() {
+        traverse.enable_diagnostic_owners();
+      }
+      traverse.traverse(program, context);
+      if let Some(owners) = owners {
+        owners.extend(
+          traverse
+            .diagnostic_owners()
+            .unwrap()
+            .iter()
+            .map(|&handler| handler_rules[handler].0),
+        );
+      }
+      if let Some(timings) = traverse.timings() {
+        rule_timings.extend(
+          handler_rules
+            .iter()
+            .map(|(_, code)| *code)
+            .zip(timings.iter().copied()),
+        );
+      }
+    }
+
+    if let Some(timing) = &self.ctx.timing {
+      let mut timing = timing.lock().unwrap();
+      timing.add_file();
+      timing.add_phase(
+        RULES_PHASE,
+        start.elapsed().saturating_sub(context.analysis_time()),
+      );
+      for (code, duration) in rule_timings {
+        timing.add_rule(code, duration);
+      }
+    }
+  }
 }
@@ -0,0 +1,596 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Lint rules implemented in JavaScript and run by an external process.
+//!
+//! A plugin runner is a long-lived subprocess that reads requests from its
+//! stdin and writes responses to its stdout, one JSON object per line. The
+//! host sends two kinds of requests:
+//!
+//! - `{"type": "getRules", "protocolVersion": 1}`, answered with
+//!   `{"protocolVersion": 1, "rules": [{"code": "...", "version": "..."}]}`.
+//!   The `version` of a rule, e.g. a hash of its source, has to change
+//!   whenever the rule could report other diagnostics, as it's part of the
+//!   keys of cached lint results.
+//! - `{"type": "lint", "rule": "...", "specifier": "...", "source": "...",
+//!   "ast": {...}}`, answered with `{"diagnostics": [...]}`.
+//!
+//! The AST is the swc AST in its ESTree-like JSON form, as consumed by
+//! `examples/dlint/visitor.js`, except that `span`s hold byte offsets into
+//! `source` and syntax contexts are omitted. Its shape is part of the
+//! protocol, so `PLUGIN_PROTOCOL_VERSION` is bumped whenever it changes, e.g.
+//! with an update of swc. Diagnostics use the same offsets:
+//!
+//! ```json
+//! {
+//!   "span": { "start": 0, "end": 8 },
+//!   "message": "...",
+//!   "hint": "...",
+//!   "fixes": [{
+//!     "description": "...",
+//!     "kind": "safe",
+//!     "changes": [{ "span": { "start": 0, "end": 8 }, "newText": "..." }]
+//!   }]
+//! }
+//! ```
+//!
+//! The `kind` of a fix is `safe`, `unsafe` or `suggestion`, which is the
+//! default.
+//!
+//! Any request can instead be answered with `{"error": "..."}`.
+
+use crate::context::Context;
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::rules::LintRule;
+use crate::Program;
+use deno_ast::{ParsedSource, ProgramRef, SourceRange};
+use derive_more::Display;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Display)]
+pub enum PluginError {
+  #[display(fmt = "Failed to communicate with the plugin runner: {}", _0)]
+  Io(std::io::Error),
+  #[display(fmt = "Invalid response from the plugin runner: {}", _0)]
+  InvalidResponse(String),
+  #[display(fmt = "Plugin failed: {}", _0)]
+  Plugin(String),
+}
+
+impl std::error::Error for PluginError {}
+
+impl From<std::io::Error> for PluginError {
+  fn from(err: std::io::Error) -> Self {
+    PluginError::Io(err)
+  }
+}
+
+/// Version of the protocol described in the module docs. Runners answer
+/// `getRules` with the version they implement, and are rejected if it
+/// differs.
+pub const PLUGIN_PROTOCOL_VERSION: u32 = 1;
+
+/// A running plugin runner process. Rules returned by `PluginHost::rules`
+/// keep the process alive; it's killed once they and the host are dropped.
+pub struct PluginHost {
+  inner: Arc<PluginHostInner>,
+}
+
+struct PluginHostInner {
+  command: String,
+  process: Mutex<PluginProcess>,
+  codes: Mutex<HashMap<String, &'static str>>,
+  /// AST of the last linted file, so it's serialized once for all the rules.
+  last_ast: Mutex<Option<(Arc<str>, Arc<Value>)>>,
+}
+
+struct PluginProcess {
+  child: Child,
+  stdin: ChildStdin,
+  stdout: BufReader<ChildStdout>,
+}
+
+impl Drop for PluginHostInner {
+  fn drop(&mut self) {
+    if let Ok(process) = self.process.get_mut() {
+      let _ = process.child.kill();
+      let _ = process.child.wait();
+    }
+  }
+}
+
+impl PluginHost {
+  /// Starts the plugin runner.
+  pub fn spawn<I, S>(
+    command: impl AsRef<OsStr>,
+    args: I,
+  ) -> Result<Self, PluginError>
+  where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+  {
+    let mut cmd = Command::new(command);
+    cmd
+      .args(args)
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::inherit());
+    let description = format!("{:?}", cmd);
+    let mut child = cmd.spawn()?;
+    let stdin = child.stdin.take().unwrap();
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    Ok(PluginHost {
+      inner: Arc::new(PluginHostInner {
+        command: description,
+        process: Mutex::new(PluginProcess {
+          child,
+          stdin,
+          stdout,
+        }),
+        codes: Default::default(),
+        last_ast: Mutex::new(None),
+      }),
+    })
+  }
+
+  /// Asks the runner for the rules it provides.
+  ///
+  /// Codes of the rules are leaked once per runner, as `LintRule::code`
+  /// returns a static string. The codes need to be added to
+  /// `LinterOptions::all_rule_codes` to be usable in ignore directives.
+  pub fn rules(&self) -> Result<Vec<Box<dyn LintRule>>, PluginError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RulesResponse {
+      protocol_version: u32,
+      rules: Vec<RuleInfo>,
+    }
+    #[derive(Deserialize)]
+    struct RuleInfo {
+      code: String,
+      version: String,
+    }
+
+    let response: RulesResponse = self.inner.request(&json!({
+      "type": "getRules",
+      "protocolVersion": PLUGIN_PROTOCOL_VERSION,
+    }))?;
+    if response.protocol_version != PLUGIN_PROTOCOL_VERSION {
+      return Err(PluginError::InvalidResponse(format!(
+        "unsupported protocol version {}, expected {}",
+        response.protocol_version, PLUGIN_PROTOCOL_VERSION
+      )));
+    }
+    let mut codes = self.inner.codes.lock().unwrap();
+    Ok(
+      response
+        .rules
+        .into_iter()
+        .map(|rule| -> Box<dyn LintRule> {
+          let code = *codes.entry(rule.code).or_insert_with_key(|code| {
+            Box::leak(code.clone().into_boxed_str())
+          });
+          Box::new(PluginRule {
+            code,
+            version: rule.version,
+            host: self.inner.clone(),
+          })
+        })
+        .collect(),
+    )
+  }
+}
+
+impl PluginHostInner {
+  fn request<T: for<'de> Deserialize<'de>>(
+    &self,
+    request: &Value,
+  ) -> Result<T, PluginError> {
+    let line = {
+      let mut process = self.process.lock().unwrap();
+      serde_json::to_writer(&mut process.stdin, request)
+        .map_err(std::io::Error::from)?;
+      process.stdin.write_all(b"\n")?;
+      process.stdin.flush()?;
+      let mut line = String::new();
+      if process.stdout.read_line(&mut line)? == 0 {
+        return Err(PluginError::Io(std::io::Error::new(
+          std::io::ErrorKind::UnexpectedEof,
+          "the plugin runner exited",
+        )));
+      }
+      line
+    };
+
+    let response: Value = serde_json::from_str(&line)
+      .map_err(|err| PluginError::InvalidResponse(err.to_string()))?;
+    if let Some(error) = response.get("error") {
+      return Err(PluginError::Plugin(match error {
+        Value::String(message) => message.clone(),
+        error => error.to_string(),
+      }));
+    }
+    serde_json::from_value(response)
+      .map_err(|err| PluginError::InvalidResponse(err.to_string()))
+  }
+
+  fn serialized_ast(
+    &self,
+    context: &Context,
+  ) -> Result<Arc<Value>, PluginError> {
+    let parsed_source = context.parsed_source();
+    let text = parsed_source.text();
+    let mut last_ast = self.last_ast.lock().unwrap();
+    if let Some((last_text, ast)) = &*last_ast {
+      // Holding on to the text guarantees the pointer isn't reused.
+      if Arc::ptr_eq(last_text, text) {
+        return Ok(ast.clone());
+      }
+    }
+
+    let ast = Arc::new(serialize_ast(parsed_source)?);
+    *last_ast = Some((text.clone(), ast.clone()));
+    Ok(ast)
+  }
+}
+
+/// The AST sent to plugins, see the module docs.
+fn serialize_ast(parsed_source: &ParsedSource) -> Result<Value, PluginError> {
+  let ast = match parsed_source.program_ref() {
+    ProgramRef::Module(module) => serde_json::to_value(module),
+    ProgramRef::Script(script) => serde_json::to_value(script),
+  }
+  .map_err(|err| PluginError::InvalidResponse(err.to_string()))?;
+  let start = parsed_source.text_info_lazy().range().start;
+  Ok(normalize_spans(ast, start.as_byte_pos().0))
+}
+
+/// Makes spans relative to the start of the file and drops syntax contexts,
+/// which are internal to the parser.
+fn normalize_spans(value: Value, start: u32) -> Value {
+  match value {
+    Value::Object(object) => Value::Object(
+      object
+        .into_iter()
+        .filter(|(key, _)| key != "ctxt")
+        .map(|(key, value)| {
+          let value = match (key.as_str(), value) {
+            ("span", Value::Object(mut span)) => {
+              for key in ["start", "end"] {
+                if let Some(pos) = span.get(key).and_then(Value::as_u64) {
+                  span.insert(
+                    key.to_string(),
+                    pos.saturating_sub(start as u64).into(),
+                  );
+                }
+              }
+              Value::Object(span)
+            }
+            (_, value) => normalize_spans(value, start),
+          };
+          (key, value)
+        })
+        .collect(),
+    ),
+    Value::Array(array) => Value::Array(
+      array
+        .into_iter()
+        .map(|value| normalize_spans(value, start))
+        .collect(),
+    ),
+    value => value,
+  }
+}
+
+#[derive(Deserialize)]
+struct LintResponse {
+  diagnostics: Vec<PluginDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct PluginDiagnostic {
+  span: PluginSpan,
+  message: String,
+  hint: Option<String>,
+  #[serde(default)]
+  fixes: Vec<PluginFix>,
+}
+
+#[derive(Deserialize)]
+struct PluginFix {
+  description: String,
+  #[serde(default)]
+  kind: LintFixKind,
+  changes: Vec<PluginFixChange>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PluginFixChange {
+  span: PluginSpan,
+  new_text: String,
+}
+
+#[derive(Deserialize)]
+struct PluginSpan {
+  start: usize,
+  end: usize,
+}
+
+/// A rule provided by a plugin runner.
+pub struct PluginRule {
+  code: &'static str,
+  /// Version reported by the runner, which changes with the rule's source.
+  version: String,
+  host: Arc<PluginHostInner>,
+}
+
+/// The debug representation identifies the rule in the lint cache, so it
+/// contains the version of the rule but not any state of the process.
+impl std::fmt::Debug for PluginRule {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("PluginRule")
+      .field("code", &self.code)
+      .field("version", &self.version)
+      .field("command", &self.host.command)
+      .finish()
+  }
+}
+
+impl PluginRule {
+  fn lint(&self, context: &Context) -> Result<LintResponse, PluginError> {
+    let ast = self.host.serialized_ast(context)?;
+    self.host.request(&json!({
+      "type": "lint",
+      "rule": self.code,
+      "specifier": context.specifier().as_str(),
+      "source": context.text_info().text_str(),
+      "ast": &*ast,
+    }))
+  }
+
+  fn range(
+    &self,
+    context: &Context,
+    span: &PluginSpan,
+  ) -> Result<SourceRange, PluginError> {
+    let file_range = context.text_info().range();
+    let len = file_range.end - file_range.start;
+    if span.start > span.end || span.end > len {
+      return Err(PluginError::InvalidResponse(format!(
+        "span {}..{} is out of the bounds of the file",
+        span.start, span.end
+      )));
+    }
+    let text = context.text_info().text_str();
+    if !text.is_char_boundary(span.start) || !text.is_char_boundary(span.end) {
+      return Err(PluginError::InvalidResponse(format!(
+        "span {}..{} doesn't start and end on character boundaries",
+        span.start, span.end
+      )));
+    }
+    Ok(SourceRange::new(
+      file_range.start + span.start,
+      file_range.start + span.end,
+    ))
+  }
+
+  fn report(
+    &self,
+    context: &mut Context,
+    response: LintResponse,
+  ) -> Result<(), PluginError> {
+    let mut diagnostics = Vec::with_capacity(response.diagnostics.len());
+    for diagnostic in response.diagnostics {
+      let range = self.range(context, &diagnostic.span)?;
+      let fixes = diagnostic
+        .fixes
+        .into_iter()
+        .map(|fix| {
+          Ok(LintFix {
+            description: fix.description.into(),
+            kind: fix.kind,
+            changes: fix
+              .changes
+              .into_iter()
+              .map(|change| {
+                Ok(LintFixChange {
+                  new_text: change.new_text.into(),
+                  range: self.range(context, &change.span)?,
+                })
+              })
+              .collect::<Result<_, PluginError>>()?,
+          })
+        })
+        .collect::<Result<_, PluginError>>()?;
+      diagnostics.push((range, diagnostic.message, diagnostic.hint, fixes));
+    }
+
+    for (range, message, hint, fixes) in diagnostics {
+      let details =
+        context.create_diagnostic_details(self.code, message, hint, fixes);
+      context.add_diagnostic_details(
+        Some(context.create_diagnostic_range(range)),
+        details,
+      );
+    }
+    Ok(())
+  }
+}
+
+impl LintRule for PluginRule {
+  fn lint_program_with_ast_view<'view>(
+    &self,
+    context: &mut Context<'view>,
+    _program: Program<'view>,
+  ) {
+    let result = self
+      .lint(context)
+      .and_then(|response| self.report(context, response));
+    if let Err(err) = result {
+      // Report the failure at the start of the file, so it can't go unnoticed.
+      let start = context.text_info().range().start.as_source_pos();
+      let details = context.create_diagnostic_details(
+        self.code,
+        err.to_string(),
+        None,
+        vec![],
+      );
+      context.add_diagnostic_details(
+        Some(context.create_diagnostic_range(SourceRange::new(start, start))),
+        details,
+      );
+    }
+  }
+
+  fn code(&self) -> &'static str {
+    self.code
+  }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+  use super::*;
+  use crate::linter::LinterOptions;
+  use crate::test_util::{lint_file, linter};
+
+  /// A runner that reports the last `foo` identifier of the file, found with
+  /// `sed`, which is enough to check the offsets of the AST.
+  const RUNNER: &str = r#"
+while read -r line; do
+  case "$line" in
+    *getRules*) echo '{"protocolVersion":1,"rules":[{"code":"no-foo","version":"1"}]}' ;;
+    *'"value":"foo"'*)
+      span=$(printf '%s' "$line" | sed 's/.*"span":\({[^}]*}\),"type":"Identifier","value":"foo".*/\1/')
+      echo "{\"diagnostics\":[{\"span\":$span,\"message\":\"foo\",\"fixes\":[{\"description\":\"Rename\",\"changes\":[{\"span\":$span,\"newText\":\"bar\"}]}]}]}"
+      ;;
+    *'"value":"oops"'*) echo '{"error":"oops"}' ;;
+    *'"value":"ü"'*) echo '{"diagnostics":[{"span":{"start":1,"end":2},"message":"ü"}]}' ;;
+    *) echo '{"diagnostics":[]}' ;;
+  esac
+done
+"#;
+
+  fn lint(source: &str) -> Vec<(String, String, Option<String>)> {
+    let host = PluginHost::spawn("sh", ["-c", RUNNER]).unwrap();
+    let rules = host.rules().unwrap();
+    assert_eq!(
+      rules.iter().map(|rule| rule.code()).collect::<Vec<_>>(),
+      vec!["no-foo"]
+    );
+    let linter = linter(
+      rules,
+      LinterOptions {
+        all_rule_codes: ["no-foo"].into_iter().collect(),
+        ..Default::default()
+      },
+    );
+    lint_file(&linter, source, Default::default())
+      .into_iter()
+      .map(|d| {
+        let range = d.range.unwrap();
+        let text = range.text_info.range_text(&range.range).to_string();
+        let fix = d.details.fixes.first().map(|fix| {
+          let change = &fix.changes[0];
+          format!(
+            "{} -> {}",
+            range.text_info.range_text(&change.range),
+            change.new_text
+          )
+        });
+        (d.details.message, text, fix)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn plugin_diagnostics() {
+    assert_eq!(
+      lint("const a = 1;\nfoo();"),
+      vec![(
+        "foo".to_string(),
+        "foo".to_string(),
+        Some("foo -> bar".to_string())
+      )]
+    );
+    assert!(lint("bar();").is_empty());
+  }
+
+  #[test]
+  fn plugin_rules() {
+    let host = PluginHost::spawn("sh", ["-c", RUNNER]).unwrap();
+    let rules = host.rules().unwrap();
+    // The version of the rule is part of the keys of cached results.
+    assert!(rules[0].cache_key().contains(r#"version: "1""#));
+
+    let runner = r#"read -r line; echo '{"protocolVersion":2,"rules":[]}'"#;
+    let host = PluginHost::spawn("sh", ["-c", runner]).unwrap();
+    assert_eq!(
+      host.rules().unwrap_err().to_string(),
+      "Invalid response from the plugin runner: unsupported protocol version 2, expected 1"
+    );
+  }
+
+  /// The AST is part of the protocol, so changes to its shape need a new
+  /// `PLUGIN_PROTOCOL_VERSION`.
+  #[test]
+  fn plugin_ast() {
+    let parsed_source = crate::test_util::parse("foo(1);");
+    let span = |start: u32, end: u32| json!({ "start": start, "end": end });
+    assert_eq!(PLUGIN_PROTOCOL_VERSION, 1);
+    assert_eq!(
+      serialize_ast(&parsed_source).unwrap(),
+      json!({
+        "type": "Script",
+        "span": span(0, 7),
+        "interpreter": null,
+        "body": [{
+          "type": "ExpressionStatement",
+          "span": span(0, 7),
+          "expression": {
+            "type": "CallExpression",
+            "span": span(0, 6),
+            "callee": {
+              "type": "Identifier",
+              "span": span(0, 3),
+              "value": "foo",
+              "optional": false,
+            },
+            "arguments": [{
+              "spread": null,
+              "expression": {
+                "type": "NumericLiteral",
+                "span": span(4, 5),
+                "value": 1.0,
+                "raw": "1",
+              },
+            }],
+            "typeArguments": null,
+          },
+        }],
+      })
+    );
+  }
+
+  #[test]
+  fn plugin_error() {
+    assert_eq!(
+      lint("oops();"),
+      vec![("Plugin failed: oops".to_string(), "".to_string(), None)]
+    );
+    // A span in the middle of `ü`.
+    assert_eq!(
+      lint("ü;"),
+      vec![(
+        "Invalid response from the plugin runner: span 1..2 doesn't start and end on character boundaries".to_string(),
+        "".to_string(),
+        None
+      )]
+    );
+  }
+}
@@ -0,0 +1,339 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Presets are named sets of rules. Each tag is a preset containing the rules
+//! that currently have the tag, e.g. `recommended`, and a preset can be
+//! pinned to the set of rules it had in a release, e.g. `recommended@0.69`,
+//! so upgrading doesn't enable new rules until the pin is bumped.
+
+use crate::rules::get_all_rules;
+use crate::tags::ALL_TAGS;
+use derive_more::Display;
+use std::collections::BTreeSet;
+
+/// The rules of a preset as of a release.
+struct PresetSnapshot {
+  name: &'static str,
+  /// `major.minor` version of the release.
+  version: &'static str,
+  rules: &'static [&'static str],
+}
+
+/// Snapshots of the presets, taken on releases changing them, oldest first.
+///
+/// When the rules of a tag change, a snapshot with the next version must be
+/// added, which is checked by the `latest_snapshots_are_up_to_date` test.
+const SNAPSHOTS: &[PresetSnapshot] = &[
+  PresetSnapshot {
+    name: "recommended",
+    version: "0.69",
+    rules: &[
+      "adjacent-overload-signatures",
+      "ban-ts-comment",
+      "ban-types",
+      "ban-unknown-rule-code",
+      "ban-untagged-ignore",
+      "ban-unused-ignore",
+      "button-has-type",
+      "constructor-super",
+      "for-direction",
+      "getter-return",
+      "jsx-boolean-value",
+      "jsx-curly-braces",
+      "jsx-key",
+      "jsx-no-children-prop",
+      "jsx-no-comment-text-nodes",
+      "jsx-no-danger-with-children",
+      "jsx-no-duplicate-props",
+      "jsx-no-unescaped-entities",
+      "jsx-no-useless-fragment",
+      "jsx-props-no-spread-multi",
+      "jsx-void-dom-elements-no-children",
+      "no-array-constructor",
+      "no-async-promise-executor",
+      "no-await-in-sync-fn",
+      "no-case-declarations",
+      "no-class-assign",
+      "no-compare-neg-zero",
+      "no-cond-assign",
+      "no-constant-condition",
+      "no-control-regex",
+      "no-danger",
+      "no-debugger",
+      "no-delete-var",
+      "no-deprecated-deno-api",
+      "no-dupe-args",
+      "no-dupe-class-members",
+      "no-dupe-else-if",
+      "no-dupe-keys",
+      "no-duplicate-case",
+      "no-empty",
+      "no-empty-character-class",
+      "no-empty-enum",
+      "no-empty-interface",
+      "no-empty-pattern",
+      "no-ex-assign",
+      "no-explicit-any",
+      "no-extra-boolean-cast",
+      "no-extra-non-null-assertion",
+      "no-fallthrough",
+      "no-func-assign",
+      "no-global-assign",
+      "no-import-assertions",
+      "no-import-assign",
+      "no-inner-declarations",
+      "no-invalid-regexp",
+      "no-invalid-triple-slash-reference",
+      "no-irregular-whitespace",
+      "no-misused-new",
+      "no-namespace",
+      "no-new-symbol",
+      "no-node-globals",
+      "no-obj-calls",
+      "no-octal",
+      "no-process-global",
+      "no-prototype-builtins",
+      "no-redeclare",
+      "no-regex-spaces",
+      "no-self-assign",
+      "no-setter-return",
+      "no-shadow-restricted-names",
+      "no-this-alias",
+      "no-this-before-super",
+      "no-unreachable",
+      "no-unsafe-finally",
+      "no-unsafe-negation",
+      "no-unused-labels",
+      "no-unused-vars",
+      "no-var",
+      "no-window",
+      "no-window-prefix",
+      "no-with",
+      "prefer-as-const",
+      "prefer-const",
+      "prefer-namespace-keyword",
+      "require-await",
+      "require-yield",
+      "rules-of-hooks",
+      "use-isnan",
+      "valid-typeof",
+    ],
+  },
+  PresetSnapshot {
+    name: "fresh",
+    version: "0.69",
+    rules: &[
+      "button-has-type",
+      "fresh-handler-export",
+      "fresh-server-event-handlers",
+      "jsx-no-children-prop",
+      "jsx-no-comment-text-nodes",
+      "jsx-no-danger-with-children",
+      "jsx-no-unescaped-entities",
+      "jsx-no-useless-fragment",
+      "jsx-void-dom-elements-no-children",
+      "rules-of-hooks",
+    ],
+  },
+  PresetSnapshot {
+    name: "jsr",
+    version: "0.69",
+    rules: &["verbatim-module-syntax"],
+  },
+  PresetSnapshot {
+    name: "react",
+    version: "0.69",
+    rules: &[
+      "button-has-type",
+      "jsx-boolean-value",
+      "jsx-curly-braces",
+      "jsx-key",
+      "jsx-no-children-prop",
+      "jsx-no-comment-text-nodes",
+      "jsx-no-danger-with-children",
+      "jsx-no-duplicate-props",
+      "jsx-no-unescaped-entities",
+      "jsx-no-useless-fragment",
+      "jsx-props-no-spread-multi",
+      "jsx-void-dom-elements-no-children",
+      "no-danger",
+      "rules-of-hooks",
+    ],
+  },
+  PresetSnapshot {
+    name: "jsx",
+    version: "0.69",
+    rules: &[
+      "button-has-type",
+      "jsx-boolean-value",
+      "jsx-curly-braces",
+      "jsx-key",
+      "jsx-no-children-prop",
+      "jsx-no-comment-text-nodes",
+      "jsx-no-danger-with-children",
+      "jsx-no-duplicate-props",
+      "jsx-no-unescaped-entities",
+      "jsx-no-useless-fragment",
+      "jsx-props-no-spread-multi",
+      "jsx-void-dom-elements-no-children",
+      "no-danger",
+      "rules-of-hooks",
+    ],
+  },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum PresetError {
+  #[display(fmt = "Unknown preset \"{}\"", _0)]
+  UnknownPreset(String),
+  #[display(
+    fmt = "Unknown version \"{}\" of preset \"{}\", available versions: {}",
+    version,
+    name,
+    "available.join(\", \")"
+  )]
+  UnknownVersion {
+    name: String,
+    version: String,
+    available: Vec<&'static str>,
+  },
+}
+
+impl std::error::Error for PresetError {}
+
+/// Whether the name is a preset, optionally with a version, rather than a
+/// plain tag.
+pub fn is_versioned_preset(spec: &str) -> bool {
+  spec.contains('@')
+}
+
+/// Returns codes of the rules of a preset, given as `name` for the current
+/// rules or `name@version` for the rules as of a release.
+///
+/// Versioned presets may contain rules that have since been removed, which
+/// are ignored when filtering rules.
+pub fn preset_rules(spec: &str) -> Result<BTreeSet<&'static str>, PresetError> {
+  let (name, version) = match spec.split_once('@') {
+    Some((name, version)) => (name, Some(version)),
+    None => (spec, None),
+  };
+  let Some(tag) = ALL_TAGS.iter().find(|tag| tag.display() == name) else {
+    return Err(PresetError::UnknownPreset(name.to_string()));
+  };
+
+  match version {
+    None => Ok(
+      get_all_rules()
+        .iter()
+        .filter(|rule| rule.tags().contains(tag))
+        .map(|rule| rule.code())
+        .collect(),
+    ),
+    Some(version) => {
+      // A version without a snapshot of its own has the rules of the last
+      // snapshot before it.
+      let snapshot = SNAPSHOTS
+        .iter()
+        .filter(|snapshot| {
+          snapshot.name == name
+            && compare_versions(snapshot.version, version)
+              .is_some_and(|ordering| ordering != std::cmp::Ordering::Greater)
+        })
+        .last();
+      match snapshot {
+        Some(snapshot) => Ok(snapshot.rules.iter().copied().collect()),
+        None => Err(PresetError::UnknownVersion {
+          name: name.to_string(),
+          version: version.to_string(),
+          available: preset_versions(name),
+        }),
+      }
+    }
+  }
+}
+
+/// Versions of a preset that have a snapshot.
+pub fn preset_versions(name: &str) -> Vec<&'static str> {
+  SNAPSHOTS
+    .iter()
+    .filter(|snapshot| snapshot.name == name)
+    .map(|snapshot| snapshot.version)
+    .collect()
+}
+
+/// Rules added to and removed from a preset between two of its versions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PresetDiff {
+  pub added: Vec<&'static str>,
+  pub removed: Vec<&'static str>,
+}
+
+/// Compares the rules of two presets, e.g. `recommended@0.69` and
+/// `recommended`.
+pub fn diff_presets(from: &str, to: &str) -> Result<PresetDiff, PresetError> {
+  let from = preset_rules(from)?;
+  let to = preset_rules(to)?;
+  Ok(PresetDiff {
+    added: to.difference(&from).copied().collect(),
+    removed: from.difference(&to).copied().collect(),
+  })
+}
+
+fn compare_versions(a: &str, b: &str) -> Option<std::cmp::Ordering> {
+  fn parse(version: &str) -> Option<Vec<u64>> {
+    version.split('.').map(|part| part.parse().ok()).collect()
+  }
+  Some(parse(a)?.cmp(&parse(b)?))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn latest_snapshots_are_up_to_date() {
+    for tag in ALL_TAGS {
+      let name = tag.display();
+      let latest = preset_versions(name).last().copied().unwrap();
+      assert_eq!(
+        preset_rules(&format!("{}@{}", name, latest)).unwrap(),
+        preset_rules(name).unwrap(),
+        "rules of \"{}\" changed since {}, add a snapshot for the next \
+         version to `SNAPSHOTS`",
+        name,
+        latest
+      );
+    }
+  }
+
+  #[test]
+  fn versioned_presets() {
+    assert!(preset_rules("recommended@0.69")
+      .unwrap()
+      .contains("no-debugger"));
+    // Later versions have the rules of the last snapshot.
+    assert_eq!(
+      preset_rules("jsr@0.75").unwrap(),
+      preset_rules("jsr@0.69").unwrap()
+    );
+    assert_eq!(
+      preset_rules("jsr@0.1"),
+      Err(PresetError::UnknownVersion {
+        name: "jsr".to_string(),
+        version: "0.1".to_string(),
+        available: vec!["0.69"],
+      })
+    );
+    assert_eq!(
+      preset_rules("strict"),
+      Err(PresetError::UnknownPreset("strict".to_string()))
+    );
+    assert_eq!(
+      diff_presets("jsr@0.69", "jsr").unwrap(),
+      PresetDiff::default()
+    );
+    assert_eq!(
+      diff_presets("jsr", "fresh").unwrap().removed,
+      vec!["verbatim-module-syntax"]
+    );
+  }
+}
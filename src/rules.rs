@@ -1,12 +1,18 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use crate::context::Context;
+use crate::context::{Context, ProjectContext};
+use crate::diagnostic::LintSeverity;
+use crate::file_scope::FileScope;
+use crate::handler::{Handler, Traverse};
 use crate::tags;
+use crate::tags::TagExpr;
 use crate::tags::Tags;
 use crate::Program;
 use crate::ProgramRef;
+use derive_more::Display;
+use serde::de::DeserializeOwned;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 pub mod adjacent_overload_signatures;
 pub mod ban_ts_comment;
@@ -14,6 +20,7 @@ pub mod ban_types;
 pub mod ban_unknown_rule_code;
 pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
+pub mod ban_unused_global;
 pub mod ban_unused_ignore;
 pub mod button_has_type;
 pub mod camelcase;
@@ -105,6 +112,7 @@ pub mod no_this_before_super;
 pub mod no_throw_literal;
 pub mod no_top_level_await;
 pub mod no_undef;
+pub mod no_unexported_import;
 pub mod no_unreachable;
 pub mod no_unsafe_finally;
 pub mod no_unsafe_negation;
@@ -131,21 +139,63 @@ pub mod verbatim_module_syntax;
 
 pub trait LintRule: std::fmt::Debug + Send + Sync {
   /// Executes lint using `dprint-swc-ecma-ast-view`.
-  /// Falls back to the `lint_program` method if not implemented.
+  /// By default it traverses the program with the handler returned from
+  /// `handler`.
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
     program: Program<'view>,
-  );
+  ) {
+    if let Some(mut handler) = self.handler() {
+      handler.traverse(program, context);
+    }
+  }
+
+  /// Returns a fresh handler for a single file, if this rule is implemented
+  /// purely as a `Handler`.
+  ///
+  /// Handlers of all the enabled rules are run by `Linter` in one combined
+  /// traversal of the program, in which case `lint_program_with_ast_view` is
+  /// not called.
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    None
+  }
 
   /// Returns the unique code that identifies the rule
   fn code(&self) -> &'static str;
 
+  /// One-line description of the rule, e.g. for the rules of SARIF logs
+  fn description(&self) -> Option<&'static str> {
+    None
+  }
+
   /// Returns the tags this rule belongs to, e.g. `recommended`
   fn tags(&self) -> Tags {
     &[]
   }
 
+  /// Severity of diagnostics reported by this rule, unless overridden in
+  /// `LinterOptions`.
+  fn default_severity(&self) -> LintSeverity {
+    LintSeverity::Error
+  }
+
+  /// Files this rule is run on, unless overridden in `LinterOptions`. By
+  /// default rules are run on all files.
+  fn file_scope(&self) -> Option<FileScope> {
+    None
+  }
+
+  /// Identifies the rule along with its options in caches of lint results,
+  /// so it has to change whenever the rule could report other diagnostics
+  /// for the same file, e.g. after `LintRule::configure`.
+  ///
+  /// Defaults to the debug representation of the rule, which includes the
+  /// options of the built-in rules.
+  fn cache_key(&self) -> String {
+    format!("{:?}", self)
+  }
+
   /// The lower the return value is, the earlier this rule will be run.
   ///
   /// By default it is 0. Some rules might want to defer being run to the end
@@ -153,6 +203,76 @@ pub trait LintRule: std::fmt::Debug + Send + Sync {
   fn priority(&self) -> u32 {
     0
   }
+
+  /// Configures the rule with user provided options.
+  ///
+  /// By default rules don't accept any options, so anything other than
+  /// `null` is rejected. Of the built-in rules, only these accept options:
+  ///
+  /// - `eqeqeq`: `{ "allowNull": true }` allows `== null` and `!= null`.
+  /// - `no-console`: `{ "allow": ["error"] }` allows the listed methods.
+  fn configure(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), LintRuleOptionsError> {
+    if options.is_null() {
+      Ok(())
+    } else {
+      Err(LintRuleOptionsError {
+        code: self.code().to_string(),
+        message: "this rule doesn't accept any options".to_string(),
+      })
+    }
+  }
+}
+
+/// A rule that is run once over all the files linted together by
+/// `Linter::lint_project`, as opposed to `LintRule` which only ever sees a
+/// single file.
+pub trait ProjectLintRule: std::fmt::Debug + Send + Sync {
+  /// Executes lint over all files of the project.
+  fn lint_project(&self, context: &mut ProjectContext);
+
+  /// Returns the unique code that identifies the rule
+  fn code(&self) -> &'static str;
+
+  /// One-line description of the rule, e.g. for the rules of SARIF logs
+  fn description(&self) -> Option<&'static str> {
+    None
+  }
+
+  /// Returns the tags this rule belongs to, e.g. `recommended`
+  fn tags(&self) -> Tags {
+    &[]
+  }
+
+  /// Severity of diagnostics reported by this rule, unless overridden in
+  /// `LinterOptions`.
+  fn default_severity(&self) -> LintSeverity {
+    LintSeverity::Error
+  }
+}
+
+/// Error returned when options passed to a rule are invalid.
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "Invalid options for rule \"{}\": {}", code, message)]
+pub struct LintRuleOptionsError {
+  /// Code of the rule the options were passed to.
+  pub code: String,
+  pub message: String,
+}
+
+impl std::error::Error for LintRuleOptionsError {}
+
+/// Deserializes options of a rule into its typed representation.
+pub(crate) fn deserialize_options<T: DeserializeOwned>(
+  code: &str,
+  options: &serde_json::Value,
+) -> Result<T, LintRuleOptionsError> {
+  T::deserialize(options).map_err(|err| LintRuleOptionsError {
+    code: code.to_string(),
+    message: err.to_string(),
+  })
 }
 
 /// TODO(@magurotuna): remove this after all rules get to use ast_view
@@ -167,6 +287,10 @@ pub fn get_all_rules() -> Vec<Box<dyn LintRule>> {
   get_all_rules_raw()
 }
 
+pub fn get_all_project_rules() -> Vec<Box<dyn ProjectLintRule>> {
+  vec![Box::new(no_unexported_import::NoUnexportedImport)]
+}
+
 /// Filters the lint rules to only the recommended rules.
 pub fn recommended_rules(
   all_rules: Vec<Box<dyn LintRule>>,
@@ -234,6 +358,168 @@ pub fn filtered_rules(
   rules
 }
 
+/// Returns the rules whose tags match the expression, sorted alphabetically.
+pub fn filtered_rules_by_tag_expr(
+  all_rules: Vec<Box<dyn LintRule>>,
+  expr: &TagExpr,
+) -> Vec<Box<dyn LintRule>> {
+  let mut rules = all_rules
+    .into_iter()
+    .filter(|rule| expr.matches(rule.tags()))
+    .collect::<Vec<_>>();
+  rules.sort_by_key(|r| r.code());
+  rules
+}
+
+/// Returns a list of rules after filtering, like `filtered_rules`, with each
+/// rule configured with its entry of `options`.
+///
+/// Rules are configured before they're filtered, so `options` can contain
+/// entries of any rule in `all_rules`, but not of unknown rules.
+pub fn filtered_configured_rules(
+  mut all_rules: Vec<Box<dyn LintRule>>,
+  maybe_tags: Option<Vec<String>>,
+  maybe_exclude: Option<Vec<String>>,
+  maybe_include: Option<Vec<String>>,
+  options: &HashMap<String, serde_json::Value>,
+) -> Result<Vec<Box<dyn LintRule>>, LintRuleOptionsError> {
+  configure_rules(&mut all_rules, options)?;
+  Ok(filtered_rules(
+    all_rules,
+    maybe_tags,
+    maybe_exclude,
+    maybe_include,
+  ))
+}
+
+/// Configures rules with options keyed by rule code.
+///
+/// Options for codes that don't belong to any of `rules` are rejected.
+pub fn configure_rules(
+  rules: &mut [Box<dyn LintRule>],
+  options: &HashMap<String, serde_json::Value>,
+) -> Result<(), LintRuleOptionsError> {
+  if let Some(code) = options
+    .keys()
+    .find(|code| !rules.iter().any(|rule| rule.code() == code.as_str()))
+  {
+    return Err(LintRuleOptionsError {
+      code: code.clone(),
+      message: "unknown rule".to_string(),
+    });
+  }
+  for rule in rules.iter_mut() {
+    if let Some(rule_options) = options.get(rule.code()) {
+      rule.configure(rule_options)?;
+    }
+  }
+  Ok(())
+}
+
+/// Error returned when a rule is registered with a code that is already used
+/// by another rule.
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "Rule code \"{}\" is already registered", code)]
+pub struct RuleCodeCollisionError {
+  pub code: String,
+}
+
+impl std::error::Error for RuleCodeCollisionError {}
+
+/// Collection of rules available to lint with: the built-in rules and rules
+/// registered by other crates, e.g. to enforce project conventions.
+///
+/// Rules of other crates are written the same way as built-in ones, usually
+/// by implementing `LintRule::handler` with a `crate::handler::Handler`.
+pub struct RuleRegistry {
+  rules: Vec<Box<dyn LintRule>>,
+  project_rules: Vec<Box<dyn ProjectLintRule>>,
+}
+
+impl Default for RuleRegistry {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl RuleRegistry {
+  /// Creates a registry containing all the built-in rules.
+  pub fn new() -> Self {
+    Self {
+      rules: get_all_rules(),
+      project_rules: get_all_project_rules(),
+    }
+  }
+
+  /// Creates a registry without any rules.
+  pub fn empty() -> Self {
+    Self {
+      rules: Vec::new(),
+      project_rules: Vec::new(),
+    }
+  }
+
+  pub fn register(
+    &mut self,
+    rule: Box<dyn LintRule>,
+  ) -> Result<(), RuleCodeCollisionError> {
+    self.check_code(rule.code())?;
+    self.rules.push(rule);
+    Ok(())
+  }
+
+  pub fn register_project_rule(
+    &mut self,
+    rule: Box<dyn ProjectLintRule>,
+  ) -> Result<(), RuleCodeCollisionError> {
+    self.check_code(rule.code())?;
+    self.project_rules.push(rule);
+    Ok(())
+  }
+
+  fn check_code(&self, code: &str) -> Result<(), RuleCodeCollisionError> {
+    if code == crate::diagnostic::PARSE_ERROR_CODE
+      || self.all_rule_codes().contains(code)
+    {
+      return Err(RuleCodeCollisionError {
+        code: code.to_string(),
+      });
+    }
+    Ok(())
+  }
+
+  /// Codes of all the registered rules, to be passed as
+  /// `LinterOptions::all_rule_codes` so that ignore directives for any of
+  /// them are not reported by `ban-unknown-rule-code`.
+  pub fn all_rule_codes(&self) -> HashSet<&'static str> {
+    self
+      .rules
+      .iter()
+      .map(|rule| rule.code())
+      .chain(self.project_rules.iter().map(|rule| rule.code()))
+      .collect()
+  }
+
+  pub fn rules(&self) -> &[Box<dyn LintRule>] {
+    &self.rules
+  }
+
+  pub fn project_rules(&self) -> &[Box<dyn ProjectLintRule>] {
+    &self.project_rules
+  }
+
+  /// Takes the registered rules out of the registry, e.g. to narrow them
+  /// down with `filtered_rules` and pass them to `LinterOptions::rules`.
+  pub fn take_rules(&mut self) -> Vec<Box<dyn LintRule>> {
+    std::mem::take(&mut self.rules)
+  }
+
+  /// Takes the registered project-level rules out of the registry.
+  pub fn take_project_rules(&mut self) -> Vec<Box<dyn ProjectLintRule>> {
+    std::mem::take(&mut self.project_rules)
+  }
+}
+
 /// Sort lint rules by priority and alphabetically.
 pub(crate) fn sort_rules_by_priority(rules: &mut [Box<dyn LintRule>]) {
   rules.sort_by(|rule1, rule2| {
@@ -255,12 +541,13 @@ fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
     Box::new(ban_unknown_rule_code::BanUnknownRuleCode),
     Box::new(ban_untagged_ignore::BanUntaggedIgnore),
     Box::new(ban_untagged_todo::BanUntaggedTodo),
+    Box::new(ban_unused_global::BanUnusedGlobal),
     Box::new(ban_unused_ignore::BanUnusedIgnore),
     Box::new(button_has_type::ButtonHasType),
     Box::new(camelcase::Camelcase),
     Box::new(constructor_super::ConstructorSuper),
     Box::new(default_param_last::DefaultParamLast),
-    Box::new(eqeqeq::Eqeqeq),
+    Box::new(eqeqeq::Eqeqeq::default()),
     Box::new(explicit_function_return_type::ExplicitFunctionReturnType),
     Box::new(explicit_module_boundary_types::ExplicitModuleBoundaryTypes),
     Box::new(for_direction::ForDirection),
@@ -288,7 +575,7 @@ fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
     Box::new(no_class_assign::NoClassAssign),
     Box::new(no_compare_neg_zero::NoCompareNegZero),
     Box::new(no_cond_assign::NoCondAssign),
-    Box::new(no_console::NoConsole),
+    Box::new(no_console::NoConsole::default()),
     Box::new(no_const_assign::NoConstAssign),
     Box::new(no_constant_condition::NoConstantCondition),
     Box::new(no_control_regex::NoControlRegex),
@@ -406,6 +693,52 @@ mod tests {
     }
   }
 
+  #[test]
+  fn schemas_are_up_to_date() {
+    fn schema_enum(schema: &str) -> Vec<String> {
+      let schema: serde_json::Value = serde_json::from_str(schema).unwrap();
+      serde_json::from_value(schema["enum"].clone()).unwrap()
+    }
+
+    // The schema also lists rules implemented by Deno itself.
+    let schema_codes = schema_enum(include_str!("../schemas/rules.v1.json"));
+    for rule in get_all_rules() {
+      assert!(
+        schema_codes.iter().any(|c| c == rule.code()),
+        "\"{}\" is missing in schemas/rules.v1.json",
+        rule.code()
+      );
+    }
+    // Project-level rules can't be enabled in configs, as they're only run
+    // by `Linter::lint_project`.
+    for rule in get_all_project_rules() {
+      assert!(
+        !schema_codes.iter().any(|c| c == rule.code()),
+        "\"{}\" is listed in schemas/rules.v1.json",
+        rule.code()
+      );
+    }
+
+    for (code, description) in get_all_rules()
+      .iter()
+      .map(|rule| (rule.code(), rule.description()))
+      .chain(
+        get_all_project_rules()
+          .iter()
+          .map(|rule| (rule.code(), rule.description())),
+      )
+    {
+      assert!(description.is_some(), "\"{}\" has no description", code);
+    }
+
+    let mut tags = crate::tags::ALL_TAGS
+      .iter()
+      .map(|tag| tag.to_string())
+      .collect::<Vec<_>>();
+    tags.sort();
+    assert_eq!(schema_enum(include_str!("../schemas/tags.v1.json")), tags);
+  }
+
   #[test]
   fn test_get_filtered_rules() {
     // Should return recommended rules when given `recommended` tag.
@@ -479,6 +812,18 @@ mod tests {
     assert_eq!(rules[1].code(), "no-throw-literal");
   }
 
+  #[test]
+  fn test_filtered_rules_by_tag_expr() {
+    let expr = TagExpr::parse("jsx & !fresh").unwrap();
+    let codes = filtered_rules_by_tag_expr(get_all_rules(), &expr)
+      .iter()
+      .map(|rule| rule.code())
+      .collect::<Vec<_>>();
+    assert!(codes.contains(&"jsx-key"));
+    assert!(!codes.contains(&"jsx-no-children-prop"));
+    assert!(!codes.contains(&"no-debugger"));
+  }
+
   #[test]
   fn ensure_lint_rules_are_sharable_across_threads() {
     use std::thread::spawn;
@@ -500,13 +845,146 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_configure_rules() {
+    let mut rules = filtered_rules(
+      get_all_rules(),
+      Some(vec![]),
+      None,
+      Some(vec!["eqeqeq".to_string(), "no-debugger".to_string()]),
+    );
+
+    let options = HashMap::from([
+      (
+        "eqeqeq".to_string(),
+        serde_json::json!({ "allowNull": true }),
+      ),
+      (
+        "no-console".to_string(),
+        serde_json::json!({ "allow": ["error"] }),
+      ),
+    ]);
+    let err = configure_rules(&mut rules, &options).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Invalid options for rule \"no-console\": unknown rule"
+    );
+    let configured = filtered_configured_rules(
+      get_all_rules(),
+      Some(vec![]),
+      None,
+      Some(vec!["eqeqeq".to_string(), "no-debugger".to_string()]),
+      &options,
+    )
+    .unwrap();
+    assert_eq!(
+      configured
+        .iter()
+        .map(|rule| rule.code())
+        .collect::<Vec<_>>(),
+      vec!["eqeqeq", "no-debugger"]
+    );
+
+    let options =
+      HashMap::from([("no-such-rule".to_string(), serde_json::Value::Null)]);
+    let err =
+      filtered_configured_rules(get_all_rules(), None, None, None, &options)
+        .unwrap_err();
+    assert_eq!(err.code, "no-such-rule");
+
+    let options = HashMap::from([(
+      "eqeqeq".to_string(),
+      serde_json::json!({ "allowNull": "yes" }),
+    )]);
+    let err = configure_rules(&mut rules, &options).unwrap_err();
+    assert_eq!(err.code, "eqeqeq");
+
+    let options = HashMap::from([(
+      "no-debugger".to_string(),
+      serde_json::json!({ "foo": true }),
+    )]);
+    let err = configure_rules(&mut rules, &options).unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Invalid options for rule \"no-debugger\": this rule doesn't accept any options"
+    );
+  }
+
+  #[test]
+  fn rule_registry() {
+    use crate::handler::Handler;
+    use crate::linter::LinterOptions;
+    use crate::test_util::{lint_file, linter};
+    use deno_ast::view as ast_view;
+    use deno_ast::SourceRanged;
+
+    #[derive(Debug)]
+    struct NoFoo;
+
+    impl LintRule for NoFoo {
+      fn code(&self) -> &'static str {
+        "acme/no-foo"
+      }
+
+      fn handler(&self) -> Option<Box<dyn Handler>> {
+        Some(Box::new(NoFooHandler))
+      }
+    }
+
+    struct NoFooHandler;
+
+    impl Handler for NoFooHandler {
+      fn ident(&mut self, ident: &ast_view::Ident, ctx: &mut Context) {
+        if ident.sym() == "foo" {
+          ctx.add_diagnostic(ident.range(), "acme/no-foo", "foo is banned");
+        }
+      }
+    }
+
+    let mut registry = RuleRegistry::new();
+    registry.register(Box::new(NoFoo)).unwrap();
+    assert_eq!(
+      registry.register(Box::new(NoFoo)).unwrap_err().to_string(),
+      "Rule code \"acme/no-foo\" is already registered"
+    );
+    assert!(registry
+      .register(Box::new(ban_unknown_rule_code::BanUnknownRuleCode))
+      .is_err());
+    assert!(registry.all_rule_codes().contains("acme/no-foo"));
+    assert!(registry.all_rule_codes().contains("no-unexported-import"));
+
+    let all_rule_codes = registry.all_rule_codes();
+    let linter = linter(
+      filtered_rules(
+        registry.take_rules(),
+        Some(vec![]),
+        None,
+        Some(vec![
+          "acme/no-foo".to_string(),
+          "ban-unknown-rule-code".to_string(),
+        ]),
+      ),
+      LinterOptions {
+        all_rule_codes,
+        ..Default::default()
+      },
+    );
+    let diagnostics = lint_file(
+      &linter,
+      "foo();\n// deno-lint-ignore acme/no-foo\nfoo();",
+      Default::default(),
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].details.code, "acme/no-foo");
+  }
+
   #[test]
   fn sort_by_priority() {
     let mut rules: Vec<Box<dyn LintRule>> = vec![
       Box::new(ban_unknown_rule_code::BanUnknownRuleCode),
       Box::new(ban_unused_ignore::BanUnusedIgnore),
       Box::new(no_redeclare::NoRedeclare),
-      Box::new(eqeqeq::Eqeqeq),
+      Box::new(eqeqeq::Eqeqeq::default()),
     ];
 
     sort_rules_by_priority(&mut rules);
@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::StringRepr;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
 use std::collections::HashSet;
@@ -35,18 +35,29 @@ impl LintRule for AdjacentOverloadSignatures {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    AdjacentOverloadSignaturesHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires overload signatures to be next to each other.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(AdjacentOverloadSignaturesHandler))
   }
 }
 
 struct AdjacentOverloadSignaturesHandler;
 
 impl Handler for AdjacentOverloadSignaturesHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::Script,
+      NodeKind::Module,
+      NodeKind::TsModuleBlock,
+      NodeKind::Class,
+      NodeKind::TsTypeLit,
+      NodeKind::TsInterfaceBody,
+    ])
+  }
+
   fn script(&mut self, script: &ast_view::Script, ctx: &mut Context) {
     check(script.body, ctx);
   }
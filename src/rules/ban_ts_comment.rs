@@ -78,6 +78,10 @@ impl LintRule for BanTsComment {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `@ts-<directive>` comments without an explanation.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
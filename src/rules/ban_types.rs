@@ -1,9 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::TsEntityName;
 use deno_ast::{view as ast_view, SourceRanged};
 use if_chain::if_chain;
@@ -13,6 +14,7 @@ use std::convert::TryFrom;
 pub struct BanTypes;
 
 const CODE: &str = "ban-types";
+const FIX_DESC: &str = "Replace with the lower-case primitive type";
 
 #[derive(Clone, Copy)]
 enum BannedType {
@@ -56,6 +58,21 @@ impl BannedType {
   }
 }
 
+impl BannedType {
+  /// The type to use instead, for wrappers of primitive types.
+  fn primitive(&self) -> Option<&'static str> {
+    use BannedType::*;
+    match *self {
+      String => Some("string"),
+      Boolean => Some("boolean"),
+      Number => Some("number"),
+      Symbol => Some("symbol"),
+      BigInt => Some("bigint"),
+      Function | CapitalObject | EmptyObjectLiteral => None,
+    }
+  }
+}
+
 impl TryFrom<&str> for BannedType {
   type Error = ();
 
@@ -83,18 +100,22 @@ impl LintRule for BanTypes {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    BanTypesHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Bans types like `String` and `Object` that are usually mistakes.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(BanTypesHandler))
   }
 }
 
 struct BanTypesHandler;
 
 impl Handler for BanTypesHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsTypeRef, NodeKind::TsTypeLit])
+  }
+
   fn ts_type_ref(
     &mut self,
     ts_type_ref: &ast_view::TsTypeRef,
@@ -106,11 +127,25 @@ impl Handler for BanTypesHandler {
       if ctx.scope().is_global(&ident.to_id());
       if let Ok(banned_type) = BannedType::try_from(ident.sym().as_ref());
       then {
-        ctx.add_diagnostic_with_hint(
+        let fixes = banned_type
+          .primitive()
+          .filter(|_| ts_type_ref.type_params.is_none())
+          .map(|primitive| LintFix {
+            description: FIX_DESC.into(),
+            kind: LintFixKind::Safe,
+            changes: vec![LintFixChange {
+              new_text: primitive.into(),
+              range: ident.range(),
+            }],
+          })
+          .into_iter()
+          .collect();
+        ctx.add_diagnostic_with_fixes(
           ts_type_ref.range(),
           CODE,
           banned_type.as_message(),
-          banned_type.as_hint(),
+          Some(banned_type.as_hint().to_string()),
+          fixes,
         );
       }
     }
@@ -168,6 +203,7 @@ mod tests {
           col: 7,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "let a: string;", LintFixKind::Safe),
         }
       ],
       "let a: Object;": [
@@ -182,6 +218,7 @@ mod tests {
           col: 7,
           message: message("Number"),
           hint: hint("Number"),
+          fix: (FIX_DESC, "let a: number;"),
         }
       ],
       "let a: Symbol;": [
@@ -189,6 +226,7 @@ mod tests {
           col: 7,
           message: message("Symbol"),
           hint: hint("Symbol"),
+          fix: (FIX_DESC, "let a: symbol;"),
         }
       ],
       "let a: BigInt;": [
@@ -196,6 +234,7 @@ mod tests {
           col: 7,
           message: message("BigInt"),
           hint: hint("BigInt"),
+          fix: (FIX_DESC, "let a: bigint;"),
         }
       ],
       "let a: Function;": [
@@ -217,6 +256,7 @@ mod tests {
           col: 12,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "let a: { b: string };"),
         }
       ],
       "let a: { b: Number };": [
@@ -224,6 +264,7 @@ mod tests {
           col: 12,
           message: message("Number"),
           hint: hint("Number"),
+          fix: (FIX_DESC, "let a: { b: number };"),
         }
       ],
       "let a: { b: object, c: Object };": [
@@ -245,6 +286,7 @@ mod tests {
           col: 13,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "let a: Array<string>"),
         }
       ],
       "let a: Number<Function>": [
@@ -264,6 +306,7 @@ mod tests {
           col: 16,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "function foo(a: string) {}"),
         }
       ],
       "function foo(): Number {}": [
@@ -271,6 +314,7 @@ mod tests {
           col: 16,
           message: message("Number"),
           hint: hint("Number"),
+          fix: (FIX_DESC, "function foo(): number {}"),
         }
       ],
       "let a: () => Number;": [
@@ -278,6 +322,7 @@ mod tests {
           col: 13,
           message: message("Number"),
           hint: hint("Number"),
+          fix: (FIX_DESC, "let a: () => number;"),
         }
       ],
       "'a' as String;": [
@@ -285,6 +330,7 @@ mod tests {
           col: 7,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "'a' as string;"),
         }
       ],
       "1 as Number;": [
@@ -292,6 +338,7 @@ mod tests {
           col: 5,
           message: message("Number"),
           hint: hint("Number"),
+          fix: (FIX_DESC, "1 as number;"),
         }
       ],
       "
@@ -307,12 +354,28 @@ class Foo<F = String> extends Bar<String> implements Baz<Object> {
           col: 14,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "
+class Foo<F = string> extends Bar<String> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<String> {
+    const foo: String = 1 as String;
+  }
+}"),
         },
         {
           line: 2,
           col: 34,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "
+class Foo<F = String> extends Bar<string> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<String> {
+    const foo: String = 1 as String;
+  }
+}"),
         },
         {
           line: 2,
@@ -325,6 +388,14 @@ class Foo<F = String> extends Bar<String> implements Baz<Object> {
           col: 19,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "
+class Foo<F = String> extends Bar<String> implements Baz<Object> {
+  constructor(foo: string | Object) {}
+
+  exit(): Array<String> {
+    const foo: String = 1 as String;
+  }
+}"),
         },
         {
           line: 3,
@@ -337,18 +408,42 @@ class Foo<F = String> extends Bar<String> implements Baz<Object> {
           col: 16,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "
+class Foo<F = String> extends Bar<String> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<string> {
+    const foo: String = 1 as String;
+  }
+}"),
         },
         {
           line: 6,
           col: 15,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "
+class Foo<F = String> extends Bar<String> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<String> {
+    const foo: string = 1 as String;
+  }
+}"),
         },
         {
           line: 6,
           col: 29,
           message: message("String"),
           hint: hint("String"),
+          fix: (FIX_DESC, "
+class Foo<F = String> extends Bar<String> implements Baz<Object> {
+  constructor(foo: String | Object) {}
+
+  exit(): Array<String> {
+    const foo: String = 1 as string;
+  }
+}"),
         }
       ]
     };
@@ -22,6 +22,10 @@ impl LintRule for BanUnknownRuleCode {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Warns about unknown rule codes in ignore directives.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     _context: &mut Context,
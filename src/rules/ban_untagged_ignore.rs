@@ -21,6 +21,10 @@ impl LintRule for BanUntaggedIgnore {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires ignore directives to name the rules they ignore.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -20,6 +20,10 @@ impl LintRule for BanUntaggedTodo {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires TODO comments to be tagged with a user or an issue.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
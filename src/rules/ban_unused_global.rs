@@ -0,0 +1,125 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::program_ref;
+use super::{Context, LintRule};
+use crate::Program;
+use crate::ProgramRef;
+use deno_ast::swc::ast::Ident;
+use deno_ast::swc::common::SyntaxContext;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct BanUnusedGlobal;
+
+const CODE: &str = "ban-unused-global";
+const HINT: &str = "Remove the declaration from the `global` comment";
+
+impl LintRule for BanUnusedGlobal {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn description(&self) -> Option<&'static str> {
+    Some(
+      "Warns about globals declared by `global` comments that are never used.",
+    )
+  }
+
+  fn lint_program_with_ast_view<'view>(
+    &self,
+    context: &mut Context<'view>,
+    program: Program<'view>,
+  ) {
+    if context.global_directives().is_empty() {
+      return;
+    }
+
+    let mut collector = UnresolvedIdentCollector {
+      unresolved_ctxt: context.unresolved_ctxt(),
+      names: HashSet::new(),
+    };
+    match program_ref(program) {
+      ProgramRef::Module(m) => m.visit_with(&mut collector),
+      ProgramRef::Script(s) => s.visit_with(&mut collector),
+    }
+
+    let unused = context
+      .global_directives()
+      .iter()
+      .filter(|directive| directive.access.is_some())
+      .filter(|directive| !collector.names.contains(directive.name.as_str()))
+      .map(|directive| (directive.range, directive.name.clone()))
+      .collect::<Vec<_>>();
+    for (range, name) in unused {
+      context.add_diagnostic_with_hint(
+        range,
+        CODE,
+        format!("Global \"{}\" is declared but never used.", name),
+        HINT,
+      );
+    }
+  }
+}
+
+/// Collects the names of identifiers referring to globals, including the
+/// ones in types.
+struct UnresolvedIdentCollector {
+  unresolved_ctxt: SyntaxContext,
+  names: HashSet<String>,
+}
+
+impl Visit for UnresolvedIdentCollector {
+  fn visit_ident(&mut self, ident: &Ident) {
+    if ident.ctxt == self.unresolved_ctxt {
+      self.names.insert(ident.sym.to_string());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ban_unused_global_valid() {
+    assert_lint_ok! {
+      BanUnusedGlobal,
+      "/* global foo */ foo();",
+      "/* globals foo, bar: writable */ bar = foo;",
+      "/* global Foo */ let x: Foo;",
+      "let foo = 1;",
+      // Disabling a global doesn't declare it.
+      "/* global Deno: off */",
+    };
+  }
+
+  #[test]
+  fn ban_unused_global_invalid() {
+    assert_lint_err! {
+      BanUnusedGlobal,
+      "/* global foo */": [
+        {
+          col: 10,
+          message: "Global \"foo\" is declared but never used.",
+          hint: HINT,
+        }
+      ],
+      "/* global foo, bar: writable */ foo();": [
+        {
+          col: 15,
+          message: "Global \"bar\" is declared but never used.",
+          hint: HINT,
+        }
+      ],
+      // Declarations shadow the global.
+      "/* global foo */ function f(foo) { return foo; }": [
+        {
+          col: 10,
+          message: "Global \"foo\" is declared but never used.",
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
@@ -20,6 +20,10 @@ impl LintRule for BanUnusedIgnore {
     "ban-unused-ignore"
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Warns about ignore directives that don't ignore anything.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     _context: &mut Context,
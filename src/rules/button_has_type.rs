@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   Expr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElementName, JSXExpr,
   Lit, Tpl,
@@ -24,12 +24,12 @@ impl LintRule for ButtonHasType {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    HasButtonTypeHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `<button>` elements to have a `type` attribute.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(HasButtonTypeHandler))
   }
 }
 
@@ -72,6 +72,10 @@ impl DiagnosticKind {
 struct HasButtonTypeHandler;
 
 impl Handler for HasButtonTypeHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXOpeningElement])
+  }
+
   fn jsx_opening_element(
     &mut self,
     node: &ast_view::JSXOpeningElement,
@@ -25,6 +25,10 @@ impl LintRule for Camelcase {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Enforces camelCase names for variables, functions and properties.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
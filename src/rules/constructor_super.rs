@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRange, SourceRanged};
 use if_chain::if_chain;
 
@@ -23,12 +23,14 @@ impl LintRule for ConstructorSuper {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    ConstructorSuperHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some(
+      "Requires `super()` in the constructors of derived classes, and only there.",
+    )
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(ConstructorSuperHandler))
   }
 }
 
@@ -184,6 +186,10 @@ fn check_constructor(
 struct ConstructorSuperHandler;
 
 impl Handler for ConstructorSuperHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Class])
+  }
+
   fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
     for member in class.body {
       if let ast_view::ClassMember::Constructor(cons) = member {
@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
 
@@ -30,18 +30,28 @@ impl LintRule for DefaultParamLast {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    DefaultParamLastHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some(
+      "Requires parameters with defaults to come after the other parameters.",
+    )
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(DefaultParamLastHandler))
   }
 }
 
 struct DefaultParamLastHandler;
 
 impl Handler for DefaultParamLastHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::Function,
+      NodeKind::Constructor,
+      NodeKind::ArrowExpr,
+    ])
+  }
+
   fn function(&mut self, function: &ast_view::Function, ctx: &mut Context) {
     check_params(function.params.iter().rev().copied().map(|p| p.pat), ctx);
   }
@@ -1,16 +1,30 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use super::{Context, LintRule, LintRuleOptionsError};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use deno_ast::swc::ast::BinaryOp;
-use deno_ast::{view as ast_view, SourceRanged};
+use deno_ast::view::NodeKind;
+use deno_ast::{view as ast_view, SourceRanged, SourceRangedForSpanned};
 use derive_more::Display;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct Eqeqeq;
+#[derive(Debug, Default)]
+pub struct Eqeqeq {
+  options: EqeqeqOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+struct EqeqeqOptions {
+  /// Allow comparing against `null` with `==` and `!=`, which is a common
+  /// idiom to check for both `null` and `undefined`.
+  allow_null: bool,
+}
 
 const CODE: &str = "eqeqeq";
+const FIX_DESC_EQUAL: &str = "Replace '==' with '==='";
+const FIX_DESC_NOT_EQUAL: &str = "Replace '!=' with '!=='";
 
 #[derive(Display)]
 enum EqeqeqMessage {
@@ -33,30 +47,122 @@ impl LintRule for Eqeqeq {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    EqeqeqHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `===` and `!==` instead of `==` and `!=`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(EqeqeqHandler {
+      options: self.options.clone(),
+    }))
+  }
+
+  fn configure(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), LintRuleOptionsError> {
+    self.options = super::deserialize_options(CODE, options)?;
+    Ok(())
   }
 }
 
-struct EqeqeqHandler;
+struct EqeqeqHandler {
+  options: EqeqeqOptions,
+}
 
 impl Handler for EqeqeqHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
+  }
+
   fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, context: &mut Context) {
     if matches!(bin_expr.op(), BinaryOp::EqEq | BinaryOp::NotEq) {
-      let (message, hint) = if bin_expr.op() == BinaryOp::EqEq {
-        (EqeqeqMessage::ExpectedEqual, EqeqeqHint::UseEqeqeq)
-      } else {
-        (EqeqeqMessage::ExpectedNotEqual, EqeqeqHint::UseNoteqeq)
-      };
-      context.add_diagnostic_with_hint(bin_expr.range(), CODE, message, hint)
+      if self.options.allow_null
+        && (matches!(
+          bin_expr.left,
+          ast_view::Expr::Lit(ast_view::Lit::Null(_))
+        ) || matches!(
+          bin_expr.right,
+          ast_view::Expr::Lit(ast_view::Lit::Null(_))
+        ))
+      {
+        return;
+      }
+
+      let (message, hint, description, operator) =
+        if bin_expr.op() == BinaryOp::EqEq {
+          (
+            EqeqeqMessage::ExpectedEqual,
+            EqeqeqHint::UseEqeqeq,
+            FIX_DESC_EQUAL,
+            "===",
+          )
+        } else {
+          (
+            EqeqeqMessage::ExpectedNotEqual,
+            EqeqeqHint::UseNoteqeq,
+            FIX_DESC_NOT_EQUAL,
+            "!==",
+          )
+        };
+      let fixes = bin_expr
+        .left
+        .next_token_fast(context.program())
+        .map(|token| LintFix {
+          description: description.into(),
+          kind: if has_same_type(bin_expr) {
+            LintFixKind::Safe
+          } else {
+            LintFixKind::Unsafe
+          },
+          changes: vec![LintFixChange {
+            new_text: operator.into(),
+            range: token.span.range(),
+          }],
+        })
+        .into_iter()
+        .collect();
+      context.add_diagnostic_with_fixes(
+        bin_expr.range(),
+        CODE,
+        message,
+        Some(hint.to_string()),
+        fixes,
+      );
     }
   }
 }
 
+/// Whether both operands are known to have the same type, so using a strict
+/// comparison doesn't change the result.
+fn has_same_type(bin_expr: &ast_view::BinExpr) -> bool {
+  use ast_view::{Expr, Lit, UnaryOp};
+
+  fn unwrap_parens<'a>(expr: &Expr<'a>) -> Expr<'a> {
+    match expr {
+      Expr::Paren(paren) => unwrap_parens(&paren.expr),
+      expr => *expr,
+    }
+  }
+
+  let is_typeof = |expr: &Expr| matches!(expr, Expr::Unary(unary) if unary.op() == UnaryOp::TypeOf);
+  match (
+    unwrap_parens(&bin_expr.left),
+    unwrap_parens(&bin_expr.right),
+  ) {
+    (Expr::Lit(Lit::Str(_)), expr) | (expr, Expr::Lit(Lit::Str(_)))
+      if is_typeof(&expr) =>
+    {
+      true
+    }
+    (left, right) if is_typeof(&left) && is_typeof(&right) => true,
+    (Expr::Lit(left), Expr::Lit(right)) => {
+      std::mem::discriminant(&left) == std::mem::discriminant(&right)
+    }
+    _ => false,
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -64,7 +170,7 @@ mod tests {
   #[test]
   fn eqeqeq_valid() {
     assert_lint_ok! {
-      Eqeqeq,
+      Eqeqeq::default(),
       "midori === sapphire",
       "midori !== hazuki",
       "kumiko === null",
@@ -74,34 +180,86 @@ mod tests {
     };
   }
 
+  fn eqeqeq_allow_null() -> Eqeqeq {
+    let mut rule = Eqeqeq::default();
+    rule
+      .configure(&serde_json::json!({ "allowNull": true }))
+      .unwrap();
+    rule
+  }
+
+  #[test]
+  fn eqeqeq_allow_null_valid() {
+    assert_lint_ok! {
+      eqeqeq_allow_null(),
+      "a == null",
+      "null != a",
+      "a.b == null",
+      "midori === sapphire",
+    };
+  }
+
+  #[test]
+  fn eqeqeq_allow_null_invalid() {
+    assert_lint_err! {
+      eqeqeq_allow_null(),
+      "a == b": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedEqual,
+        hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "a === b"),
+      }],
+      "a != undefined": [
+      {
+        col: 0,
+        message: EqeqeqMessage::ExpectedNotEqual,
+        hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "a !== undefined"),
+      }],
+    };
+  }
+
+  #[test]
+  fn eqeqeq_invalid_options() {
+    let mut rule = Eqeqeq::default();
+    assert!(rule
+      .configure(&serde_json::json!({ "allowNil": true }))
+      .is_err());
+  }
+
   #[test]
   fn eqeqeq_invalid() {
     assert_lint_err! {
-      Eqeqeq,
+      Eqeqeq::default(),
 
       "a == b": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "a === b", LintFixKind::Unsafe),
       }],
       "a != b": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "a !== b"),
       }],
       "typeof a == 'number'": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "typeof a === 'number'", LintFixKind::Safe),
       }],
       "'string' != typeof a": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "'string' !== typeof a"),
       }],
       "true == true": [
       {
@@ -109,54 +267,63 @@ mod tests {
 
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "true === true", LintFixKind::Safe),
       }],
       "2 == 3": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "2 === 3"),
       }],
       "'hello' != 'world'": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "'hello' !== 'world'"),
       }],
       "a == null": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "a === null"),
       }],
       "null != a": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "null !== a"),
       }],
       "true == null": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "true === null"),
       }],
       "true != null": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "true !== null"),
       }],
       "null == null": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "null === null"),
       }],
       "null != null": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "null !== null"),
       }],
       r#"
 a
@@ -167,42 +334,52 @@ b     "#: [
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, r#"
+a
+===
+b     "#),
       }],
       "(a) == b": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "(a) === b"),
       }],
       "(a) != b": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "(a) !== b"),
       }],
       "a == (b)": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "a === (b)"),
       }],
       "a != (b)": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "a !== (b)"),
       }],
       "(a) == (b)": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "(a) === (b)"),
       }],
       "(a) != (b)": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "(a) !== (b)"),
       }],
       "(a == b) == (c)": [
       {
@@ -210,12 +387,14 @@ b     "#: [
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "(a == b) === (c)"),
       },
       {
         line: 1,
         col: 1,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "(a === b) == (c)"),
       }],
 
       "(a != b) != (c)": [
@@ -224,66 +403,77 @@ b     "#: [
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "(a != b) !== (c)"),
       },
       {
         line: 1,
         col: 1,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "(a !== b) != (c)"),
       }],
       "(a == b) === (c)": [
       {
         col: 1,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "(a === b) === (c)"),
       }],
       "(a == b) !== (c)": [
       {
         col: 1,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "(a === b) !== (c)"),
       }],
       "(a === b) == (c)": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "(a === b) === (c)"),
       }],
       "(a === b) != (c)": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "(a === b) !== (c)"),
       }],
       "a == b;": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "a === b;"),
       }],
       "a!=b;": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "a!==b;"),
       }],
       "(a + b) == c;": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "(a + b) === c;"),
       }],
       "(a + b)  !=  c;": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedNotEqual,
         hint: EqeqeqHint::UseNoteqeq,
+        fix: (FIX_DESC_NOT_EQUAL, "(a + b)  !==  c;"),
       }],
       "((1) )  ==  (2);": [
       {
         col: 0,
         message: EqeqeqMessage::ExpectedEqual,
         hint: EqeqeqHint::UseEqeqeq,
+        fix: (FIX_DESC_EQUAL, "((1) )  ===  (2);"),
       }],
     }
   }
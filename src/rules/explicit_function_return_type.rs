@@ -28,6 +28,10 @@ impl LintRule for ExplicitFunctionReturnType {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires functions to declare their return type.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -34,6 +34,12 @@ impl LintRule for ExplicitModuleBoundaryTypes {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some(
+      "Requires exported functions to declare their parameter and return types.",
+    )
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
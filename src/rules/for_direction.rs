@@ -1,12 +1,12 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::swc::ast::AssignOp;
 use deno_ast::swc::ast::BinaryOp;
 use deno_ast::swc::ast::UpdateOp;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   AssignExpr, AssignTarget, Expr, SimpleAssignTarget, UnaryOp, UpdateExpr,
 };
@@ -24,12 +24,14 @@ impl LintRule for ForDirection {
     "for-direction"
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    ForDirectionHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some(
+      "Disallows `for` loops whose counter moves away from the end condition.",
+    )
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(ForDirectionHandler))
   }
 }
 
@@ -102,6 +104,10 @@ fn check_assign_right_direction(
 }
 
 impl Handler for ForDirectionHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ForStmt])
+  }
+
   fn for_stmt(&mut self, for_stmt: &ast_view::ForStmt, context: &mut Context) {
     if for_stmt.update.is_none() {
       return;
@@ -1,10 +1,12 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::file_scope::FileScope;
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
 
-use deno_ast::view::{Decl, Pat, Program};
+use deno_ast::view::NodeKind;
+use deno_ast::view::{Decl, Pat};
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -24,32 +26,33 @@ impl LintRule for FreshHandlerExport {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    Visitor.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires Fresh routes to export `handler` instead of `handlers`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(Visitor))
+  }
+
+  // Fresh only considers components in the routes/ folder to be
+  // server components.
+  fn file_scope(&self) -> Option<FileScope> {
+    FileScope::new(vec!["routes/**".to_string()], vec![]).ok()
   }
 }
 
 struct Visitor;
 
 impl Handler for Visitor {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ExportDecl])
+  }
+
   fn export_decl(
     &mut self,
     export_decl: &deno_ast::view::ExportDecl,
     ctx: &mut Context,
   ) {
-    // Fresh only considers components in the routes/ folder to be
-    // server components.
-    let Some(mut path_segments) = ctx.specifier().path_segments() else {
-      return;
-    };
-    if !path_segments.any(|part| part == "routes") {
-      return;
-    }
-
     let id = match export_decl.decl {
       Decl::Var(var_decl) => {
         if let Some(first) = var_decl.decls.first() {
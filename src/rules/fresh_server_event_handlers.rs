@@ -1,11 +1,13 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::file_scope::FileScope;
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
 
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
-  Expr, JSXAttrName, JSXAttrValue, JSXElementName, JSXExpr, Program,
+  Expr, JSXAttrName, JSXAttrValue, JSXElementName, JSXExpr,
 };
 use deno_ast::SourceRanged;
 
@@ -27,36 +29,38 @@ impl LintRule for FreshServerEventHandlers {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    Visitor.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows event handlers in server-rendered Fresh components.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(Visitor))
+  }
+
+  // Fresh only considers components in the routes/ folder to be
+  // server components. Files inside an `(_islands)` folder are considered
+  // islands though, even if they are inside the `routes` folder.
+  fn file_scope(&self) -> Option<FileScope> {
+    FileScope::new(
+      vec!["routes/**".to_string()],
+      vec!["(_islands)/**".to_string()],
+    )
+    .ok()
   }
 }
 
 struct Visitor;
 
 impl Handler for Visitor {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXAttr])
+  }
+
   fn jsx_attr(
     &mut self,
     jsx_attr: &deno_ast::view::JSXAttr,
     ctx: &mut Context,
   ) {
-    // Fresh only considers components in the routes/ folder to be
-    // server components. Files inside an `(_islands)` folder are considered
-    // islands though, even if they are inside the `routes` folder.
-    let Some(path_segments) = ctx.specifier().path_segments() else {
-      return;
-    };
-    let segments = path_segments.collect::<Vec<_>>();
-    if !segments.iter().any(|comp| *comp == "routes")
-      || segments.iter().any(|comp| *comp == "(_islands)")
-    {
-      return;
-    }
-
     // We only care about native HTML elements not namespaced XML
     // like `<foo:bar />` or member expressions like `<foo.bar />`
     let parent_name = match jsx_attr.parent().name {
@@ -48,6 +48,10 @@ impl LintRule for GetterReturn {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires getters to return a value.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
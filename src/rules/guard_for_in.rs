@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -17,18 +17,22 @@ impl LintRule for GuardForIn {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    GuardForInHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `for-in` loops to filter out inherited properties.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(GuardForInHandler))
   }
 }
 
 struct GuardForInHandler;
 
 impl Handler for GuardForInHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ForInStmt])
+  }
+
   fn for_in_stmt(
     &mut self,
     for_in_stmt: &deno_ast::view::ForInStmt,
@@ -1,11 +1,13 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
+use crate::tags;
 use crate::tags::Tags;
-use crate::{tags, Program};
+use deno_ast::view::NodeKind;
 use deno_ast::view::{Expr, JSXAttr, JSXAttrValue, JSXExpr, Lit};
-use deno_ast::SourceRanged;
+use deno_ast::{SourceRange, SourceRanged};
 
 #[derive(Debug)]
 pub struct JSXBooleanValue;
@@ -21,28 +23,46 @@ impl LintRule for JSXBooleanValue {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXBooleanValueHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `={true}` on boolean JSX attributes.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXBooleanValueHandler))
   }
 }
 
 const MESSAGE: &str =
   "Passing 'true' to boolean attributes is the same as not passing it`";
 const HINT: &str = "Remove the attribute value";
+const FIX_DESC: &str = HINT;
 
 struct JSXBooleanValueHandler;
 
 impl Handler for JSXBooleanValueHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXAttr])
+  }
+
   fn jsx_attr(&mut self, node: &JSXAttr, ctx: &mut Context) {
     if let Some(value) = node.value {
       if let JSXAttrValue::JSXExprContainer(expr) = value {
         if let JSXExpr::Expr(Expr::Lit(Lit::Bool(lit_bool))) = expr.expr {
           if lit_bool.value() {
-            ctx.add_diagnostic_with_hint(value.range(), CODE, MESSAGE, HINT);
+            ctx.add_diagnostic_with_fixes(
+              value.range(),
+              CODE,
+              MESSAGE,
+              Some(HINT.to_string()),
+              vec![LintFix {
+                description: FIX_DESC.into(),
+                kind: LintFixKind::Safe,
+                changes: vec![LintFixChange {
+                  new_text: "".into(),
+                  range: SourceRange::new(node.name.end(), value.end()),
+                }],
+              }],
+            );
           }
         }
       }
@@ -77,6 +97,15 @@ mod tests {
           col: 9,
           message: MESSAGE,
           hint: HINT,
+          fix: (FIX_DESC, "<Foo foo />", LintFixKind::Safe),
+        }
+      ],
+      "<Foo foo = {true} bar />": [
+        {
+          col: 11,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "<Foo foo bar />"),
         }
       ],
     };
@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::diagnostic::{LintFix, LintFixChange};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   Expr, JSXAttr, JSXAttrValue, JSXElement, JSXElementChild, JSXExpr, Lit,
   NodeTrait,
@@ -25,12 +25,12 @@ impl LintRule for JSXCurlyBraces {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXCurlyBracesHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows curly braces around string literals in JSX.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXCurlyBracesHandler))
   }
 }
 
@@ -69,6 +69,10 @@ impl DiagnosticKind {
 struct JSXCurlyBracesHandler;
 
 impl Handler for JSXCurlyBracesHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXElement, NodeKind::JSXAttr])
+  }
+
   fn jsx_element(&mut self, node: &JSXElement, ctx: &mut Context) {
     for child in node.children {
       if let JSXElementChild::JSXExprContainer(child_expr) = child {
@@ -80,6 +84,7 @@ impl Handler for JSXCurlyBracesHandler {
             Some(DiagnosticKind::CurlyChild.hint().to_string()),
             vec![LintFix {
               description: "Remove curly braces around JSX child".into(),
+              kind: LintFixKind::Unsafe,
               changes: vec![LintFixChange {
                 new_text: lit_str.value().to_string().into(),
                 range: child.range(),
@@ -104,6 +109,7 @@ impl Handler for JSXCurlyBracesHandler {
               vec![LintFix {
                 description: "Remove curly braces around JSX attribute value"
                   .into(),
+                kind: LintFixKind::Unsafe,
                 changes: vec![LintFixChange {
                   new_text: format!("\"{}\"", lit_str.value()).into(),
                   range: value.range(),
@@ -120,6 +126,7 @@ impl Handler for JSXCurlyBracesHandler {
             Some(DiagnosticKind::MissingCurlyAttribute.hint().to_string()),
             vec![LintFix {
               description: "Add curly braces around JSX attribute value".into(),
+              kind: LintFixKind::Safe,
               changes: vec![LintFixChange {
                 new_text: format!("{{{}}}", jsx_el.text()).into(),
                 range: value.range(),
@@ -160,7 +167,8 @@ mod tests {
           hint: DiagnosticKind::CurlyAttribute.hint(),
           fix: (
             "Remove curly braces around JSX attribute value",
-            "<div foo=\"foo\" />"
+            "<div foo=\"foo\" />",
+            LintFixKind::Unsafe
           )
         }
       ],
@@ -171,7 +179,8 @@ mod tests {
           hint: DiagnosticKind::MissingCurlyAttribute.hint(),
           fix: (
             "Add curly braces around JSX attribute value",
-            "<div foo={<div />} />"
+            "<div foo={<div />} />",
+            LintFixKind::Safe
           )
         }
       ],
@@ -182,7 +191,8 @@ mod tests {
           hint: DiagnosticKind::CurlyChild.hint(),
           fix: (
             "Remove curly braces around JSX child",
-            "<div>foo</div>"
+            "<div>foo</div>",
+            LintFixKind::Unsafe
           )
         }
       ],
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   ArrayLit, BlockStmtOrExpr, CallExpr, Callee, Expr, JSXAttrName,
   JSXAttrOrSpread, MemberProp, OptCall, OptChainBase, Stmt,
@@ -24,12 +24,12 @@ impl LintRule for JSXKey {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXKeyHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires a `key` prop on JSX elements created in iterators.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXKeyHandler))
   }
 }
 
@@ -59,6 +59,10 @@ impl DiagnosticKind {
 struct JSXKeyHandler;
 
 impl Handler for JSXKeyHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ArrayLit, NodeKind::OptCall, NodeKind::CallExpr])
+  }
+
   fn array_lit(&mut self, node: &ArrayLit, ctx: &mut Context) {
     for elem in node.elems.iter().flatten() {
       check_expr(ctx, &elem.expr);
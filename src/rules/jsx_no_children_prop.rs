@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{JSXAttrName, JSXAttrOrSpread, JSXOpeningElement};
 use deno_ast::SourceRanged;
 
@@ -21,12 +21,12 @@ impl LintRule for JSXNoChildrenProp {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXNoChildrenPropHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows passing children as a prop.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXNoChildrenPropHandler))
   }
 }
 
@@ -35,6 +35,10 @@ const MESSAGE: &str = "Avoid passing children as a prop";
 struct JSXNoChildrenPropHandler;
 
 impl Handler for JSXNoChildrenPropHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXOpeningElement])
+  }
+
   fn jsx_opening_element(
     &mut self,
     node: &JSXOpeningElement,
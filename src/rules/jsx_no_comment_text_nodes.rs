@@ -1,10 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
+use crate::tags;
 use crate::tags::Tags;
-use crate::{tags, Program};
 use deno_ast::view::JSXText;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -21,12 +22,12 @@ impl LintRule for JSXNoCommentTextNodes {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXNoCommentTextNodesHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows JavaScript comments that are rendered as JSX text.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXNoCommentTextNodesHandler))
   }
 }
 
@@ -36,6 +37,10 @@ const MESSAGE: &str =
 struct JSXNoCommentTextNodesHandler;
 
 impl Handler for JSXNoCommentTextNodesHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXText])
+  }
+
   fn jsx_text(&mut self, node: &JSXText, ctx: &mut Context) {
     let value = &node.inner.value;
     if value.starts_with("//") || value.starts_with("/*") {
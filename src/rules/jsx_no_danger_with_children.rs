@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{JSXAttrName, JSXAttrOrSpread, JSXElement};
 use deno_ast::SourceRanged;
 
@@ -21,12 +21,12 @@ impl LintRule for JSXNoDangerWithChildren {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXNoDangerWithChildrenHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `dangerouslySetInnerHTML` along with children.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXNoDangerWithChildrenHandler))
   }
 }
 
@@ -37,6 +37,10 @@ const HINT: &str = "Remove the JSX children";
 struct JSXNoDangerWithChildrenHandler;
 
 impl Handler for JSXNoDangerWithChildrenHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXElement])
+  }
+
   fn jsx_element(&mut self, node: &JSXElement, ctx: &mut Context) {
     for attr in node.opening.attrs {
       if let JSXAttrOrSpread::JSXAttr(attr) = attr {
@@ -3,9 +3,9 @@
 use std::collections::HashSet;
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{JSXAttrName, JSXAttrOrSpread, JSXOpeningElement};
 use deno_ast::SourceRanged;
 
@@ -23,12 +23,12 @@ impl LintRule for JSXNoDuplicateProps {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXNoDuplicatedPropsHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows setting a JSX prop twice.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXNoDuplicatedPropsHandler))
   }
 }
 
@@ -38,6 +38,10 @@ const HINT: &str = "Remove the duplicated attribute.";
 struct JSXNoDuplicatedPropsHandler;
 
 impl Handler for JSXNoDuplicatedPropsHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXOpeningElement])
+  }
+
   fn jsx_opening_element(
     &mut self,
     node: &JSXOpeningElement,
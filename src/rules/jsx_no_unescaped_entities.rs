@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::diagnostic::{LintFix, LintFixChange};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{JSXElement, JSXElementChild};
 use deno_ast::SourceRanged;
 
@@ -22,12 +22,12 @@ impl LintRule for JSXNoUnescapedEntities {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXNoUnescapedEntitiesHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows unescaped entities like `>` in JSX text.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXNoUnescapedEntitiesHandler))
   }
 }
 
@@ -37,6 +37,10 @@ const HINT: &str = "Escape the '\">} characters respectively";
 struct JSXNoUnescapedEntitiesHandler;
 
 impl Handler for JSXNoUnescapedEntitiesHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXElement])
+  }
+
   fn jsx_element(&mut self, node: &JSXElement, ctx: &mut Context) {
     for child in node.children {
       if let JSXElementChild::JSXText(jsx_text) = child {
@@ -55,6 +59,7 @@ impl Handler for JSXNoUnescapedEntitiesHandler {
             Some(HINT.to_string()),
             vec![LintFix {
               description: "Escape entities in the text node".into(),
+              kind: LintFixKind::Safe,
               changes: vec![LintFixChange {
                 new_text: new_text.into(),
                 range: child.range(),
@@ -93,7 +98,8 @@ mod tests {
           hint: HINT,
           fix: (
             "Escape entities in the text node",
-            "<div>&apos;&quot;&gt;&#125;</div>"
+            "<div>&apos;&quot;&gt;&#125;</div>",
+            LintFixKind::Safe
           )
         }
       ]
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{JSXElement, JSXElementChild, JSXFragment};
 use deno_ast::SourceRanged;
 
@@ -21,12 +21,12 @@ impl LintRule for JSXNoUselessFragment {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXNoUselessFragmentHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows fragments that aren't needed.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXNoUselessFragmentHandler))
   }
 }
 
@@ -36,6 +36,10 @@ const HINT: &str = "Remove this Fragment";
 struct JSXNoUselessFragmentHandler;
 
 impl Handler for JSXNoUselessFragmentHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXFragment, NodeKind::JSXElement])
+  }
+
   // Check root fragments
   fn jsx_fragment(&mut self, node: &JSXFragment, ctx: &mut Context) {
     if node.children.is_empty() {
@@ -3,10 +3,10 @@
 use std::collections::HashSet;
 
 use super::{Context, LintRule};
-use crate::diagnostic::{LintFix, LintFixChange};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{JSXAttrOrSpread, JSXOpeningElement, NodeTrait};
 use deno_ast::{SourceRange, SourceRanged};
 
@@ -24,12 +24,12 @@ impl LintRule for JSXPropsNoSpreadMulti {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXPropsNoSpreadMultiHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows spreading the same expression twice in JSX props.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXPropsNoSpreadMultiHandler))
   }
 }
 
@@ -39,6 +39,10 @@ const HINT: &str = "Remove this spread attribute";
 struct JSXPropsNoSpreadMultiHandler;
 
 impl Handler for JSXPropsNoSpreadMultiHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXOpeningElement])
+  }
+
   fn jsx_opening_element(
     &mut self,
     node: &JSXOpeningElement,
@@ -56,6 +60,9 @@ impl Handler for JSXPropsNoSpreadMultiHandler {
             Some(HINT.to_string()),
             vec![LintFix {
               description: "Remove this spread attribute".into(),
+              // The attributes between the spreads might have been
+              // overridden by the removed one.
+              kind: LintFixKind::Unsafe,
               changes: vec![LintFixChange {
                 new_text: "".into(),
                 range: SourceRange {
@@ -104,7 +111,8 @@ mod tests {
           hint: HINT,
           fix: (
             "Remove this spread attribute",
-            "<div {...foo} />"
+            "<div {...foo} />",
+            LintFixKind::Unsafe
           )
         }
       ],
@@ -115,7 +123,8 @@ mod tests {
           hint: HINT,
           fix: (
             "Remove this spread attribute",
-            "<Foo {...foo} />"
+            "<Foo {...foo} />",
+            LintFixKind::Unsafe
           )
         }
       ],
@@ -126,7 +135,8 @@ mod tests {
           hint: HINT,
           fix: (
             "Remove this spread attribute",
-            "<div {...foo.bar.baz} a />"
+            "<div {...foo.bar.baz} a />",
+            LintFixKind::Unsafe
           )
         }
       ],
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{JSXElement, JSXElementName};
 use deno_ast::SourceRanged;
 
@@ -21,12 +21,12 @@ impl LintRule for JSXVoidDomElementsNoChildren {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    JSXVoidDomElementsNoChildrenHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows children on void elements like `<img>`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(JSXVoidDomElementsNoChildrenHandler))
   }
 }
 
@@ -36,6 +36,10 @@ const HINT: &str = "Escape the '\">} characters respectively";
 struct JSXVoidDomElementsNoChildrenHandler;
 
 impl Handler for JSXVoidDomElementsNoChildrenHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXElement])
+  }
+
   fn jsx_element(&mut self, node: &JSXElement, ctx: &mut Context) {
     if let JSXElementName::Ident(name) = node.opening.name {
       if !node.children.is_empty()
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{CallExpr, Callee, Expr, ExprOrSpread, NewExpr};
 use deno_ast::{SourceRange, SourceRanged};
 
@@ -23,12 +23,12 @@ impl LintRule for NoArrayConstructor {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoArrayConstructorHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires array literals instead of the `Array` constructor.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoArrayConstructorHandler))
   }
 }
 
@@ -45,6 +45,10 @@ fn check_args(
 struct NoArrayConstructorHandler;
 
 impl Handler for NoArrayConstructorHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
   fn new_expr(&mut self, new_expr: &NewExpr, context: &mut Context) {
     if let Expr::Ident(ident) = &new_expr.callee {
       let name = ident.inner.as_ref();
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{Expr, NewExpr, ParenExpr};
 use deno_ast::SourceRanged;
 
@@ -24,12 +24,12 @@ impl LintRule for NoAsyncPromiseExecutor {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoAsyncPromiseExecutorHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows async functions as `Promise` executors.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoAsyncPromiseExecutorHandler))
   }
 }
 
@@ -45,6 +45,10 @@ fn is_async_function(expr: &Expr) -> bool {
 struct NoAsyncPromiseExecutorHandler;
 
 impl Handler for NoAsyncPromiseExecutorHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::NewExpr])
+  }
+
   fn new_expr(&mut self, new_expr: &NewExpr, context: &mut Context) {
     if let Expr::Ident(ident) = &new_expr.callee {
       let name = ident.inner.as_ref();
@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::view::NodeTrait;
 use deno_ast::{view as ast_view, SourceRanged};
 
@@ -18,18 +18,22 @@ impl LintRule for NoAwaitInLoop {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoAwaitInLoopHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `await` in the body of loops.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoAwaitInLoopHandler))
   }
 }
 
 struct NoAwaitInLoopHandler;
 
 impl Handler for NoAwaitInLoopHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AwaitExpr])
+  }
+
   fn await_expr(
     &mut self,
     await_expr: &ast_view::AwaitExpr,
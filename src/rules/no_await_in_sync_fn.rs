@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::NodeTrait;
 use deno_ast::{view as ast_view, SourceRanged};
 
@@ -23,18 +23,22 @@ impl LintRule for NoAwaitInSyncFn {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoAwaitInSyncFnHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `await` in functions that aren't async.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoAwaitInSyncFnHandler))
   }
 }
 
 struct NoAwaitInSyncFnHandler;
 
 impl Handler for NoAwaitInSyncFnHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AwaitExpr])
+  }
+
   fn await_expr(
     &mut self,
     await_expr: &ast_view::AwaitExpr,
@@ -1,7 +1,7 @@
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::Tags;
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{CallExpr, NodeTrait};
 use deno_ast::SourceRanged;
 
@@ -15,18 +15,18 @@ const HINT: &str =
   "const ARG_ONE = true, ARG_TWO = false;\nyourFunction(ARG_ONE, ARG_TWO)";
 
 impl LintRule for NoBooleanLiteralForArguments {
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoBooleanLiteralForArgumentsVisitor.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoBooleanLiteralForArgumentsVisitor))
   }
 
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows boolean literals as arguments of function calls.")
+  }
+
   fn tags(&self) -> Tags {
     &[]
   }
@@ -35,6 +35,10 @@ impl LintRule for NoBooleanLiteralForArguments {
 struct NoBooleanLiteralForArgumentsVisitor;
 
 impl Handler for NoBooleanLiteralForArgumentsVisitor {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
   fn call_expr(&mut self, call_expression: &CallExpr, ctx: &mut Context) {
     let args = call_expression.args;
     let is_boolean_literal =
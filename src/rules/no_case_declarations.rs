@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{Decl, Stmt, SwitchCase, VarDeclKind};
 use deno_ast::SourceRanged;
 
@@ -23,18 +23,22 @@ impl LintRule for NoCaseDeclarations {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoCaseDeclarationsHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows lexical declarations directly in `case` clauses.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoCaseDeclarationsHandler))
   }
 }
 
 struct NoCaseDeclarationsHandler;
 
 impl Handler for NoCaseDeclarationsHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::SwitchCase])
+  }
+
   fn switch_case(&mut self, switch_case: &SwitchCase, context: &mut Context) {
     for stmt in switch_case.cons {
       let is_lexical_decl = match stmt {
@@ -1,11 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::find_lhs_ids;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::view::AssignExpr;
+use deno_ast::view::NodeKind;
 use deno_ast::{BindingKind, SourceRanged};
 
 #[derive(Debug)]
@@ -24,18 +24,22 @@ impl LintRule for NoClassAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoClassAssignVisitor.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows reassigning class declarations.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoClassAssignVisitor))
   }
 }
 
 struct NoClassAssignVisitor;
 
 impl Handler for NoClassAssignVisitor {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
+  }
+
   fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
     let ids = find_lhs_ids(&assign_expr.left);
     for id in ids {
@@ -1,14 +1,14 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::swc::ast::BinaryOp::*;
 use deno_ast::swc::ast::Expr::Lit;
 use deno_ast::swc::ast::Lit::Num;
 use deno_ast::swc::ast::UnaryExpr;
 use deno_ast::swc::ast::UnaryOp::Minus;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{BinExpr, BinaryOp, Expr};
 use deno_ast::SourceRanged;
 use derive_more::Display;
@@ -39,18 +39,22 @@ impl LintRule for NoCompareNegZero {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoCompareNegZeroHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows comparisons with `-0`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoCompareNegZeroHandler))
   }
 }
 
 struct NoCompareNegZeroHandler;
 
 impl Handler for NoCompareNegZeroHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
+  }
+
   fn bin_expr(&mut self, bin_expr: &BinExpr, context: &mut Context) {
     if !bin_expr.op().is_comparator() {
       return;
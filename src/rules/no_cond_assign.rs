@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{CondExpr, DoWhileStmt, Expr, ForStmt, IfStmt, WhileStmt};
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
@@ -38,12 +38,12 @@ impl LintRule for NoCondAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoCondAssignHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows assignments in conditions.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoCondAssignHandler))
   }
 }
 
@@ -76,6 +76,16 @@ impl NoCondAssignHandler {
 }
 
 impl Handler for NoCondAssignHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::IfStmt,
+      NodeKind::WhileStmt,
+      NodeKind::DoWhileStmt,
+      NodeKind::ForStmt,
+      NodeKind::CondExpr,
+    ])
+  }
+
   fn if_stmt(&mut self, if_stmt: &IfStmt, ctx: &mut Context) {
     self.check_condition(&if_stmt.test, ctx);
   }
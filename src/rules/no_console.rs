@@ -1,14 +1,24 @@
-use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use super::{Context, LintRule, LintRuleOptionsError};
+use crate::handler::Handler;
 use crate::tags::Tags;
-use crate::Program;
 
 use deno_ast::view as ast_view;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoConsole;
+#[derive(Debug, Default)]
+pub struct NoConsole {
+  options: NoConsoleOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct NoConsoleOptions {
+  /// Methods of `console` that are allowed to be used, e.g. `error`.
+  allow: Vec<String>,
+}
 
 const MESSAGE: &str = "`console` usage is not allowed.";
 const CODE: &str = "no-console";
@@ -22,18 +32,47 @@ impl LintRule for NoConsole {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoConsoleHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows the use of `console`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoConsoleHandler {
+      options: self.options.clone(),
+    }))
+  }
+
+  fn configure(
+    &mut self,
+    options: &serde_json::Value,
+  ) -> Result<(), LintRuleOptionsError> {
+    self.options = super::deserialize_options(CODE, options)?;
+    Ok(())
   }
 }
 
-struct NoConsoleHandler;
+/// Name of the accessed method, for `console.error` and `console["error"]`.
+fn method_name<'a>(prop: &'a ast_view::MemberProp) -> Option<&'a str> {
+  use deno_ast::view::{Expr, Lit, MemberProp};
+  match prop {
+    MemberProp::Ident(ident) => Some(ident.sym()),
+    MemberProp::Computed(prop) => match &prop.expr {
+      Expr::Lit(Lit::Str(s)) => Some(s.value()),
+      _ => None,
+    },
+    MemberProp::PrivateName(_) => None,
+  }
+}
+
+struct NoConsoleHandler {
+  options: NoConsoleOptions,
+}
 
 impl Handler for NoConsoleHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr, NodeKind::ExprStmt])
+  }
+
   fn member_expr(&mut self, expr: &ast_view::MemberExpr, ctx: &mut Context) {
     if expr.parent().is::<ast_view::MemberExpr>() {
       return;
@@ -44,6 +83,8 @@ impl Handler for NoConsoleHandler {
       if let Expr::Ident(ident) = &expr.obj;
       if ident.sym() == "console";
       if ctx.scope().is_global(&ident.inner.to_id());
+      if !method_name(&expr.prop)
+        .is_some_and(|name| self.options.allow.iter().any(|a| a == name));
       then {
         ctx.add_diagnostic(
           ident.range(),
@@ -78,7 +119,7 @@ mod tests {
   #[test]
   fn console_allowed() {
     assert_lint_ok!(
-      NoConsole,
+      NoConsole::default(),
       // ignored
       r"// deno-lint-ignore no-console\nconsole.error('Error message');",
       // not global
@@ -88,11 +129,52 @@ mod tests {
     );
   }
 
+  fn no_console_allow_error() -> NoConsole {
+    let mut rule = NoConsole::default();
+    rule
+      .configure(&serde_json::json!({ "allow": ["error"] }))
+      .unwrap();
+    rule
+  }
+
+  #[test]
+  fn no_console_allow_valid() {
+    assert_lint_ok!(
+      no_console_allow_error(),
+      r#"console.error('Error message');"#,
+      r#"function log() { console.error('Log'); }"#,
+      r#"console["error"]('Error message');"#,
+    );
+  }
+
+  #[test]
+  fn no_console_allow_invalid() {
+    assert_lint_err!(
+      no_console_allow_error(),
+      r#"console.log('Debug message');"#: [{
+        col: 0,
+        message: MESSAGE,
+      }],
+      r#"console;"#: [{
+        col: 0,
+        message: MESSAGE,
+      }],
+      r#"console["log"]('Debug message');"#: [{
+        col: 0,
+        message: MESSAGE,
+      }],
+      r#"console[method]('Debug message');"#: [{
+        col: 0,
+        message: MESSAGE,
+      }],
+    );
+  }
+
   #[test]
   fn no_console_invalid() {
     // Test cases where console is present
     assert_lint_err!(
-        NoConsole,
+        NoConsole::default(),
         r#"console.log('Debug message');"#: [{
             col: 0,
             message: MESSAGE,
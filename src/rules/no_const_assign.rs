@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   ArrayPat, AssignExpr, AssignTarget, AssignTargetPat, Expr, Ident, ObjectPat,
   ObjectPatProp, Pat, SimpleAssignTarget, UpdateExpr,
@@ -33,12 +33,12 @@ impl LintRule for NoConstAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoConstAssignHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows reassigning constants.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoConstAssignHandler))
   }
 }
 
@@ -96,6 +96,10 @@ fn check_scope_for_const(range: SourceRange, name: &Ident, ctx: &mut Context) {
 }
 
 impl Handler for NoConstAssignHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr, NodeKind::UpdateExpr])
+  }
+
   fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
     match &assign_expr.left {
       AssignTarget::Simple(target) => match target {
@@ -39,6 +39,10 @@ impl LintRule for NoConstantCondition {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows constant expressions as conditions.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
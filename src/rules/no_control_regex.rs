@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::extract_regex;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{CallExpr, Callee, Expr, NewExpr, Regex};
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
@@ -42,12 +42,12 @@ impl LintRule for NoControlRegex {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoControlRegexHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows control characters in regular expressions.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoControlRegexHandler))
   }
 }
 
@@ -120,6 +120,10 @@ fn read_hex_until_brace(iter: &mut Peekable<Chars>) -> Option<u64> {
 }
 
 impl Handler for NoControlRegexHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
   fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
     check_regex(regex.inner.exp.to_string().as_str(), regex.range(), ctx);
   }
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{JSXAttr, JSXAttrName};
 use deno_ast::SourceRanged;
 
@@ -21,12 +21,12 @@ impl LintRule for NoDanger {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoDangerHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `dangerouslySetInnerHTML`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoDangerHandler))
   }
 }
 
@@ -36,6 +36,10 @@ const HINT: &str = "Remove this attribute";
 struct NoDangerHandler;
 
 impl Handler for NoDangerHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXAttr])
+  }
+
   fn jsx_attr(&mut self, node: &JSXAttr, ctx: &mut Context) {
     if let JSXAttrName::Ident(name) = node.name {
       if name.sym() == "dangerouslySetInnerHTML" {
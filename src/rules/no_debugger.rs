@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::view::DebuggerStmt;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -34,18 +34,22 @@ impl LintRule for NoDebugger {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoDebuggerHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `debugger` statements.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoDebuggerHandler))
   }
 }
 
 struct NoDebuggerHandler;
 
 impl Handler for NoDebuggerHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::DebuggerStmt])
+  }
+
   fn debugger_stmt(&mut self, debugger_stmt: &DebuggerStmt, ctx: &mut Context) {
     ctx.add_diagnostic_with_hint(
       debugger_stmt.range(),
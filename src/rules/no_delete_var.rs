@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{Expr, UnaryExpr, UnaryOp};
 use deno_ast::SourceRanged;
 use derive_more::Display;
@@ -34,18 +34,22 @@ impl LintRule for NoDeleteVar {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoDeleteVarHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows deleting variables.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoDeleteVarHandler))
   }
 }
 
 struct NoDeleteVarHandler;
 
 impl Handler for NoDeleteVarHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::UnaryExpr])
+  }
+
   fn unary_expr(&mut self, unary_expr: &UnaryExpr, ctx: &mut Context) {
     if unary_expr.op() != UnaryOp::Delete {
       return;
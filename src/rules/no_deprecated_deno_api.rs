@@ -3,12 +3,11 @@
 use super::Context;
 use super::LintRule;
 use crate::handler::Handler;
-use crate::handler::Traverse;
 use crate::tags;
 use crate::tags::Tags;
-use crate::Program;
 
 use deno_ast::view as ast_view;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
 use std::convert::TryFrom;
@@ -27,12 +26,12 @@ impl LintRule for NoDeprecatedDenoApi {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoDeprecatedDenoApiHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Warns about deprecated Deno APIs.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoDeprecatedDenoApiHandler))
   }
 }
 
@@ -294,6 +293,10 @@ impl DeprecatedApi {
 struct NoDeprecatedDenoApiHandler;
 
 impl Handler for NoDeprecatedDenoApiHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr, NodeKind::TsQualifiedName])
+  }
+
   fn member_expr(
     &mut self,
     member_expr: &ast_view::MemberExpr,
@@ -35,6 +35,10 @@ impl LintRule for NoDupeArgs {
     "no-dupe-args"
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows duplicate parameter names.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -42,6 +42,10 @@ impl LintRule for NoDupeClassMembers {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows duplicate class members.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -42,6 +42,10 @@ impl LintRule for NoDupeElseIf {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows repeating a condition in an `if`-`else if` chain.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
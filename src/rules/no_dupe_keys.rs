@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::StringRepr;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   GetterProp, KeyValueProp, MethodProp, ObjectLit, Prop, PropOrSpread,
   SetterProp,
@@ -40,12 +40,12 @@ impl LintRule for NoDupeKeys {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoDupeKeysHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows duplicate keys in object literals.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoDupeKeysHandler))
   }
 }
 
@@ -161,6 +161,10 @@ impl PropertyInfo {
 }
 
 impl Handler for NoDupeKeysHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ObjectLit])
+  }
+
   fn object_lit(&mut self, obj_lit: &ObjectLit, ctx: &mut Context) {
     let range = obj_lit.range();
     let mut keys: HashMap<String, PropertyInfo> = HashMap::new();
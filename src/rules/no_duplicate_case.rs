@@ -39,6 +39,10 @@ impl LintRule for NoDuplicateCase {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows duplicate `case` labels.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{ArrowExpr, BlockStmt, Constructor, Function, SwitchStmt};
 use deno_ast::{SourceRanged, SourceRangedForSpanned};
 
@@ -21,18 +21,22 @@ impl LintRule for NoEmpty {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoEmptyHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows empty block statements.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoEmptyHandler))
   }
 }
 
 struct NoEmptyHandler;
 
 impl Handler for NoEmptyHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BlockStmt, NodeKind::SwitchStmt])
+  }
+
   fn block_stmt(&mut self, block_stmt: &BlockStmt, ctx: &mut Context) {
     // Empty functions shouldn't be caught by this rule.
     // Because function's body is a block statement, we're gonna
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::Regex;
 use deno_ast::SourceRanged;
 use once_cell::sync::Lazy;
@@ -25,18 +25,22 @@ impl LintRule for NoEmptyCharacterClass {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoEmptyCharacterClassVisitor.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows empty character classes in regular expressions.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoEmptyCharacterClassVisitor))
   }
 }
 
 struct NoEmptyCharacterClassVisitor;
 
 impl Handler for NoEmptyCharacterClassVisitor {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex])
+  }
+
   fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
     let raw_regex = regex.text_fast(ctx.text_info());
 
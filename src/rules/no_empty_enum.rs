@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRanged};
 
 #[derive(Debug)]
@@ -21,18 +21,22 @@ impl LintRule for NoEmptyEnum {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoEmptyEnumHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows enums without members.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoEmptyEnumHandler))
   }
 }
 
 struct NoEmptyEnumHandler;
 
 impl Handler for NoEmptyEnumHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsEnumDecl])
+  }
+
   fn ts_enum_decl(
     &mut self,
     enum_decl: &ast_view::TsEnumDecl,
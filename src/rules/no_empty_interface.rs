@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::TsInterfaceDecl;
 use deno_ast::SourceRanged;
 use derive_more::Display;
@@ -34,18 +34,22 @@ impl LintRule for NoEmptyInterface {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoEmptyInterfaceHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows interfaces without members.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoEmptyInterfaceHandler))
   }
 }
 
 struct NoEmptyInterfaceHandler;
 
 impl Handler for NoEmptyInterfaceHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsInterfaceDecl])
+  }
+
   fn ts_interface_decl(
     &mut self,
     interface_decl: &TsInterfaceDecl,
@@ -27,6 +27,10 @@ impl LintRule for NoEmptyPattern {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows empty destructuring patterns.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
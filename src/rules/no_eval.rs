@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::StringRepr;
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{CallExpr, Callee, Expr, ParenExpr, VarDeclarator};
 use deno_ast::{SourceRange, SourceRanged};
 
@@ -19,12 +19,12 @@ impl LintRule for NoEval {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoEvalHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `eval()`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoEvalHandler))
   }
 }
 
@@ -68,6 +68,10 @@ impl NoEvalHandler {
 }
 
 impl Handler for NoEvalHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::VarDeclarator, NodeKind::CallExpr])
+  }
+
   fn var_declarator(&mut self, v: &VarDeclarator, ctx: &mut Context) {
     if let Some(Expr::Ident(ident)) = &v.init {
       self.maybe_add_diagnostic(*ident, v.range(), ctx);
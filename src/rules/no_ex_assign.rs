@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   ArrayPat, AssignExpr, AssignTarget, AssignTargetPat, Ident, ObjectPat,
   ObjectPatProp, Pat, SimpleAssignTarget,
@@ -37,12 +37,12 @@ impl LintRule for NoExAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoExAssignHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows reassigning the exception of a `catch` clause.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoExAssignHandler))
   }
 }
 
@@ -100,6 +100,10 @@ fn check_scope_for_const(range: SourceRange, name: &Ident, ctx: &mut Context) {
 }
 
 impl Handler for NoExAssignHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
+  }
+
   fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
     match &assign_expr.left {
       AssignTarget::Simple(target) => match target {
@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::swc::ast::TsKeywordTypeKind::TsAnyKeyword;
+use deno_ast::view::NodeKind;
 use deno_ast::view::TsKeywordType;
 use deno_ast::SourceRanged;
 
@@ -24,18 +24,22 @@ impl LintRule for NoExplicitAny {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoExplicitAnyHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows the `any` type.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoExplicitAnyHandler))
   }
 }
 
 struct NoExplicitAnyHandler;
 
 impl Handler for NoExplicitAnyHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsKeywordType])
+  }
+
   fn ts_keyword_type(
     &mut self,
     ts_keyword_type: &TsKeywordType,
@@ -1,13 +1,13 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::file_scope::FileScope;
+use crate::handler::Handler;
 use crate::tags::Tags;
-use crate::Program;
 use deno_ast::view::ImportDecl;
+use deno_ast::view::NodeKind;
 use deno_ast::{ModuleSpecifier, SourceRanged};
 use derive_more::Display;
-use std::ffi::OsStr;
 
 #[derive(Debug)]
 pub struct NoExternalImport;
@@ -35,13 +35,24 @@ impl LintRule for NoExternalImport {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    let mut handler = NoExternalImportHandler;
-    handler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows importing remote modules outside of `deps.ts`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoExternalImportHandler))
+  }
+
+  // External imports belong in the `deps.ts` file. Declaration files like
+  // `deps.d.ts` are still linted, but remote `deps.ts` modules aren't.
+  fn file_scope(&self) -> Option<FileScope> {
+    let exclude = [
+      "", ".ts", ".tsx", ".mts", ".cts", ".js", ".jsx", ".mjs", ".cjs",
+    ]
+    .iter()
+    .map(|extension| format!("deps{}", extension))
+    .collect();
+    FileScope::new(vec![], exclude).ok()
   }
 }
 
@@ -51,13 +62,7 @@ struct NoExternalImportHandler;
 impl NoExternalImportHandler {
   fn check_import_path(&self, decl: &ImportDecl, ctx: &mut Context) {
     let parsed_src = ModuleSpecifier::parse(decl.src.value());
-    let maybe_file_path = ctx.specifier().to_file_path().ok();
-    let file_stem = maybe_file_path
-      .as_ref()
-      .and_then(|p| p.file_stem())
-      .and_then(OsStr::to_str);
-
-    if parsed_src.is_ok() && file_stem != Some("deps") {
+    if parsed_src.is_ok() {
       ctx.add_diagnostic_with_hint(
         decl.range(),
         CODE,
@@ -69,6 +74,10 @@ impl NoExternalImportHandler {
 }
 
 impl Handler for NoExternalImportHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ImportDecl])
+  }
+
   fn import_decl(
     &mut self,
     decl: &deno_ast::view::ImportDecl,
@@ -105,6 +114,12 @@ mod tests {
       },
       "import { assertEquals } from 'https://deno.land/std@0.126.0/testing/asserts.ts'"
     };
+
+    assert_lint_ok! {
+      NoExternalImport,
+      filename: "https://example.com/deps.ts",
+      "import { assertEquals } from 'https://deno.land/std@0.126.0/testing/asserts.ts'"
+    };
   }
 
   #[test]
@@ -154,5 +169,21 @@ mod tests {
         },
       ],
     };
+
+    assert_lint_err! {
+      NoExternalImport,
+      filename: if cfg!(windows) {
+        "file:///c:/deps.d.ts"
+      } else {
+        "file:///deps.d.ts"
+      },
+      "import type { Foo } from 'https://example.com';": [
+        {
+          col: 0,
+          message: NoExternalImportMessage::Unexpected,
+          hint: NoExternalImportHint::CreateDependencyFile,
+        },
+      ],
+    };
   }
 }
@@ -1,9 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   CallExpr, Callee, CondExpr, DoWhileStmt, Expr, ExprOrSpread, ForStmt, Ident,
   IfStmt, NewExpr, ParenExpr, UnaryExpr, UnaryOp, WhileStmt,
@@ -43,49 +44,155 @@ impl LintRule for NoExtraBooleanCast {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoExtraBooleanCastHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows unnecessary boolean casts.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoExtraBooleanCastHandler))
   }
 }
 
 struct NoExtraBooleanCastHandler;
 
-fn unexpected_call(range: SourceRange, ctx: &mut Context) {
-  ctx.add_diagnostic_with_hint(
+const BOOLEAN_CALL_FIX_DESC: &str = "Remove the Boolean call";
+const DOUBLE_NEGATION_FIX_DESC: &str = "Remove the double negation";
+
+fn unexpected_call(
+  range: SourceRange,
+  replacement: Option<String>,
+  ctx: &mut Context,
+) {
+  ctx.add_diagnostic_with_fixes(
     range,
     CODE,
     NoExtraBooleanCastMessage::BooleanCall,
-    NoExtraBooleanCastHint::BooleanCall,
+    Some(NoExtraBooleanCastHint::BooleanCall.to_string()),
+    fixes(range, BOOLEAN_CALL_FIX_DESC, replacement, ctx),
   );
 }
 
-fn unexpected_negation(range: SourceRange, ctx: &mut Context) {
-  ctx.add_diagnostic_with_hint(
+fn unexpected_negation(
+  range: SourceRange,
+  replacement: Option<String>,
+  ctx: &mut Context,
+) {
+  ctx.add_diagnostic_with_fixes(
     range,
     CODE,
     NoExtraBooleanCastMessage::DoubleNegation,
-    NoExtraBooleanCastHint::DoubleNegation,
+    Some(NoExtraBooleanCastHint::DoubleNegation.to_string()),
+    fixes(range, DOUBLE_NEGATION_FIX_DESC, replacement, ctx),
   );
 }
 
-fn check_condition(expr: &Expr, ctx: &mut Context) {
+fn fixes(
+  range: SourceRange,
+  description: &'static str,
+  replacement: Option<String>,
+  ctx: &Context,
+) -> Vec<LintFix> {
+  replacement
+    .map(|new_text| LintFix {
+      description: description.into(),
+      kind: LintFixKind::Safe,
+      changes: vec![LintFixChange {
+        new_text: separate_from_surroundings(range, new_text, ctx).into(),
+        range,
+      }],
+    })
+    .into_iter()
+    .collect()
+}
+
+/// Adds spaces around the replacement of `range` where it would otherwise
+/// merge with the surrounding tokens, e.g. `yield!!a` to `yield a`.
+fn separate_from_surroundings(
+  range: SourceRange,
+  new_text: String,
+  ctx: &Context,
+) -> String {
+  let text = ctx.text_info().text_str();
+  let byte_range = range.as_byte_range(ctx.text_info().range().start);
+  let is_word_char = |c: Option<char>| {
+    c.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$')
+  };
+  let mut result = new_text;
+  if is_word_char(text[..byte_range.start].chars().next_back())
+    && is_word_char(result.chars().next())
+  {
+    result.insert(0, ' ');
+  }
+  if is_word_char(text[byte_range.end..].chars().next())
+    && is_word_char(result.chars().next_back())
+  {
+    result.push(' ');
+  }
+  result
+}
+
+/// Returns the source text of `expr`, wrapped in parentheses if `wrap` is
+/// true.
+fn expr_text(expr: &Expr, wrap: bool, ctx: &Context) -> String {
+  let text = expr.text_fast(ctx.text_info());
+  if wrap {
+    format!("({})", text)
+  } else {
+    text.to_string()
+  }
+}
+
+/// Returns the only argument of a `Boolean` call, which is what the call can
+/// be replaced with.
+fn boolean_call_arg<'a>(args: &[&ExprOrSpread<'a>]) -> Option<Expr<'a>> {
+  match args {
+    [arg] if arg.spread().is_none() => Some(arg.expr),
+    _ => None,
+  }
+}
+
+/// Returns the operand of `expr` if it's a `!` expression, ignoring
+/// parentheses.
+fn negated_arg<'a>(expr: &Expr<'a>) -> Option<Expr<'a>> {
+  match expr {
+    Expr::Unary(unary_expr) if unary_expr.op() == UnaryOp::Bang => {
+      Some(unary_expr.arg)
+    }
+    Expr::Paren(ParenExpr { expr, .. }) => negated_arg(expr),
+    _ => None,
+  }
+}
+
+/// Checks the test of a statement or of a conditional expression, if
+/// `is_cond_test` is true.
+fn check_condition(expr: &Expr, is_cond_test: bool, ctx: &mut Context) {
   match expr {
     Expr::Call(CallExpr {
-      ref callee, inner, ..
+      ref callee,
+      inner,
+      args,
+      ..
     }) => {
       if callee_is_boolean(callee) {
-        unexpected_call(inner.range(), ctx);
+        let replacement = boolean_call_arg(args).map(|arg| {
+          let wrap = is_cond_test
+            && matches!(
+              arg,
+              Expr::Assign(_) | Expr::Arrow(_) | Expr::Yield(_) | Expr::Cond(_)
+            );
+          expr_text(&arg, wrap, ctx)
+        });
+        unexpected_call(inner.range(), replacement, ctx);
       }
     }
-    Expr::Unary(UnaryExpr { inner, ref arg, .. }) if has_n_bang(arg, 1) => {
-      unexpected_negation(inner.range(), ctx);
+    Expr::Unary(unary_expr) if has_n_bang(&unary_expr.arg, 1) => {
+      let replacement = negated_arg(expr)
+        .and_then(|arg| negated_arg(&arg))
+        .map(|arg| expr_text(&arg, false, ctx));
+      unexpected_negation(unary_expr.range(), replacement, ctx);
     }
     Expr::Paren(ParenExpr { ref expr, .. }) => {
-      check_condition(expr, ctx);
+      check_condition(expr, false, ctx);
     }
     _ => (),
   }
@@ -104,13 +211,32 @@ fn check_unary_expr_internal(
   ctx: &mut Context,
 ) {
   match internal_expr {
-    Expr::Call(CallExpr { ref callee, .. }) => {
+    Expr::Call(CallExpr {
+      ref callee, args, ..
+    }) => {
       if callee_is_boolean(callee) {
-        unexpected_call(unary_expr_range, ctx);
+        let replacement = boolean_call_arg(args).map(|arg| {
+          let wrap = matches!(
+            arg,
+            Expr::Bin(_)
+              | Expr::Assign(_)
+              | Expr::Cond(_)
+              | Expr::Arrow(_)
+              | Expr::Yield(_)
+              | Expr::TsAs(_)
+              | Expr::TsSatisfies(_)
+              | Expr::TsConstAssertion(_)
+          );
+          format!("!{}", expr_text(&arg, wrap, ctx))
+        });
+        unexpected_call(unary_expr_range, replacement, ctx);
       }
     }
     Expr::Unary(UnaryExpr { ref arg, .. }) if has_n_bang(arg, 1) => {
-      unexpected_negation(unary_expr_range, ctx);
+      let replacement = negated_arg(internal_expr)
+        .and_then(|arg| negated_arg(&arg))
+        .map(|arg| format!("!{}", expr_text(&arg, false, ctx)));
+      unexpected_negation(unary_expr_range, replacement, ctx);
     }
     Expr::Paren(ParenExpr { ref expr, .. }) => {
       check_unary_expr_internal(unary_expr_range, expr, ctx);
@@ -120,32 +246,45 @@ fn check_unary_expr_internal(
 }
 
 impl Handler for NoExtraBooleanCastHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::CondExpr,
+      NodeKind::ForStmt,
+      NodeKind::IfStmt,
+      NodeKind::WhileStmt,
+      NodeKind::DoWhileStmt,
+      NodeKind::CallExpr,
+      NodeKind::NewExpr,
+      NodeKind::UnaryExpr,
+    ])
+  }
+
   fn cond_expr(&mut self, cond_expr: &CondExpr, ctx: &mut Context) {
-    check_condition(&cond_expr.test, ctx);
+    check_condition(&cond_expr.test, true, ctx);
   }
 
   fn for_stmt(&mut self, for_stmt: &ForStmt, ctx: &mut Context) {
     if let Some(ref test_expr) = for_stmt.test {
-      check_condition(test_expr, ctx);
+      check_condition(test_expr, false, ctx);
     }
   }
 
   fn if_stmt(&mut self, if_stmt: &IfStmt, ctx: &mut Context) {
-    check_condition(&if_stmt.test, ctx);
+    check_condition(&if_stmt.test, false, ctx);
   }
 
   fn while_stmt(&mut self, while_stmt: &WhileStmt, ctx: &mut Context) {
-    check_condition(&while_stmt.test, ctx);
+    check_condition(&while_stmt.test, false, ctx);
   }
 
   fn do_while_stmt(&mut self, do_while_stmt: &DoWhileStmt, ctx: &mut Context) {
-    check_condition(&do_while_stmt.test, ctx);
+    check_condition(&do_while_stmt.test, false, ctx);
   }
 
   fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
     if callee_is_boolean(&call_expr.callee) {
       if let Some(ExprOrSpread { expr, .. }) = call_expr.args.first() {
-        check_condition(expr, ctx);
+        check_condition(expr, false, ctx);
       }
     }
   }
@@ -155,7 +294,7 @@ impl Handler for NoExtraBooleanCastHandler {
       if let Some(ExprOrSpread { expr, .. }) =
         new_expr.args.as_ref().and_then(|a| a.first())
       {
-        check_condition(expr, ctx);
+        check_condition(expr, false, ctx);
       }
     }
   }
@@ -228,6 +367,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if (foo) {}", LintFixKind::Safe),
         }
       ],
       "do {} while (!!foo)": [
@@ -235,6 +375,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while (foo)"),
         }
       ],
       "while (!!foo) {}": [
@@ -242,6 +383,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while (foo) {}"),
         }
       ],
       "!!foo ? bar : baz": [
@@ -249,6 +391,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "foo ? bar : baz"),
         }
       ],
       "for (; !!foo;) {}": [
@@ -256,6 +399,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; foo;) {}"),
         }
       ],
       "!!!foo": [
@@ -263,6 +407,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!foo"),
         }
       ],
       "Boolean(!!foo)": [
@@ -270,6 +415,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean(foo)"),
         }
       ],
       "new Boolean(!!foo)": [
@@ -277,6 +423,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean(foo)"),
         }
       ],
       "if (Boolean(foo)) {}": [
@@ -284,6 +431,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if (foo) {}"),
         }
       ],
       "do {} while (Boolean(foo))": [
@@ -291,6 +439,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "do {} while (foo)"),
         }
       ],
       "while (Boolean(foo)) {}": [
@@ -298,6 +447,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "while (foo) {}"),
         }
       ],
       "Boolean(foo) ? bar : baz": [
@@ -305,6 +455,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "foo ? bar : baz"),
         }
       ],
       "for (; Boolean(foo);) {}": [
@@ -312,6 +463,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "for (; foo;) {}"),
         }
       ],
       "!Boolean(foo)": [
@@ -319,6 +471,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!foo"),
         }
       ],
       "!Boolean(foo && bar)": [
@@ -326,6 +479,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(foo && bar)"),
         }
       ],
       "!Boolean(foo + bar)": [
@@ -333,6 +487,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(foo + bar)"),
         }
       ],
       "!Boolean(+foo)": [
@@ -340,6 +495,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!+foo"),
         }
       ],
       "!Boolean(foo())": [
@@ -347,6 +503,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!foo()"),
         }
       ],
       "!Boolean(foo = bar)": [
@@ -354,6 +511,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(foo = bar)"),
         }
       ],
       "!Boolean(...foo);": [
@@ -375,6 +533,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(foo, bar());"),
         }
       ],
       "!Boolean();": [
@@ -431,6 +590,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "Boolean(foo)"),
         }
       ],
       "Boolean(!!foo, bar)": [
@@ -438,6 +598,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean(foo, bar)"),
         }
       ],
       "function *foo() { yield!!a ? b : c }": [
@@ -445,6 +606,7 @@ mod tests {
           col: 23,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "function *foo() { yield a ? b : c }"),
         }
       ],
       "function *foo() { yield!! a ? b : c }": [
@@ -452,6 +614,7 @@ mod tests {
           col: 23,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "function *foo() { yield a ? b : c }"),
         }
       ],
       "function *foo() { yield! !a ? b : c }": [
@@ -459,6 +622,7 @@ mod tests {
           col: 23,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "function *foo() { yield a ? b : c }"),
         }
       ],
       "function *foo() { yield !!a ? b : c }": [
@@ -466,6 +630,7 @@ mod tests {
           col: 24,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "function *foo() { yield a ? b : c }"),
         }
       ],
       "function *foo() { yield(!!a) ? b : c }": [
@@ -473,6 +638,7 @@ mod tests {
           col: 24,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "function *foo() { yield(a) ? b : c }"),
         }
       ],
       "function *foo() { yield/**/!!a ? b : c }": [
@@ -480,6 +646,7 @@ mod tests {
           col: 27,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "function *foo() { yield/**/a ? b : c }"),
         }
       ],
       "x=!!a ? b : c ": [
@@ -487,6 +654,7 @@ mod tests {
           col: 2,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "x=a ? b : c "),
         }
       ],
       "void!Boolean()": [
@@ -550,6 +718,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!foo"),
         }
       ],
       "!!/**/!foo": [
@@ -557,6 +726,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!foo"),
         }
       ],
       "!!!/**/foo": [
@@ -564,6 +734,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!foo"),
         }
       ],
       "!!!foo/**/": [
@@ -571,6 +742,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!foo/**/"),
         }
       ],
       "if(!/**/!foo);": [
@@ -578,6 +750,7 @@ mod tests {
           col: 3,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if(foo);"),
         }
       ],
       "(!!/**/foo ? 1 : 2)": [
@@ -585,6 +758,7 @@ mod tests {
           col: 1,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "(foo ? 1 : 2)"),
         }
       ],
       "!/**/Boolean(foo)": [
@@ -592,6 +766,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!foo"),
         }
       ],
       "!Boolean/**/(foo)": [
@@ -599,6 +774,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!foo"),
         }
       ],
       "!Boolean(/**/foo)": [
@@ -606,6 +782,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!foo"),
         }
       ],
       "!Boolean(foo/**/)": [
@@ -613,6 +790,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!foo"),
         }
       ],
       "!Boolean(foo)/**/": [
@@ -620,6 +798,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!foo/**/"),
         }
       ],
       "if(Boolean/**/(foo));": [
@@ -627,6 +806,7 @@ mod tests {
           col: 3,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if(foo);"),
         }
       ],
       "(Boolean(foo/**/) ? 1 : 2)": [
@@ -634,6 +814,7 @@ mod tests {
           col: 1,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "(foo ? 1 : 2)"),
         }
       ],
       "/**/!Boolean()": [
@@ -725,6 +906,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean((a, b))"),
         }
       ],
       "Boolean(Boolean((a, b)))": [
@@ -732,6 +914,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "Boolean((a, b))"),
         }
       ],
       "Boolean((!!(a, b)))": [
@@ -739,6 +922,7 @@ mod tests {
           col: 9,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean(((a, b)))"),
         }
       ],
       "Boolean((Boolean((a, b))))": [
@@ -746,6 +930,7 @@ mod tests {
           col: 9,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "Boolean(((a, b)))"),
         }
       ],
       "Boolean(!(!(a, b)))": [
@@ -753,6 +938,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean((a, b))"),
         }
       ],
       "Boolean((!(!(a, b))))": [
@@ -760,6 +946,7 @@ mod tests {
           col: 9,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean(((a, b)))"),
         }
       ],
       "Boolean(!!(a = b))": [
@@ -767,6 +954,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean((a = b))"),
         }
       ],
       "Boolean((!!(a = b)))": [
@@ -774,6 +962,7 @@ mod tests {
           col: 9,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean(((a = b)))"),
         }
       ],
       "Boolean(Boolean(a = b))": [
@@ -781,6 +970,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "Boolean(a = b)"),
         }
       ],
       "Boolean(Boolean((a += b)))": [
@@ -788,6 +978,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "Boolean((a += b))"),
         }
       ],
       "Boolean(!!(a === b))": [
@@ -795,6 +986,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean((a === b))"),
         }
       ],
       "Boolean(!!((a !== b)))": [
@@ -802,6 +994,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean(((a !== b)))"),
         }
       ],
       "Boolean(!!a.b)": [
@@ -809,6 +1002,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean(a.b)"),
         }
       ],
       "Boolean(Boolean((a)))": [
@@ -816,6 +1010,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "Boolean((a))"),
         }
       ],
       "Boolean((!!(a)))": [
@@ -823,6 +1018,7 @@ mod tests {
           col: 9,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "Boolean(((a)))"),
         }
       ],
       "new Boolean(!!(a, b))": [
@@ -830,6 +1026,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean((a, b))"),
         }
       ],
       "new Boolean(Boolean((a, b)))": [
@@ -837,6 +1034,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "new Boolean((a, b))"),
         }
       ],
       "new Boolean((!!(a, b)))": [
@@ -844,6 +1042,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean(((a, b)))"),
         }
       ],
       "new Boolean((Boolean((a, b))))": [
@@ -851,6 +1050,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "new Boolean(((a, b)))"),
         }
       ],
       "new Boolean(!(!(a, b)))": [
@@ -858,6 +1058,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean((a, b))"),
         }
       ],
       "new Boolean((!(!(a, b))))": [
@@ -865,6 +1066,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean(((a, b)))"),
         }
       ],
       "new Boolean(!!(a = b))": [
@@ -872,6 +1074,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean((a = b))"),
         }
       ],
       "new Boolean((!!(a = b)))": [
@@ -879,6 +1082,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean(((a = b)))"),
         }
       ],
       "new Boolean(Boolean(a = b))": [
@@ -886,6 +1090,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "new Boolean(a = b)"),
         }
       ],
       "new Boolean(Boolean((a += b)))": [
@@ -893,6 +1098,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "new Boolean((a += b))"),
         }
       ],
       "new Boolean(!!(a === b))": [
@@ -900,6 +1106,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean((a === b))"),
         }
       ],
       "new Boolean(!!((a !== b)))": [
@@ -907,6 +1114,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean(((a !== b)))"),
         }
       ],
       "new Boolean(!!a.b)": [
@@ -914,6 +1122,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean(a.b)"),
         }
       ],
       "new Boolean(Boolean((a)))": [
@@ -921,6 +1130,7 @@ mod tests {
           col: 12,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "new Boolean((a))"),
         }
       ],
       "new Boolean((!!(a)))": [
@@ -928,6 +1138,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "new Boolean(((a)))"),
         }
       ],
       "if (!!(a, b));": [
@@ -935,6 +1146,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if ((a, b));"),
         }
       ],
       "if (Boolean((a, b)));": [
@@ -942,6 +1154,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if ((a, b));"),
         }
       ],
       "if (!(!(a, b)));": [
@@ -949,6 +1162,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if ((a, b));"),
         }
       ],
       "if (!!(a = b));": [
@@ -956,6 +1170,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if ((a = b));"),
         }
       ],
       "if (Boolean(a = b));": [
@@ -963,6 +1178,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if (a = b);"),
         }
       ],
       "if (!!(a > b));": [
@@ -970,6 +1186,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if ((a > b));"),
         }
       ],
       "if (Boolean(a === b));": [
@@ -977,6 +1194,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if (a === b);"),
         }
       ],
       "if (!!f(a));": [
@@ -984,6 +1202,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if (f(a));"),
         }
       ],
       "if (Boolean(f(a)));": [
@@ -991,6 +1210,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if (f(a));"),
         }
       ],
       "if (!!(f(a)));": [
@@ -998,6 +1218,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if ((f(a)));"),
         }
       ],
       "if ((!!f(a)));": [
@@ -1005,6 +1226,7 @@ mod tests {
           col: 5,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if ((f(a)));"),
         }
       ],
       "if ((Boolean(f(a))));": [
@@ -1012,6 +1234,7 @@ mod tests {
           col: 5,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if ((f(a)));"),
         }
       ],
       "if (!!a);": [
@@ -1019,6 +1242,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if (a);"),
         }
       ],
       "if (Boolean(a));": [
@@ -1026,6 +1250,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if (a);"),
         }
       ],
       "while (!!(a, b));": [
@@ -1033,6 +1258,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while ((a, b));"),
         }
       ],
       "while (Boolean((a, b)));": [
@@ -1040,6 +1266,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "while ((a, b));"),
         }
       ],
       "while (!(!(a, b)));": [
@@ -1047,6 +1274,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while ((a, b));"),
         }
       ],
       "while (!!(a = b));": [
@@ -1054,6 +1282,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while ((a = b));"),
         }
       ],
       "while (Boolean(a = b));": [
@@ -1061,6 +1290,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "while (a = b);"),
         }
       ],
       "while (!!(a > b));": [
@@ -1068,6 +1298,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while ((a > b));"),
         }
       ],
       "while (Boolean(a === b));": [
@@ -1075,6 +1306,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "while (a === b);"),
         }
       ],
       "while (!!f(a));": [
@@ -1082,6 +1314,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while (f(a));"),
         }
       ],
       "while (Boolean(f(a)));": [
@@ -1089,6 +1322,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "while (f(a));"),
         }
       ],
       "while (!!(f(a)));": [
@@ -1096,6 +1330,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while ((f(a)));"),
         }
       ],
       "while ((!!f(a)));": [
@@ -1103,6 +1338,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while ((f(a)));"),
         }
       ],
       "while ((Boolean(f(a))));": [
@@ -1110,6 +1346,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "while ((f(a)));"),
         }
       ],
       "while (!!a);": [
@@ -1117,6 +1354,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while (a);"),
         }
       ],
       "while (Boolean(a));": [
@@ -1124,6 +1362,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "while (a);"),
         }
       ],
       "do {} while (!!(a, b));": [
@@ -1131,6 +1370,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while ((a, b));"),
         }
       ],
       "do {} while (Boolean((a, b)));": [
@@ -1138,6 +1378,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "do {} while ((a, b));"),
         }
       ],
       "do {} while (!(!(a, b)));": [
@@ -1145,6 +1386,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while ((a, b));"),
         }
       ],
       "do {} while (!!(a = b));": [
@@ -1152,6 +1394,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while ((a = b));"),
         }
       ],
       "do {} while (Boolean(a = b));": [
@@ -1159,6 +1402,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "do {} while (a = b);"),
         }
       ],
       "do {} while (!!(a > b));": [
@@ -1166,6 +1410,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while ((a > b));"),
         }
       ],
       "do {} while (Boolean(a === b));": [
@@ -1173,6 +1418,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "do {} while (a === b);"),
         }
       ],
       "do {} while (!!f(a));": [
@@ -1180,6 +1426,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while (f(a));"),
         }
       ],
       "do {} while (Boolean(f(a)));": [
@@ -1187,6 +1434,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "do {} while (f(a));"),
         }
       ],
       "do {} while (!!(f(a)));": [
@@ -1194,6 +1442,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while ((f(a)));"),
         }
       ],
       "do {} while ((!!f(a)));": [
@@ -1201,6 +1450,7 @@ mod tests {
           col: 14,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while ((f(a)));"),
         }
       ],
       "do {} while ((Boolean(f(a))));": [
@@ -1208,6 +1458,7 @@ mod tests {
           col: 14,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "do {} while ((f(a)));"),
         }
       ],
       "do {} while (!!a);": [
@@ -1215,6 +1466,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do {} while (a);"),
         }
       ],
       "do {} while (Boolean(a));": [
@@ -1222,6 +1474,7 @@ mod tests {
           col: 13,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "do {} while (a);"),
         }
       ],
       "for (; !!(a, b););": [
@@ -1229,6 +1482,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; (a, b););"),
         }
       ],
       "for (; Boolean((a, b)););": [
@@ -1236,6 +1490,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "for (; (a, b););"),
         }
       ],
       "for (; !(!(a, b)););": [
@@ -1243,6 +1498,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; (a, b););"),
         }
       ],
       "for (; !!(a = b););": [
@@ -1250,6 +1506,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; (a = b););"),
         }
       ],
       "for (; Boolean(a = b););": [
@@ -1257,6 +1514,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "for (; a = b;);"),
         }
       ],
       "for (; !!(a > b););": [
@@ -1264,6 +1522,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; (a > b););"),
         }
       ],
       "for (; Boolean(a === b););": [
@@ -1271,6 +1530,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "for (; a === b;);"),
         }
       ],
       "for (; !!f(a););": [
@@ -1278,6 +1538,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; f(a););"),
         }
       ],
       "for (; Boolean(f(a)););": [
@@ -1285,6 +1546,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "for (; f(a););"),
         }
       ],
       "for (; !!(f(a)););": [
@@ -1292,6 +1554,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; (f(a)););"),
         }
       ],
       "for (; (!!f(a)););": [
@@ -1299,6 +1562,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; (f(a)););"),
         }
       ],
       "for (; (Boolean(f(a))););": [
@@ -1306,6 +1570,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "for (; (f(a)););"),
         }
       ],
       "for (; !!a;);": [
@@ -1313,6 +1578,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (; a;);"),
         }
       ],
       "for (; Boolean(a););": [
@@ -1320,6 +1586,7 @@ mod tests {
           col: 7,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "for (; a;);"),
         }
       ],
       "!!(a, b) ? c : d": [
@@ -1327,6 +1594,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "(a, b) ? c : d"),
         }
       ],
       "(!!(a, b)) ? c : d": [
@@ -1334,6 +1602,7 @@ mod tests {
           col: 1,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "((a, b)) ? c : d"),
         }
       ],
       "Boolean((a, b)) ? c : d": [
@@ -1341,6 +1610,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "(a, b) ? c : d"),
         }
       ],
       "!!(a = b) ? c : d": [
@@ -1348,6 +1618,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "(a = b) ? c : d"),
         }
       ],
       "Boolean(a -= b) ? c : d": [
@@ -1355,6 +1626,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "(a -= b) ? c : d"),
         }
       ],
       "(Boolean((a *= b))) ? c : d": [
@@ -1362,6 +1634,7 @@ mod tests {
           col: 1,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "((a *= b)) ? c : d"),
         }
       ],
       "!!(a ? b : c) ? d : e": [
@@ -1369,6 +1642,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "(a ? b : c) ? d : e"),
         }
       ],
       "Boolean(a ? b : c) ? d : e": [
@@ -1376,6 +1650,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "(a ? b : c) ? d : e"),
         }
       ],
       "!!(a || b) ? c : d": [
@@ -1383,6 +1658,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "(a || b) ? c : d"),
         }
       ],
       "Boolean(a && b) ? c : d": [
@@ -1390,6 +1666,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "a && b ? c : d"),
         }
       ],
       "!!(a === b) ? c : d": [
@@ -1397,6 +1674,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "(a === b) ? c : d"),
         }
       ],
       "Boolean(a < b) ? c : d": [
@@ -1404,6 +1682,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "a < b ? c : d"),
         }
       ],
       "!!((a !== b)) ? c : d": [
@@ -1411,6 +1690,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "((a !== b)) ? c : d"),
         }
       ],
       "Boolean((a >= b)) ? c : d": [
@@ -1418,6 +1698,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "(a >= b) ? c : d"),
         }
       ],
       "!!+a ? b : c": [
@@ -1425,6 +1706,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "+a ? b : c"),
         }
       ],
       "!!+(a) ? b : c": [
@@ -1432,6 +1714,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "+(a) ? b : c"),
         }
       ],
       "Boolean(!a) ? b : c": [
@@ -1439,6 +1722,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!a ? b : c"),
         }
       ],
       "!!f(a) ? b : c": [
@@ -1446,6 +1730,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "f(a) ? b : c"),
         }
       ],
       "(!!f(a)) ? b : c": [
@@ -1453,6 +1738,7 @@ mod tests {
           col: 1,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "(f(a)) ? b : c"),
         }
       ],
       "Boolean(a.b) ? c : d": [
@@ -1460,6 +1746,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "a.b ? c : d"),
         }
       ],
       "!!a ? b : c": [
@@ -1467,6 +1754,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "a ? b : c"),
         }
       ],
       "Boolean(a) ? b : c": [
@@ -1474,6 +1762,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "a ? b : c"),
         }
       ],
       "!!!(a, b)": [
@@ -1481,6 +1770,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a, b)"),
         }
       ],
       "!Boolean((a, b))": [
@@ -1488,6 +1778,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(a, b)"),
         }
       ],
       "!!!(a = b)": [
@@ -1495,6 +1786,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a = b)"),
         }
       ],
       "!!(!(a += b))": [
@@ -1502,6 +1794,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a += b)"),
         }
       ],
       "!(!!(a += b))": [
@@ -1509,6 +1802,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a += b)"),
         }
       ],
       "!Boolean(a -= b)": [
@@ -1516,6 +1810,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(a -= b)"),
         }
       ],
       "!Boolean((a -= b))": [
@@ -1523,6 +1818,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(a -= b)"),
         }
       ],
       "!(Boolean(a -= b))": [
@@ -1530,6 +1826,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(a -= b)"),
         }
       ],
       "!!!(a || b)": [
@@ -1537,6 +1834,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a || b)"),
         }
       ],
       "!Boolean(a || b)": [
@@ -1544,6 +1842,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(a || b)"),
         }
       ],
       "!!!(a && b)": [
@@ -1551,6 +1850,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a && b)"),
         }
       ],
       "!Boolean(a && b)": [
@@ -1558,6 +1858,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(a && b)"),
         }
       ],
       "!!!(a != b)": [
@@ -1565,6 +1866,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a != b)"),
         }
       ],
       "!!!(a === b)": [
@@ -1572,6 +1874,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a === b)"),
         }
       ],
       "var x = !Boolean(a > b)": [
@@ -1579,6 +1882,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "var x = !(a > b)"),
         }
       ],
       "!!!(a - b)": [
@@ -1586,6 +1890,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a - b)"),
         }
       ],
       "!!!(a ** b)": [
@@ -1593,6 +1898,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(a ** b)"),
         }
       ],
       "!Boolean(a ** b)": [
@@ -1600,6 +1906,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(a ** b)"),
         }
       ],
       "async function f() { !!!(await a) }": [
@@ -1607,6 +1914,7 @@ mod tests {
           col: 21,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "async function f() { !(await a) }"),
         }
       ],
       "async function f() { !Boolean(await a) }": [
@@ -1614,6 +1922,7 @@ mod tests {
           col: 21,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "async function f() { !await a }"),
         }
       ],
       "!!!!a": [
@@ -1621,11 +1930,13 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!!a"),
         },
         {
           col: 1,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!!a"),
         }
       ],
       "!!(!(!a))": [
@@ -1633,11 +1944,13 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(!a)"),
         },
         {
           col: 1,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!!a"),
         }
       ],
       "!Boolean(!a)": [
@@ -1645,6 +1958,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!!a"),
         }
       ],
       "!Boolean((!a))": [
@@ -1652,6 +1966,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(!a)"),
         }
       ],
       "!Boolean(!(a))": [
@@ -1659,6 +1974,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!!(a)"),
         }
       ],
       "!(Boolean(!a))": [
@@ -1666,6 +1982,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!!a"),
         }
       ],
       "!!!+a": [
@@ -1673,6 +1990,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!+a"),
         }
       ],
       "!!!(+a)": [
@@ -1680,6 +1998,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(+a)"),
         }
       ],
       "!!(!+a)": [
@@ -1687,6 +2006,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!+a"),
         }
       ],
       "!(!!+a)": [
@@ -1694,6 +2014,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!+a"),
         }
       ],
       "!Boolean((-a))": [
@@ -1701,6 +2022,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!(-a)"),
         }
       ],
       "!Boolean(-(a))": [
@@ -1708,6 +2030,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!-(a)"),
         }
       ],
       "!!!(--a)": [
@@ -1715,6 +2038,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(--a)"),
         }
       ],
       "!Boolean(a++)": [
@@ -1722,6 +2046,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!a++"),
         }
       ],
       "!!!f(a)": [
@@ -1729,6 +2054,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!f(a)"),
         }
       ],
       "!!!(f(a))": [
@@ -1736,6 +2062,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!(f(a))"),
         }
       ],
       "!!!a": [
@@ -1743,6 +2070,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!a"),
         }
       ],
       "!Boolean(a)": [
@@ -1750,6 +2078,7 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!a"),
         }
       ],
       "!Boolean(!!a)": [
@@ -1757,11 +2086,13 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!!!a"),
         },
         {
           col: 9,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!Boolean(a)"),
         }
       ],
       "!Boolean(Boolean(a))": [
@@ -1769,11 +2100,13 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!Boolean(a)"),
         },
         {
           col: 9,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!Boolean(a)"),
         }
       ],
       "!Boolean(Boolean(!!a))": [
@@ -1781,16 +2114,19 @@ mod tests {
           col: 0,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!Boolean(!!a)"),
         },
         {
           col: 9,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "!Boolean(!!a)"),
         },
         {
           col: 17,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "!Boolean(Boolean(a))"),
         }
       ],
       "while (a) { if (!!b) {} }": [
@@ -1798,6 +2134,7 @@ mod tests {
           col: 16,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "while (a) { if (b) {} }"),
         }
       ],
       "while (a) { if (Boolean(b)) {} }": [
@@ -1805,6 +2142,7 @@ mod tests {
           col: 16,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "while (a) { if (b) {} }"),
         }
       ],
       "if (a) { const b = !!!c; }": [
@@ -1812,6 +2150,7 @@ mod tests {
           col: 19,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "if (a) { const b = !c; }"),
         }
       ],
       "if (a) { const b = !Boolean(c); }": [
@@ -1819,6 +2158,7 @@ mod tests {
           col: 19,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "if (a) { const b = !c; }"),
         }
       ],
       "for (let a = 0; a < n; a++) { if (!!b) {} }": [
@@ -1826,6 +2166,7 @@ mod tests {
           col: 34,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "for (let a = 0; a < n; a++) { if (b) {} }"),
         }
       ],
       "for (let a = 0; a < n; a++) { if (Boolean(b)) {} }": [
@@ -1833,6 +2174,7 @@ mod tests {
           col: 34,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "for (let a = 0; a < n; a++) { if (b) {} }"),
         }
       ],
       "do { const b = !!!c; } while(a)": [
@@ -1840,6 +2182,7 @@ mod tests {
           col: 15,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "do { const b = !c; } while(a)"),
         }
       ],
       "do { const b = !Boolean(c); } while(a)": [
@@ -1847,6 +2190,7 @@ mod tests {
           col: 15,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "do { const b = !c; } while(a)"),
         }
       ],
       "a ? !!!b : c": [
@@ -1854,6 +2198,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "a ? !b : c"),
         }
       ],
       "a ? b : !!!c": [
@@ -1861,6 +2206,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "a ? b : !c"),
         }
       ],
       "a ? !!!b : !!!c": [
@@ -1868,11 +2214,13 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "a ? !b : !!!c"),
         },
         {
           col: 11,
           message: NoExtraBooleanCastMessage::DoubleNegation,
           hint: NoExtraBooleanCastHint::DoubleNegation,
+          fix: (DOUBLE_NEGATION_FIX_DESC, "a ? !!!b : !c"),
         }
       ],
       "a ? !Boolean(b) : c": [
@@ -1880,6 +2228,7 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "a ? !b : c"),
         }
       ],
       "a ? b : !Boolean(c)": [
@@ -1887,6 +2236,7 @@ mod tests {
           col: 8,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "a ? b : !c"),
         }
       ],
       "a ? !Boolean(b) : !Boolean(c)": [
@@ -1894,11 +2244,13 @@ mod tests {
           col: 4,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "a ? !b : !Boolean(c)"),
         },
         {
           col: 18,
           message: NoExtraBooleanCastMessage::BooleanCall,
           hint: NoExtraBooleanCastHint::BooleanCall,
+          fix: (BOOLEAN_CALL_FIX_DESC, "a ? !Boolean(b) : !c"),
         }
       ]
     };
@@ -2,13 +2,15 @@
 
 use super::Context;
 use super::LintRule;
+use crate::diagnostic::LintFix;
+use crate::diagnostic::LintFixChange;
+use crate::diagnostic::LintFixKind;
 use crate::handler::Handler;
-use crate::handler::Traverse;
 use crate::tags;
 use crate::tags::Tags;
-use crate::Program;
 
 use deno_ast::view::Expr;
+use deno_ast::view::NodeKind;
 use deno_ast::view::OptChainBase;
 use deno_ast::view::OptChainExpr;
 use deno_ast::view::TsNonNullExpr;
@@ -20,6 +22,7 @@ use derive_more::Display;
 pub struct NoExtraNonNullAssertion;
 
 const CODE: &str = "no-extra-non-null-assertion";
+const FIX_DESC: &str = "Remove the extra non-null assertion operator";
 
 #[derive(Display)]
 enum NoExtraNonNullAssertionMessage {
@@ -42,51 +45,59 @@ impl LintRule for NoExtraNonNullAssertion {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoExtraNonNullAssertionHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows repeated non-null assertions.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoExtraNonNullAssertionHandler))
   }
 }
 
 struct NoExtraNonNullAssertionHandler;
 
-fn add_diagnostic(range: SourceRange, ctx: &mut Context) {
-  ctx.add_diagnostic_with_hint(
+/// `extra` is the expression ending with the `!` operator to remove.
+fn add_diagnostic(range: SourceRange, extra: SourceRange, ctx: &mut Context) {
+  ctx.add_diagnostic_with_fixes(
     range,
     CODE,
     NoExtraNonNullAssertionMessage::Unexpected,
-    NoExtraNonNullAssertionHint::Remove,
+    Some(NoExtraNonNullAssertionHint::Remove.to_string()),
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      kind: LintFixKind::Safe,
+      changes: vec![LintFixChange {
+        new_text: "".into(),
+        range: SourceRange::new(extra.end - 1, extra.end),
+      }],
+    }],
   );
 }
 
-fn check_expr_for_nested_non_null_assert(
-  range: SourceRange,
-  expr: &Expr,
-  ctx: &mut Context,
-) {
+fn nested_non_null_assert<'a>(
+  expr: &Expr<'a>,
+) -> Option<&'a TsNonNullExpr<'a>> {
   match expr {
-    Expr::TsNonNull(_) => add_diagnostic(range, ctx),
-    Expr::Paren(paren_expr) => {
-      check_expr_for_nested_non_null_assert(range, &paren_expr.expr, ctx)
-    }
-    _ => {}
+    Expr::TsNonNull(non_null_expr) => Some(non_null_expr),
+    Expr::Paren(paren_expr) => nested_non_null_assert(&paren_expr.expr),
+    _ => None,
   }
 }
 
 impl Handler for NoExtraNonNullAssertionHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsNonNullExpr, NodeKind::OptChainExpr])
+  }
+
   fn ts_non_null_expr(
     &mut self,
     ts_non_null_expr: &TsNonNullExpr,
     ctx: &mut Context,
   ) {
-    check_expr_for_nested_non_null_assert(
-      ts_non_null_expr.range(),
-      &ts_non_null_expr.expr,
-      ctx,
-    );
+    if nested_non_null_assert(&ts_non_null_expr.expr).is_some() {
+      let range = ts_non_null_expr.range();
+      add_diagnostic(range, range, ctx);
+    }
   }
 
   fn opt_chain_expr(
@@ -98,7 +109,9 @@ impl Handler for NoExtraNonNullAssertionHandler {
       OptChainBase::Member(member_expr) => &member_expr.obj,
       OptChainBase::Call(call_expr) => &call_expr.callee,
     };
-    check_expr_for_nested_non_null_assert(opt_chain_expr.range(), expr, ctx);
+    if let Some(non_null_expr) = nested_non_null_assert(expr) {
+      add_diagnostic(opt_chain_expr.range(), non_null_expr.range(), ctx);
+    }
   }
 }
 
@@ -126,6 +139,7 @@ mod tests {
           col: 54,
           message: NoExtraNonNullAssertionMessage::Unexpected,
           hint: NoExtraNonNullAssertionHint::Remove,
+          fix: (FIX_DESC, r#"const foo: { str: string } | null = null; const bar = foo!.str;"#, LintFixKind::Safe),
         }
       ],
       r#"function foo(bar: undefined | string) { return bar!!; }"#: [
@@ -133,6 +147,7 @@ mod tests {
           col: 47,
           message: NoExtraNonNullAssertionMessage::Unexpected,
           hint: NoExtraNonNullAssertionHint::Remove,
+          fix: (FIX_DESC, r#"function foo(bar: undefined | string) { return bar!; }"#),
         }
       ],
       r#"function foo(bar?: { str: string }) { return bar!?.str; }"#: [
@@ -140,6 +155,7 @@ mod tests {
           col: 45,
           message: NoExtraNonNullAssertionMessage::Unexpected,
           hint: NoExtraNonNullAssertionHint::Remove,
+          fix: (FIX_DESC, r#"function foo(bar?: { str: string }) { return bar?.str; }"#),
         }
       ],
       r#"function foo(bar?: { str: string }) { return (bar!)!.str; }"#: [
@@ -147,6 +163,7 @@ mod tests {
           col: 45,
           message: NoExtraNonNullAssertionMessage::Unexpected,
           hint: NoExtraNonNullAssertionHint::Remove,
+          fix: (FIX_DESC, r#"function foo(bar?: { str: string }) { return (bar!).str; }"#),
         }
       ],
       r#"function foo(bar?: { str: string }) { return (bar!)?.str; }"#: [
@@ -154,6 +171,7 @@ mod tests {
           col: 45,
           message: NoExtraNonNullAssertionMessage::Unexpected,
           hint: NoExtraNonNullAssertionHint::Remove,
+          fix: (FIX_DESC, r#"function foo(bar?: { str: string }) { return (bar)?.str; }"#),
         }
       ],
       r#"function foo(bar?: { str: string }) { return bar!?.(); }"#: [
@@ -161,6 +179,7 @@ mod tests {
           col: 45,
           message: NoExtraNonNullAssertionMessage::Unexpected,
           hint: NoExtraNonNullAssertionHint::Remove,
+          fix: (FIX_DESC, r#"function foo(bar?: { str: string }) { return bar?.(); }"#),
         }
       ],
       r#"function foo(bar?: { str: string }) { return (bar!)?.(); }"#: [
@@ -168,6 +187,7 @@ mod tests {
           col: 45,
           message: NoExtraNonNullAssertionMessage::Unexpected,
           hint: NoExtraNonNullAssertionHint::Remove,
+          fix: (FIX_DESC, r#"function foo(bar?: { str: string }) { return (bar)?.(); }"#),
         }
       ]
     };
@@ -41,6 +41,10 @@ impl LintRule for NoFallthrough {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `case` clauses falling through to the next one.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -1,11 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::find_lhs_ids;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::view::AssignExpr;
+use deno_ast::view::NodeKind;
 use deno_ast::{BindingKind, SourceRanged};
 use derive_more::Display;
 
@@ -37,18 +37,22 @@ impl LintRule for NoFuncAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoFuncAssignVisitor.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows reassigning function declarations.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoFuncAssignVisitor))
   }
 }
 
 struct NoFuncAssignVisitor;
 
 impl Handler for NoFuncAssignVisitor {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
+  }
+
   fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
     let ids = find_lhs_ids(&assign_expr.left);
 
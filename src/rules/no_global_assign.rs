@@ -1,11 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
-use crate::{globals::GLOBALS, swc_util::find_lhs_ids};
+use crate::{globals::GlobalAccess, swc_util::find_lhs_ids};
 use deno_ast::swc::ast::Id;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use deno_ast::{view::*, SourceRanged};
@@ -37,12 +37,12 @@ impl LintRule for NoGlobalAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoGlobalAssignVisitor.traverse(program, context)
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows assigning to readonly globals.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoGlobalAssignVisitor))
   }
 }
 
@@ -58,24 +58,24 @@ impl NoGlobalAssignVisitor {
       return;
     }
 
-    // We only care about globals.
-    let maybe_global = GLOBALS.iter().find(|(name, _)| name == &&*id.0);
-
-    if let Some(global) = maybe_global {
-      // If global can be overwritten then don't need to report anything
-      if !global.1 {
-        ctx.add_diagnostic_with_hint(
-          range,
-          CODE,
-          NoGlobalAssignMessage::NotAllowed,
-          NoGlobalAssignHint::Remove,
-        );
-      }
+    // We only care about globals, and those that can be overwritten don't
+    // need to be reported.
+    if ctx.lookup_global(&id.0) == Some(GlobalAccess::Readonly) {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        NoGlobalAssignMessage::NotAllowed,
+        NoGlobalAssignHint::Remove,
+      );
     }
   }
 }
 
 impl Handler for NoGlobalAssignVisitor {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr, NodeKind::UpdateExpr])
+  }
+
   fn assign_expr(&mut self, e: &AssignExpr, ctx: &mut Context) {
     let idents: Vec<deno_ast::swc::ast::Ident> = find_lhs_ids(&e.left);
 
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::Tags;
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRanged};
 
 #[derive(Debug)]
@@ -24,18 +24,22 @@ impl LintRule for NoImplicitDeclareNamespaceExport {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoImplicitDeclareNamespaceExportHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows implicit exports from ambient namespaces.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoImplicitDeclareNamespaceExportHandler))
   }
 }
 
 struct NoImplicitDeclareNamespaceExportHandler;
 
 impl Handler for NoImplicitDeclareNamespaceExportHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsModuleDecl])
+  }
+
   fn ts_module_decl(
     &mut self,
     module_decl: &ast_view::TsModuleDecl,
@@ -1,12 +1,13 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::swc::parser::token::{IdentLike, KnownIdent, Token, Word};
 use deno_ast::view as ast_view;
-use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use deno_ast::view::NodeKind;
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
 use if_chain::if_chain;
 
 #[derive(Debug)]
@@ -16,6 +17,7 @@ const CODE: &str = "no-import-assertions";
 const MESSAGE: &str =
   "The `assert` keyword is deprecated for import attributes";
 const HINT: &str = "Instead use the `with` keyword";
+const FIX_DESC: &str = "Replace `assert` with `with`";
 
 impl LintRule for NoImportAssertions {
   fn tags(&self) -> Tags {
@@ -26,18 +28,40 @@ impl LintRule for NoImportAssertions {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoImportAssertionsHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `with` instead of the deprecated `assert` in imports.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoImportAssertionsHandler))
   }
 }
 
 struct NoImportAssertionsHandler;
 
+/// `keyword` is the range of `assert`, without any quotes.
+fn add_diagnostic(ctx: &mut Context, range: SourceRange, keyword: SourceRange) {
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    MESSAGE,
+    Some(HINT.to_string()),
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      kind: LintFixKind::Safe,
+      changes: vec![LintFixChange {
+        new_text: "with".into(),
+        range: keyword,
+      }],
+    }],
+  );
+}
+
 impl Handler for NoImportAssertionsHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ImportDecl, NodeKind::CallExpr])
+  }
+
   fn import_decl(
     &mut self,
     import_decl: &ast_view::ImportDecl,
@@ -51,12 +75,8 @@ impl Handler for NoImportAssertionsHandler {
       if let IdentLike::Known(known_ident) = ident_like;
       if matches!(known_ident, KnownIdent::Assert);
       then {
-        ctx.add_diagnostic_with_hint(
-          prev_token_and_span.span.range(),
-          CODE,
-          MESSAGE,
-          HINT,
-        );
+        let range = prev_token_and_span.span.range();
+        add_diagnostic(ctx, range, range);
       }
     }
   }
@@ -75,22 +95,14 @@ impl Handler for NoImportAssertionsHandler {
               match key_value_prop.key {
                 ast_view::PropName::Ident(ident) => {
                   if ident.sym().as_ref() == "assert" {
-                    ctx.add_diagnostic_with_hint(
-                      ident.range(),
-                      CODE,
-                      MESSAGE,
-                      HINT,
-                    );
+                    add_diagnostic(ctx, ident.range(), ident.range());
                   }
                 },
                 ast_view::PropName::Str(str) => {
                   if str.value().as_ref() == "assert" {
-                    ctx.add_diagnostic_with_hint(
-                      str.range(),
-                      CODE,
-                      MESSAGE,
-                      HINT,
-                    );
+                    let range = str.range();
+                    let keyword = SourceRange::new(range.start + 1, range.end - 1);
+                    add_diagnostic(ctx, range, keyword);
                   }
                 }
                 _ => (),
@@ -129,18 +141,21 @@ mod tests {
         {
           line: 1,
           col: 27,
+          fix: (FIX_DESC, "import foo from './foo.js' with { bar: 'bar' };", LintFixKind::Safe),
         },
       ],
       r#"import('./foo.js', { assert: { bar: 'bar' } });"#: [
         {
           line: 1,
           col: 21,
+          fix: (FIX_DESC, "import('./foo.js', { with: { bar: 'bar' } });"),
         },
       ],
       r#"import('./foo.js', { "assert": { bar: 'bar' } });"#: [
         {
           line: 1,
           col: 21,
+          fix: (FIX_DESC, r#"import('./foo.js', { "with": { bar: 'bar' } });"#),
         },
       ],
     };
@@ -30,6 +30,10 @@ impl LintRule for NoImportAssign {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows assigning to imported bindings.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
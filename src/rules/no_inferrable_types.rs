@@ -2,6 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::ast::{
@@ -32,11 +33,17 @@ enum NoInferrableTypesHint {
   Remove,
 }
 
+const FIX_DESC: &str = "Remove the type annotation";
+
 impl LintRule for NoInferrableTypes {
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows type annotations that are inferred from the value.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -53,19 +60,37 @@ impl LintRule for NoInferrableTypes {
 
 struct NoInferrableTypesVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  /// Range of the type annotation being checked, if it can be removed.
+  type_ann_range: Option<SourceRange>,
 }
 
 impl<'c, 'view> NoInferrableTypesVisitor<'c, 'view> {
   fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+    Self {
+      context,
+      type_ann_range: None,
+    }
   }
 
   fn add_diagnostic_helper(&mut self, range: SourceRange) {
-    self.context.add_diagnostic_with_hint(
+    let fixes = self
+      .type_ann_range
+      .map(|type_ann_range| LintFix {
+        description: FIX_DESC.into(),
+        kind: LintFixKind::Safe,
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range: type_ann_range,
+        }],
+      })
+      .into_iter()
+      .collect();
+    self.context.add_diagnostic_with_fixes(
       range,
       CODE,
       NoInferrableTypesMessage::NotAllowed,
-      NoInferrableTypesHint::Remove,
+      Some(NoInferrableTypesHint::Remove.to_string()),
+      fixes,
     )
   }
 
@@ -282,7 +307,9 @@ impl<'c, 'view> NoInferrableTypesVisitor<'c, 'view> {
     value: &Expr,
     ts_type: &TsTypeAnn,
     range: SourceRange,
+    fixable: bool,
   ) {
+    self.type_ann_range = fixable.then(|| ts_type.range());
     if let TsType::TsKeywordType(ts_type) = &*ts_type.type_ann {
       self.check_keyword_type(value, ts_type, range);
     } else if let TsType::TsTypeRef(ts_type) = &*ts_type.type_ann {
@@ -301,6 +328,7 @@ impl<'c, 'view> Visit for NoInferrableTypesVisitor<'c, 'view> {
               &assign_pat.right,
               ident_type_ann,
               param.range(),
+              !ident.optional,
             );
           }
         }
@@ -318,6 +346,7 @@ impl<'c, 'view> Visit for NoInferrableTypesVisitor<'c, 'view> {
               &assign_pat.right,
               ident_type_ann,
               assign_pat.range(),
+              !ident.optional,
             );
           }
         }
@@ -333,7 +362,7 @@ impl<'c, 'view> Visit for NoInferrableTypesVisitor<'c, 'view> {
     if let Some(init) = &prop.value {
       if let PropName::Ident(_) = &prop.key {
         if let Some(ident_type_ann) = &prop.type_ann {
-          self.check_ts_type(init, ident_type_ann, prop.range());
+          self.check_ts_type(init, ident_type_ann, prop.range(), true);
         }
       }
     }
@@ -346,7 +375,7 @@ impl<'c, 'view> Visit for NoInferrableTypesVisitor<'c, 'view> {
     }
     if let Some(init) = &prop.value {
       if let Some(ident_type_ann) = &prop.type_ann {
-        self.check_ts_type(init, ident_type_ann, prop.range());
+        self.check_ts_type(init, ident_type_ann, prop.range(), true);
       }
     }
     prop.visit_children_with(self);
@@ -357,7 +386,7 @@ impl<'c, 'view> Visit for NoInferrableTypesVisitor<'c, 'view> {
       if let Some(init) = &decl.init {
         if let Pat::Ident(ident) = &decl.name {
           if let Some(ident_type_ann) = &ident.type_ann {
-            self.check_ts_type(init, ident_type_ann, decl.range());
+            self.check_ts_type(init, ident_type_ann, decl.range(), true);
           }
         }
       }
@@ -443,6 +472,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = 10n", LintFixKind::Safe),
         }
       ],
       "const a: bigint = -10n": [
@@ -450,6 +480,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = -10n"),
         }
       ],
       "const a: bigint = BigInt(10)": [
@@ -457,6 +488,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = BigInt(10)"),
         }
       ],
       "const a: bigint = -BigInt?.(10)": [
@@ -464,6 +496,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = -BigInt?.(10)"),
         }
       ],
       "const a: bigint = -BigInt?.(10)": [
@@ -471,6 +504,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = -BigInt?.(10)"),
         }
       ],
       "const a: boolean = false": [
@@ -478,6 +512,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = false"),
         }
       ],
       "const a: boolean = true": [
@@ -485,6 +520,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = true"),
         }
       ],
       "const a: boolean = Boolean(true)": [
@@ -492,6 +528,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = Boolean(true)"),
         }
       ],
       "const a: boolean = Boolean(null)": [
@@ -499,6 +536,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = Boolean(null)"),
         }
       ],
       "const a: boolean = Boolean?.(null)": [
@@ -506,6 +544,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = Boolean?.(null)"),
         }
       ],
       "const a: boolean = !0": [
@@ -513,6 +552,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = !0"),
         }
       ],
       "const a: number = 10": [
@@ -520,6 +560,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = 10"),
         }
       ],
       "const a: number = +10": [
@@ -527,6 +568,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = +10"),
         }
       ],
       "const a: number = -10": [
@@ -534,6 +576,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = -10"),
         }
       ],
       "const a: number = Number('1')": [
@@ -541,6 +584,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = Number('1')"),
         }
       ],
       "const a: number = +Number('1')": [
@@ -548,6 +592,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = +Number('1')"),
         }
       ],
       "const a: number = -Number('1')": [
@@ -555,6 +600,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = -Number('1')"),
         }
       ],
       "const a: number = Number?.('1')": [
@@ -562,6 +608,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = Number?.('1')"),
         }
       ],
       "const a: number = +Number?.('1')": [
@@ -569,6 +616,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = +Number?.('1')"),
         }
       ],
       "const a: number = -Number?.('1')": [
@@ -576,6 +624,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = -Number?.('1')"),
         }
       ],
       "const a: number = Infinity": [
@@ -583,6 +632,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = Infinity"),
         }
       ],
       "const a: number = +Infinity": [
@@ -590,6 +640,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = +Infinity"),
         }
       ],
       "const a: number = -Infinity": [
@@ -597,6 +648,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = -Infinity"),
         }
       ],
       "const a: number = NaN": [
@@ -604,6 +656,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = NaN"),
         }
       ],
       "const a: number = +NaN": [
@@ -611,6 +664,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = +NaN"),
         }
       ],
       "const a: number = -NaN": [
@@ -618,6 +672,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = -NaN"),
         }
       ],
       "const a: null = null": [
@@ -625,6 +680,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = null"),
         }
       ],
       "const a: RegExp = /a/": [
@@ -632,6 +688,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = /a/"),
         }
       ],
       "const a: RegExp = RegExp('a')": [
@@ -639,6 +696,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = RegExp('a')"),
         }
       ],
       "const a: RegExp = RegExp?.('a')": [
@@ -646,6 +704,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = RegExp?.('a')"),
         }
       ],
       "const a: string = 'str'": [
@@ -653,6 +712,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = 'str'"),
         }
       ],
       r#"const a: string = "str""#: [
@@ -660,6 +720,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, r#"const a = "str""#),
         }
       ],
       "const a: string = `str`": [
@@ -667,6 +728,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = `str`"),
         }
       ],
       "const a: string = String(1)": [
@@ -674,6 +736,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = String(1)"),
         }
       ],
       "const a: string = String?.(1)": [
@@ -681,6 +744,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = String?.(1)"),
         }
       ],
       "const a: symbol = Symbol('a')": [
@@ -688,6 +752,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = Symbol('a')"),
         }
       ],
       "const a: symbol = Symbol?.('a')": [
@@ -695,6 +760,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = Symbol?.('a')"),
         }
       ],
       "const a: undefined = undefined": [
@@ -702,6 +768,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = undefined"),
         }
       ],
       "const a: undefined = void someValue": [
@@ -709,6 +776,7 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = void someValue"),
         }
       ],
       "const a: number = 0, b: string = 'foo';": [
@@ -716,11 +784,13 @@ mod tests {
           col: 6,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = 0, b: string = 'foo';"),
         },
         {
           col: 21,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a: number = 0, b = 'foo';"),
         }
       ],
       "function f(a: number = 5) {};": [
@@ -728,6 +798,7 @@ mod tests {
           col: 11,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "function f(a = 5) {};"),
         }
       ],
       "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};": [
@@ -735,16 +806,19 @@ mod tests {
           col: 12,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const fn = (a = 5, b: boolean = true, c: string = 'foo') => {};"),
         },
         {
           col: 27,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const fn = (a: number = 5, b = true, c: string = 'foo') => {};"),
         },
         {
           col: 46,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const fn = (a: number = 5, b: boolean = true, c = 'foo') => {};"),
         }
       ],
       "class A { a: number = 42; }": [
@@ -752,6 +826,7 @@ mod tests {
           col: 10,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "class A { a = 42; }"),
         }
       ],
       "class A { a(x: number = 42) {} }": [
@@ -759,6 +834,7 @@ mod tests {
           col: 12,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "class A { a(x = 42) {} }"),
         }
       ],
 
@@ -768,6 +844,7 @@ mod tests {
           col: 10,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "class A { #foo = '' }"),
         }
       ],
       "class A { static #foo: string = '' }": [
@@ -775,6 +852,7 @@ mod tests {
           col: 10,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "class A { static #foo = '' }"),
         }
       ],
       "class A { #foo(x: number = 42) {} }": [
@@ -782,6 +860,7 @@ mod tests {
           col: 15,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "class A { #foo(x = 42) {} }"),
         }
       ],
       "class A { static #foo(x: number = 42) {} }": [
@@ -789,6 +868,7 @@ mod tests {
           col: 22,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "class A { static #foo(x = 42) {} }"),
         }
       ],
 
@@ -798,6 +878,7 @@ mod tests {
           col: 21,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "function a() { const x = 5; }"),
         }
       ],
       "const a = () => { const b = (x: number = 42) => {}; };": [
@@ -805,6 +886,7 @@ mod tests {
           col: 29,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = () => { const b = (x = 42) => {}; };"),
         }
       ],
       "class A { a = class { b: number = 42; }; }": [
@@ -812,6 +894,7 @@ mod tests {
           col: 22,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "class A { a = class { b = 42; }; }"),
         }
       ],
       "const a = function () { let x: number = 42; };": [
@@ -819,6 +902,7 @@ mod tests {
           col: 28,
           message: NoInferrableTypesMessage::NotAllowed,
           hint: NoInferrableTypesHint::Remove,
+          fix: (FIX_DESC, "const a = function () { let x = 42; };"),
         }
       ],
     };
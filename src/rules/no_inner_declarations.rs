@@ -41,6 +41,10 @@ impl LintRule for NoInnerDeclarations {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows declarations in nested blocks.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -29,6 +29,10 @@ impl LintRule for NoInvalidRegexp {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows invalid regular expressions.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
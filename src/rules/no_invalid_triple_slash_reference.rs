@@ -23,6 +23,10 @@ impl LintRule for NoInvalidTripleSlashReference {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Warns about invalid triple-slash reference directives.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -49,6 +49,10 @@ impl LintRule for NoIrregularWhitespace {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows irregular whitespace characters.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   ClassDecl, ClassMember, Expr, Ident, PropName, TsEntityName, TsInterfaceDecl,
   TsType, TsTypeAliasDecl, TsTypeAnn,
@@ -38,12 +38,8 @@ enum NoMisusedNewHint {
 }
 
 impl LintRule for NoMisusedNew {
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoMisusedNewHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoMisusedNewHandler))
   }
 
   fn tags(&self) -> Tags {
@@ -53,6 +49,12 @@ impl LintRule for NoMisusedNew {
   fn code(&self) -> &'static str {
     CODE
   }
+
+  fn description(&self) -> Option<&'static str> {
+    Some(
+      "Disallows `new` and `constructor` in interfaces and classes where they're misleading.",
+    )
+  }
 }
 
 struct NoMisusedNewHandler;
@@ -72,6 +74,14 @@ fn is_constructor_keyword(ident: &Ident) -> bool {
 }
 
 impl Handler for NoMisusedNewHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::TsTypeAliasDecl,
+      NodeKind::TsInterfaceDecl,
+      NodeKind::ClassDecl,
+    ])
+  }
+
   fn ts_type_alias_decl(&mut self, t: &TsTypeAliasDecl, ctx: &mut Context) {
     if let TsType::TsTypeLit(lit) = t.type_ann {
       for member in lit.members {
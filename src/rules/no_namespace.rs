@@ -25,6 +25,10 @@ impl LintRule for NoNamespace {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows TypeScript namespaces.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{Expr, NewExpr};
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
@@ -23,18 +23,22 @@ impl LintRule for NoNewSymbol {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoNewSymbolHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows calling `Symbol` with `new`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoNewSymbolHandler))
   }
 }
 
 struct NoNewSymbolHandler;
 
 impl Handler for NoNewSymbolHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::NewExpr])
+  }
+
   fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
     if_chain! {
       if let Expr::Ident(ident) = new_expr.callee;
@@ -4,14 +4,15 @@ use super::Context;
 use super::LintRule;
 use crate::diagnostic::LintFix;
 use crate::diagnostic::LintFixChange;
+use crate::diagnostic::LintFixKind;
 use crate::handler::Handler;
-use crate::handler::Traverse;
 use crate::tags;
 use crate::tags::Tags;
 use crate::Program;
 use std::borrow::Cow;
 
 use deno_ast::view as ast_view;
+use deno_ast::view::NodeKind;
 use deno_ast::SourcePos;
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
@@ -31,21 +32,20 @@ static NODE_GLOBALS: phf::Map<&'static str, FixKind> = phf::phf_map! {
 };
 
 impl LintRule for NoNodeGlobals {
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoNodeGlobalsHandler {
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoNodeGlobalsHandler {
       most_recent_import_range: None,
-    }
-    .traverse(program, context);
+    }))
   }
 
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows Node globals that have to be imported in Deno.")
+  }
+
   fn tags(&self) -> Tags {
     &[tags::RECOMMENDED]
   }
@@ -168,6 +168,7 @@ impl NoNodeGlobalsHandler {
       Some(fix_kind.hint().to_string()),
       vec![LintFix {
         description: fix_kind.description().into(),
+        kind: LintFixKind::Unsafe,
         changes: vec![change],
       }],
     );
@@ -175,10 +176,18 @@ impl NoNodeGlobalsHandler {
 }
 
 impl Handler for NoNodeGlobalsHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Ident, NodeKind::ImportDecl])
+  }
+
   fn ident(&mut self, id: &ast_view::Ident, ctx: &mut Context) {
     if !NODE_GLOBALS.contains_key(id.sym()) {
       return;
     }
+    // They're fine in environments that have them, e.g. Node.
+    if ctx.lookup_global(id.sym()).is_some() {
+      return;
+    }
     if id.ctxt() == ctx.unresolved_ctxt() {
       self.add_diagnostic(ctx, id.range(), NODE_GLOBALS[id.sym()]);
     }
@@ -217,7 +226,8 @@ mod tests {
           hint: "Add `import { Buffer } from \"node:buffer\";`",
           fix: (
             "Import from \"node:buffer\"",
-            "import { Buffer } from \"node:buffer\";\nconst b = Buffer;"
+            "import { Buffer } from \"node:buffer\";\nconst b = Buffer;",
+            LintFixKind::Unsafe
           ),
         }
       ],
@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{Callee, Expr, TsNonNullExpr};
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
@@ -25,12 +25,12 @@ impl LintRule for NoNonNullAssertedOptionalChain {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoNonNullAssertedOptionalChainHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows non-null assertions after optional chains.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoNonNullAssertedOptionalChainHandler))
   }
 }
 
@@ -51,6 +51,10 @@ fn check_expr_for_nested_optional_assert(
 }
 
 impl Handler for NoNonNullAssertedOptionalChainHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsNonNullExpr])
+  }
+
   fn ts_non_null_expr(
     &mut self,
     ts_non_null_expr: &TsNonNullExpr,
@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::view::TsNonNullExpr;
 use deno_ast::SourceRanged;
 use derive_more::Display;
@@ -23,18 +23,22 @@ impl LintRule for NoNonNullAssertion {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoNonNullAssertionHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows non-null assertions.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoNonNullAssertionHandler))
   }
 }
 
 struct NoNonNullAssertionHandler;
 
 impl Handler for NoNonNullAssertionHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsNonNullExpr])
+  }
+
   fn ts_non_null_expr(
     &mut self,
     non_null_expr: &TsNonNullExpr,
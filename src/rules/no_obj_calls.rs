@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{CallExpr, Callee, Expr, Ident, NewExpr};
 use deno_ast::{SourceRange, SourceRanged};
 
@@ -25,12 +25,12 @@ impl LintRule for NoObjCalls {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoObjCallsHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows calling global objects like `Math` as functions.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoObjCallsHandler))
   }
 }
 
@@ -51,6 +51,10 @@ fn check_callee(callee: &Ident, range: SourceRange, ctx: &mut Context) {
 }
 
 impl Handler for NoObjCallsHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr, NodeKind::NewExpr])
+  }
+
   fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
     if let Callee::Expr(Expr::Ident(ident)) = call_expr.callee {
       check_callee(ident, call_expr.range(), ctx);
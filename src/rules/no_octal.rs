@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::Number;
 use deno_ast::SourceRanged;
 use once_cell::sync::Lazy;
@@ -25,18 +25,22 @@ impl LintRule for NoOctal {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoOctalHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows octal literals.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoOctalHandler))
   }
 }
 
 struct NoOctalHandler;
 
 impl Handler for NoOctalHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Number])
+  }
+
   fn number(&mut self, literal_num: &Number, ctx: &mut Context) {
     static OCTAL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^0[0-9]").unwrap());
 
@@ -4,13 +4,14 @@ use super::Context;
 use super::LintRule;
 use crate::diagnostic::LintFix;
 use crate::diagnostic::LintFixChange;
+use crate::diagnostic::LintFixKind;
 use crate::handler::Handler;
-use crate::handler::Traverse;
 use crate::tags;
 use crate::tags::Tags;
 use crate::Program;
 
 use deno_ast::view as ast_view;
+use deno_ast::view::NodeKind;
 use deno_ast::SourcePos;
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
@@ -23,21 +24,20 @@ const CODE: &str = "no-process-global";
 const MESSAGE: &str = "NodeJS process global is discouraged in Deno";
 
 impl LintRule for NoProcessGlobal {
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoProcessGlobalHandler {
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoProcessGlobalHandler {
       most_recent_import_range: None,
-    }
-    .traverse(program, context);
+    }))
   }
 
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows the `process` global, which has to be imported in Deno.")
+  }
+
   fn tags(&self) -> Tags {
     &[tags::RECOMMENDED]
   }
@@ -94,6 +94,7 @@ impl NoProcessGlobalHandler {
       Some(String::from("Add `import process from \"node:process\";`")),
       vec![LintFix {
         description: "Import from \"node:process\"".into(),
+        kind: LintFixKind::Unsafe,
         changes: vec![change],
       }],
     );
@@ -101,10 +102,17 @@ impl NoProcessGlobalHandler {
 }
 
 impl Handler for NoProcessGlobalHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Ident, NodeKind::ImportDecl])
+  }
+
   fn ident(&mut self, id: &ast_view::Ident, ctx: &mut Context) {
     if id.sym() != "process" {
       return;
     }
+    if ctx.lookup_global(id.sym()).is_some() {
+      return;
+    }
     if id.ctxt() == ctx.unresolved_ctxt() {
       self.add_diagnostic(ctx, id.range());
     }
@@ -140,7 +148,8 @@ mod tests {
           hint: "Add `import process from \"node:process\";`",
           fix: (
             "Import from \"node:process\"",
-            "import a from 'b';\nimport process from \"node:process\";\nconst e = process.env;"
+            "import a from 'b';\nimport process from \"node:process\";\nconst e = process.env;",
+            LintFixKind::Unsafe
           ),
         }
       ],
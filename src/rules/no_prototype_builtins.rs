@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{CallExpr, Callee, Expr, MemberProp};
 use deno_ast::SourceRanged;
 
@@ -31,18 +31,22 @@ impl LintRule for NoPrototypeBuiltins {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoPrototypeBuiltinsHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows calling `Object.prototype` methods directly on objects.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoPrototypeBuiltinsHandler))
   }
 }
 
 struct NoPrototypeBuiltinsHandler;
 
 impl Handler for NoPrototypeBuiltinsHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
   fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
     let member_expr = match call_expr.callee {
       Callee::Expr(boxed_expr) => match boxed_expr {
@@ -1,12 +1,15 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::swc_util::extract_regex;
 use crate::tags::{self, Tags};
-use crate::Program;
-use deno_ast::view::{CallExpr, Callee, Expr, NewExpr, Regex};
-use deno_ast::{SourceRange, SourceRanged};
+use deno_ast::view::NodeKind;
+use deno_ast::view::{
+  CallExpr, Callee, Expr, ExprOrSpread, Lit, NewExpr, Regex,
+};
+use deno_ast::{SourcePos, SourceRange, SourceRanged};
 use once_cell::sync::Lazy;
 
 #[derive(Debug)]
@@ -15,6 +18,7 @@ pub struct NoRegexSpaces;
 const CODE: &str = "no-regex-spaces";
 const MESSAGE: &str =
   "more than one consecutive spaces in RegExp is not allowed";
+const FIX_DESC: &str = "Replace the spaces with a quantifier";
 
 impl LintRule for NoRegexSpaces {
   fn tags(&self) -> Tags {
@@ -25,18 +29,25 @@ impl LintRule for NoRegexSpaces {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoRegexSpacesHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows multiple consecutive spaces in regular expressions.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoRegexSpacesHandler))
   }
 }
 
 struct NoRegexSpacesHandler;
 
-fn check_regex(regex: &str, range: SourceRange, ctx: &mut Context) {
+/// Checks the pattern of a regex. `pattern_start` is where the pattern starts
+/// in the source, if it's written there as is, so the spaces can be fixed.
+fn check_regex(
+  regex: &str,
+  range: SourceRange,
+  pattern_start: Option<SourcePos>,
+  ctx: &mut Context,
+) {
   static DOUBLE_SPACE: Lazy<regex::Regex> =
     Lazy::new(|| regex::Regex::new(r"(?u) {2}").unwrap());
   static BRACKETS: Lazy<regex::Regex> =
@@ -54,27 +65,81 @@ fn check_regex(regex: &str, range: SourceRange, ctx: &mut Context) {
     character_classes.push((mtch.start(), mtch.end()));
   }
 
-  for mtch in SPACES.find_iter(regex) {
-    let not_in_classes = &character_classes
+  let mut changes = vec![];
+  for captures in SPACES.captures_iter(regex) {
+    let spaces = captures.get(1).unwrap();
+    let not_in_classes = character_classes
       .iter()
-      .all(|v| mtch.start() < v.0 || v.1 <= mtch.start());
-    if *not_in_classes {
-      ctx.add_diagnostic(range, CODE, MESSAGE);
-      return;
+      .all(|v| spaces.start() < v.0 || v.1 <= spaces.start());
+    if not_in_classes {
+      let Some(pattern_start) = pattern_start else {
+        ctx.add_diagnostic(range, CODE, MESSAGE);
+        return;
+      };
+      changes.push(LintFixChange {
+        new_text: format!(" {{{}}}", spaces.len()).into(),
+        range: SourceRange::new(
+          pattern_start + spaces.start(),
+          pattern_start + spaces.end(),
+        ),
+      });
+    }
+  }
+
+  if !changes.is_empty() {
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      MESSAGE,
+      None,
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        kind: LintFixKind::Safe,
+        changes,
+      }],
+    );
+  }
+}
+
+/// Returns where the pattern passed to `RegExp` starts in the source, unless
+/// the string literal contains escapes.
+fn arg_pattern_start(
+  arg: Option<&&ExprOrSpread>,
+  regex: &str,
+  ctx: &Context,
+) -> Option<SourcePos> {
+  let arg = arg?;
+  match arg.expr {
+    Expr::Lit(Lit::Str(str))
+      if str.text_fast(ctx.text_info()).len() == regex.len() + 2 =>
+    {
+      Some(str.start() + 1)
     }
+    Expr::Lit(Lit::Regex(_)) => Some(arg.expr.start() + 1),
+    _ => None,
   }
 }
 
 impl Handler for NoRegexSpacesHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
   fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
-    check_regex(regex.inner.exp.to_string().as_str(), regex.range(), ctx);
+    check_regex(
+      regex.inner.exp.to_string().as_str(),
+      regex.range(),
+      Some(regex.start() + 1),
+      ctx,
+    );
   }
 
   fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
     if let Expr::Ident(ident) = new_expr.callee {
       if let Some(args) = &new_expr.args {
         if let Some(regex) = extract_regex(ctx.scope(), ident, args) {
-          check_regex(regex.as_str(), new_expr.range(), ctx);
+          let pattern_start = arg_pattern_start(args.first(), &regex, ctx);
+          check_regex(regex.as_str(), new_expr.range(), pattern_start, ctx);
         }
       }
     }
@@ -83,7 +148,9 @@ impl Handler for NoRegexSpacesHandler {
   fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
     if let Callee::Expr(Expr::Ident(ident)) = &call_expr.callee {
       if let Some(regex) = extract_regex(ctx.scope(), ident, call_expr.args) {
-        check_regex(regex.as_str(), call_expr.range(), ctx);
+        let pattern_start =
+          arg_pattern_start(call_expr.args.first(), &regex, ctx);
+        check_regex(regex.as_str(), call_expr.range(), pattern_start, ctx);
       }
     }
   }
@@ -155,138 +222,161 @@ mod tests {
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar {2}baz/;", LintFixKind::Safe),
         }
       ],
       "let foo = /bar    baz/;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar {4}baz/;"),
         }
       ],
       "let foo = / a b  c d /;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = / a b {2}c d /;"),
         }
       ],
       "let foo = RegExp(' a b c d  ');": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp(' a b c d {2}');"),
         }
       ],
       "let foo = RegExp('bar    baz');": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp('bar {4}baz');"),
         }
       ],
       "let foo = new RegExp('bar    baz');": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = new RegExp('bar {4}baz');"),
         }
       ],
       "{ let RegExp = function() {}; } var foo = RegExp('bar    baz');": [
         {
           col: 42,
           message: MESSAGE,
+          fix: (FIX_DESC, "{ let RegExp = function() {}; } var foo = RegExp('bar {4}baz');"),
         }
       ],
       "let foo = /bar   {3}baz/;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar {2} {3}baz/;"),
         }
       ],
       "let foo = /bar    ?baz/;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar {3} ?baz/;"),
         }
       ],
       "let foo = RegExp('bar   +baz')": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp('bar {2} +baz')"),
         }
       ],
       "let foo = new RegExp('bar    ');": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = new RegExp('bar {4}');"),
         }
       ],
       "let foo = /bar\\  baz/;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, r#"let foo = /bar\ {2}baz/;"#),
         }
       ],
       "let foo = /[   ]  /;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /[   ] {2}/;"),
         }
       ],
       "let foo = /  [   ] /;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = / {2}[   ] /;"),
         }
       ],
       "let foo = new RegExp('[   ]  ');": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = new RegExp('[   ] {2}');"),
         }
       ],
       "let foo = RegExp('  [ ]');": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp(' {2}[ ]');"),
         }
       ],
       "let foo = /\\[  /;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, r#"let foo = /\[ {2}/;"#),
         }
       ],
       "let foo = /\\[  \\]/;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, r#"let foo = /\[ {2}\]/;"#),
         }
       ],
       "let foo = /(?:  )/;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /(?: {2})/;"),
         }
       ],
       "let foo = RegExp('^foo(?=   )');": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp('^foo(?= {3})');"),
         }
       ],
       "let foo = /\\  /": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, r#"let foo = /\ {2}/"#),
         }
       ],
       "let foo = / \\  /": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, r#"let foo = / \ {2}/"#),
         }
       ],
       "let foo = /  foo   /;": [
         {
           col: 10,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = / {2}foo {3}/;"),
         }
       ],
       "let foo = new RegExp('\\\\d  ')": [
@@ -1,10 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::StringRepr;
 use crate::tags::{self, Tags};
-use crate::Program;
 
 use deno_ast::view::AssignExpr;
 use deno_ast::view::AssignOp;
@@ -14,6 +13,7 @@ use deno_ast::view::ExprOrSpread;
 use deno_ast::view::Ident;
 use deno_ast::view::MemberExpr;
 use deno_ast::view::MemberProp;
+use deno_ast::view::NodeKind;
 use deno_ast::view::ObjectPatProp;
 use deno_ast::view::Pat;
 use deno_ast::view::Prop;
@@ -49,12 +49,12 @@ impl LintRule for NoSelfAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoSelfAssignVisitor.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows assigning a variable to itself.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoSelfAssignVisitor))
   }
 }
 
@@ -314,6 +314,10 @@ impl NoSelfAssignVisitor {
 }
 
 impl Handler for NoSelfAssignVisitor {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
+  }
+
   fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
     if assign_expr.op() == AssignOp::Assign {
       match &assign_expr.left {
@@ -1,7 +1,8 @@
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use if_chain::if_chain;
 
+use deno_ast::view::NodeKind;
 use deno_ast::{
   view::{BinaryOp, NodeTrait},
   SourceRanged,
@@ -25,18 +26,22 @@ impl LintRule for NoSelfCompare {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: deno_ast::view::Program<'view>,
-  ) {
-    NoSelfCompareHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows comparing a value with itself.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoSelfCompareHandler))
   }
 }
 
 struct NoSelfCompareHandler;
 
 impl Handler for NoSelfCompareHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
+  }
+
   fn bin_expr(
     &mut self,
     binary_expression: &deno_ast::view::BinExpr,
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::NodeTrait;
 use deno_ast::{view as ast_view, SourceRanged};
 
@@ -22,18 +22,22 @@ impl LintRule for NoSetterReturn {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoSetterReturnHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows returning values from setters.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoSetterReturnHandler))
   }
 }
 
 struct NoSetterReturnHandler;
 
 impl Handler for NoSetterReturnHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ReturnStmt])
+  }
+
   fn return_stmt(
     &mut self,
     return_stmt: &ast_view::ReturnStmt,
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   ArrowExpr, AssignExpr, AssignTarget, CatchClause, Expr, FnDecl, FnExpr,
   Ident, ObjectPatProp, Pat, SimpleAssignTarget, VarDecl,
@@ -31,12 +31,12 @@ impl LintRule for NoShadowRestrictedNames {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoShadowRestrictedNamesHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows shadowing names like `undefined` and `NaN`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoShadowRestrictedNamesHandler))
   }
 }
 
@@ -99,6 +99,17 @@ fn report_shadowing(ident: &Ident, ctx: &mut Context) {
 }
 
 impl Handler for NoShadowRestrictedNamesHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::VarDecl,
+      NodeKind::FnDecl,
+      NodeKind::FnExpr,
+      NodeKind::ArrowExpr,
+      NodeKind::CatchClause,
+      NodeKind::AssignExpr,
+    ])
+  }
+
   fn var_decl(&mut self, node: &VarDecl, ctx: &mut Context) {
     for decl in node.decls {
       if let Pat::Ident(ident) = &decl.name {
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::view::ArrayLit;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -23,18 +23,22 @@ impl LintRule for NoSparseArrays {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoSparseArraysHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows sparse arrays.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoSparseArraysHandler))
   }
 }
 
 struct NoSparseArraysHandler;
 
 impl Handler for NoSparseArraysHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ArrayLit])
+  }
+
   fn array_lit(&mut self, array_lit: &ArrayLit, ctx: &mut Context) {
     if array_lit.elems.iter().any(|e| e.is_none()) {
       ctx.add_diagnostic(
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::Tags;
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::NodeTrait;
 use deno_ast::{view as ast_view, SourceRanged};
 use if_chain::if_chain;
@@ -24,12 +24,12 @@ impl LintRule for NoSyncFnInAsyncFn {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoSyncFnInAsyncFnHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows blocking Deno APIs in async functions.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoSyncFnInAsyncFnHandler))
   }
 }
 
@@ -58,6 +58,10 @@ fn extract_symbol<'a>(
 struct NoSyncFnInAsyncFnHandler;
 
 impl Handler for NoSyncFnInAsyncFnHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr])
+  }
+
   fn member_expr(
     &mut self,
     member_expr: &ast_view::MemberExpr,
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{Expr, Pat, VarDecl};
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
@@ -23,18 +23,22 @@ impl LintRule for NoThisAlias {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoThisAliasHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows assigning `this` to a variable.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoThisAliasHandler))
   }
 }
 
 struct NoThisAliasHandler;
 
 impl Handler for NoThisAliasHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::VarDecl])
+  }
+
   fn var_decl(&mut self, var_decl: &VarDecl, ctx: &mut Context) {
     for decl in var_decl.decls {
       if_chain! {
@@ -3,7 +3,6 @@
 use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::view::NodeTrait;
 use deno_ast::{view as ast_view, SourceRange, SourceRanged};
 
@@ -23,13 +22,12 @@ impl LintRule for NoThisBeforeSuper {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    let mut handler = NoThisBeforeSuperHandler::new();
-    handler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `this` and `super` before `super()` in constructors.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoThisBeforeSuperHandler::new()))
   }
 }
 
@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{Expr, ThrowStmt};
 use deno_ast::SourceRanged;
 use derive_more::Display;
@@ -26,18 +26,22 @@ impl LintRule for NoThrowLiteral {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoThrowLiteralHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires throwing `Error` objects instead of literals.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoThrowLiteralHandler))
   }
 }
 
 struct NoThrowLiteralHandler;
 
 impl Handler for NoThrowLiteralHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ThrowStmt])
+  }
+
   fn throw_stmt(&mut self, throw_stmt: &ThrowStmt, ctx: &mut Context) {
     match throw_stmt.arg {
       Expr::Lit(_) => ctx.add_diagnostic(
@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::view::NodeTrait;
 use deno_ast::view::{self as ast_view};
 use deno_ast::SourceRanged;
@@ -19,18 +19,22 @@ impl LintRule for NoTopLevelAwait {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoTopLevelAwaitHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `await` at the top level of modules.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoTopLevelAwaitHandler))
   }
 }
 
 struct NoTopLevelAwaitHandler;
 
 impl Handler for NoTopLevelAwaitHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AwaitExpr, NodeKind::ForOfStmt])
+  }
+
   fn await_expr(
     &mut self,
     await_expr: &ast_view::AwaitExpr,
@@ -2,7 +2,6 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
-use crate::globals::GLOBALS;
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::{
@@ -19,6 +18,10 @@ impl LintRule for NoUndef {
     "no-undef"
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows the use of undeclared variables.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -62,7 +65,7 @@ impl<'c, 'view> NoUndefVisitor<'c, 'view> {
     }
 
     // Globals
-    if GLOBALS.iter().any(|(name, _)| name == &&*ident.sym) {
+    if self.context.lookup_global(&ident.sym).is_some() {
       return;
     }
 
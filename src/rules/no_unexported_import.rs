@@ -0,0 +1,341 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::ProjectLintRule;
+use crate::context::ProjectContext;
+use deno_ast::swc::ast::{
+  Decl, ExportSpecifier, ImportSpecifier, ModuleDecl, ModuleExportName,
+  ModuleItem, NamedExport, TsModuleName,
+};
+use deno_ast::swc::utils::find_pat_ids;
+use deno_ast::{ParsedSource, ProgramRef, SourceRange, SourceRangedForSpanned};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct NoUnexportedImport;
+
+const CODE: &str = "no-unexported-import";
+const HINT: &str =
+  "Check the name of the import or add the missing export to the module";
+
+impl ProjectLintRule for NoUnexportedImport {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows importing names that the imported module doesn't export.")
+  }
+
+  fn lint_project(&self, context: &mut ProjectContext) {
+    for parsed_source in context.parsed_sources() {
+      let ProgramRef::Module(module) = parsed_source.program_ref() else {
+        continue;
+      };
+
+      for item in &module.body {
+        let ModuleItem::ModuleDecl(module_decl) = item else {
+          continue;
+        };
+        let (src, names) = match module_decl {
+          ModuleDecl::Import(import) => (
+            &import.src,
+            import
+              .specifiers
+              .iter()
+              .filter_map(|specifier| match specifier {
+                ImportSpecifier::Named(named) => Some((
+                  named
+                    .imported
+                    .as_ref()
+                    .map(export_name)
+                    .unwrap_or_else(|| named.local.sym.to_string()),
+                  named.range(),
+                )),
+                ImportSpecifier::Default(default) => {
+                  Some(("default".to_string(), default.range()))
+                }
+                ImportSpecifier::Namespace(_) => None,
+              })
+              .collect::<Vec<_>>(),
+          ),
+          ModuleDecl::ExportNamed(NamedExport {
+            src: Some(src),
+            specifiers,
+            ..
+          }) => (
+            src,
+            specifiers
+              .iter()
+              .filter_map(|specifier| match specifier {
+                ExportSpecifier::Named(named) => {
+                  Some((export_name(&named.orig), named.range()))
+                }
+                ExportSpecifier::Default(default) => {
+                  Some(("default".to_string(), default.exported.range()))
+                }
+                ExportSpecifier::Namespace(_) => None,
+              })
+              .collect::<Vec<_>>(),
+          ),
+          _ => continue,
+        };
+        if names.is_empty() {
+          continue;
+        }
+
+        let Some(target) =
+          context.resolve(&src.value, parsed_source.specifier())
+        else {
+          continue;
+        };
+        let Some(exports) = collect_exports(target) else {
+          continue;
+        };
+
+        for (name, range) in names {
+          if !exports.contains(&name) {
+            report(context, parsed_source, range, &name, &src.value);
+          }
+        }
+      }
+    }
+  }
+}
+
+fn report(
+  context: &mut ProjectContext,
+  parsed_source: &ParsedSource,
+  range: SourceRange,
+  name: &str,
+  src: &str,
+) {
+  context.add_diagnostic_with_hint(
+    parsed_source,
+    range,
+    CODE,
+    format!("\"{}\" is not exported by \"{}\"", name, src),
+    Some(HINT.to_string()),
+  );
+}
+
+fn export_name(name: &ModuleExportName) -> String {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.to_string(),
+    ModuleExportName::Str(str) => str.value.to_string(),
+  }
+}
+
+/// Collects names exported by the module. Returns `None` if the exports can't
+/// be determined statically, e.g. when the module has `export *`.
+fn collect_exports(parsed_source: &ParsedSource) -> Option<HashSet<String>> {
+  let ProgramRef::Module(module) = parsed_source.program_ref() else {
+    return None;
+  };
+
+  let mut exports = HashSet::new();
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(module_decl) = item else {
+      continue;
+    };
+    match module_decl {
+      ModuleDecl::ExportDecl(export_decl) => match &export_decl.decl {
+        Decl::Class(class) => {
+          exports.insert(class.ident.sym.to_string());
+        }
+        Decl::Fn(func) => {
+          exports.insert(func.ident.sym.to_string());
+        }
+        Decl::Var(var) => {
+          for decl in &var.decls {
+            let ids: Vec<deno_ast::swc::ast::Id> = find_pat_ids(&decl.name);
+            exports.extend(ids.into_iter().map(|id| id.0.to_string()));
+          }
+        }
+        Decl::Using(using) => {
+          for decl in &using.decls {
+            let ids: Vec<deno_ast::swc::ast::Id> = find_pat_ids(&decl.name);
+            exports.extend(ids.into_iter().map(|id| id.0.to_string()));
+          }
+        }
+        Decl::TsInterface(interface) => {
+          exports.insert(interface.id.sym.to_string());
+        }
+        Decl::TsTypeAlias(alias) => {
+          exports.insert(alias.id.sym.to_string());
+        }
+        Decl::TsEnum(ts_enum) => {
+          exports.insert(ts_enum.id.sym.to_string());
+        }
+        Decl::TsModule(ts_module) => match &ts_module.id {
+          TsModuleName::Ident(ident) => {
+            exports.insert(ident.sym.to_string());
+          }
+          TsModuleName::Str(_) => {}
+        },
+      },
+      ModuleDecl::ExportNamed(named_export) => {
+        for specifier in &named_export.specifiers {
+          match specifier {
+            ExportSpecifier::Named(named) => {
+              exports.insert(export_name(
+                named.exported.as_ref().unwrap_or(&named.orig),
+              ));
+            }
+            ExportSpecifier::Default(default) => {
+              exports.insert(default.exported.sym.to_string());
+            }
+            ExportSpecifier::Namespace(namespace) => {
+              exports.insert(export_name(&namespace.name));
+            }
+          }
+        }
+      }
+      ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => {
+        exports.insert("default".to_string());
+      }
+      ModuleDecl::TsImportEquals(import_equals) if import_equals.is_export => {
+        exports.insert(import_equals.id.sym.to_string());
+      }
+      ModuleDecl::ExportAll(_) | ModuleDecl::TsExportAssignment(_) => {
+        return None;
+      }
+      _ => {}
+    }
+  }
+
+  Some(exports)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ast_parser::parse_program;
+  use crate::linter::{LintConfig, Linter, LinterOptions};
+  use deno_ast::{MediaType, ModuleSpecifier};
+  use std::collections::HashMap;
+
+  fn lint_project(
+    files: &[(&str, &str)],
+  ) -> HashMap<String, Vec<(usize, String)>> {
+    let parsed_sources = files
+      .iter()
+      .map(|(name, source)| {
+        let specifier =
+          ModuleSpecifier::parse(&format!("file:///{}", name)).unwrap();
+        parse_program(specifier, MediaType::TypeScript, source.to_string())
+          .unwrap()
+      })
+      .collect::<Vec<_>>();
+    let linter = Linter::new(LinterOptions {
+      rules: vec![],
+      project_rules: vec![Box::new(NoUnexportedImport)],
+      all_rule_codes: [CODE].into_iter().collect(),
+      custom_ignore_file_directive: None,
+      custom_ignore_diagnostic_directive: None,
+      ..Default::default()
+    });
+    let resolver = |specifier: &str, referrer: &ModuleSpecifier| {
+      referrer.join(specifier).ok()
+    };
+
+    linter
+      .lint_project(
+        &parsed_sources,
+        &resolver,
+        LintConfig {
+          default_jsx_factory: None,
+          default_jsx_fragment_factory: None,
+          globals: Default::default(),
+        },
+      )
+      .into_iter()
+      .map(|(specifier, diagnostics)| {
+        let diagnostics = diagnostics
+          .into_iter()
+          .map(|d| {
+            let range = d.range.unwrap();
+            let col = range
+              .text_info
+              .line_and_column_index(range.range.start)
+              .column_index;
+            (col, d.details.message)
+          })
+          .collect();
+        (
+          specifier.path().trim_start_matches('/').to_string(),
+          diagnostics,
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn no_unexported_import_valid() {
+    let diagnostics = lint_project(&[
+      (
+        "a.ts",
+        r#"
+export const a = 1, { b, c: [d] } = obj;
+export function f() {}
+export class C {}
+export interface I {}
+export type T = number;
+export enum E {}
+const g = 1;
+export { g, g as h };
+export default 42;
+export * as ns from "./c.ts";
+"#,
+      ),
+      (
+        "b.ts",
+        r#"
+import x, { a, b, d, f, C, I, T, E, g, h, ns } from "./a.ts";
+import * as all from "./a.ts";
+import { anything } from "./c.ts";
+import { y } from "https://deno.land/x/mod.ts";
+export { a, default as z } from "./a.ts";
+"#,
+      ),
+      ("c.ts", r#"export * from "./a.ts";"#),
+    ]);
+    for (file, diagnostics) in diagnostics {
+      assert!(diagnostics.is_empty(), "{}: {:?}", file, diagnostics);
+    }
+  }
+
+  #[test]
+  fn no_unexported_import_invalid() {
+    let diagnostics = lint_project(&[
+      ("a.ts", "export const a = 1;"),
+      (
+        "b.ts",
+        r#"import x, { a, b, a as c } from "./a.ts";
+export { d } from "./a.ts";
+// deno-lint-ignore no-unexported-import
+import { e } from "./a.ts";"#,
+      ),
+      (
+        "c.ts",
+        r#"// deno-lint-ignore no-unexported-import
+import { a } from "./a.ts";"#,
+      ),
+    ]);
+    assert!(diagnostics["a.ts"].is_empty());
+    assert_eq!(
+      diagnostics["c.ts"],
+      vec![(
+        0,
+        "Ignore for code \"no-unexported-import\" was not used.".to_string()
+      )]
+    );
+    assert_eq!(
+      diagnostics["b.ts"],
+      vec![
+        (7, "\"default\" is not exported by \"./a.ts\"".to_string()),
+        (15, "\"b\" is not exported by \"./a.ts\"".to_string()),
+        (9, "\"d\" is not exported by \"./a.ts\"".to_string()),
+      ]
+    );
+  }
+}
@@ -29,6 +29,10 @@ impl LintRule for NoUnreachable {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows unreachable code.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::NodeTrait;
 use deno_ast::{view as ast_view, SourceRange, SourceRanged};
 use derive_more::Display;
@@ -47,18 +47,27 @@ impl LintRule for NoUnsafeFinally {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoUnsafeFinallyHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows control flow statements in `finally` blocks.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoUnsafeFinallyHandler))
   }
 }
 
 struct NoUnsafeFinallyHandler;
 
 impl Handler for NoUnsafeFinallyHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::BreakStmt,
+      NodeKind::ContinueStmt,
+      NodeKind::ReturnStmt,
+      NodeKind::ThrowStmt,
+    ])
+  }
+
   fn break_stmt(
     &mut self,
     break_stmt: &ast_view::BreakStmt,
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
 use if_chain::if_chain;
@@ -30,18 +30,22 @@ impl LintRule for NoUnsafeNegation {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoUnsafeNegationHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows negating the left operand of `in` and `instanceof`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoUnsafeNegationHandler))
   }
 }
 
 struct NoUnsafeNegationHandler;
 
 impl Handler for NoUnsafeNegationHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
+  }
+
   fn bin_expr(&mut self, bin_expr: &ast_view::BinExpr, ctx: &mut Context) {
     use deno_ast::view::{BinaryOp, Expr, UnaryOp};
     if_chain! {
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
 use if_chain::if_chain;
@@ -28,13 +27,12 @@ impl LintRule for NoUnusedLabels {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    let mut handler = NoUnusedLabelsHandler::default();
-    handler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows unused labels.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoUnusedLabelsHandler::default()))
   }
 }
 
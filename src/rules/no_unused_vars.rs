@@ -64,6 +64,10 @@ impl LintRule for NoUnusedVars {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows unused variables.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
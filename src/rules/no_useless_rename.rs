@@ -1,13 +1,14 @@
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::Tags;
-use crate::Program;
 
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   ExportNamedSpecifier, ImportNamedSpecifier, ModuleExportName, ObjectPat,
   ObjectPatProp, Pat, PropName,
 };
-use deno_ast::SourceRanged;
+use deno_ast::{SourceRange, SourceRanged};
 
 #[derive(Debug)]
 pub struct NoUselessRename;
@@ -15,6 +16,7 @@ pub struct NoUselessRename;
 const MESSAGE: &str = "The original name is exactly the same as the new name.";
 const HINT: &str = "Remove the rename operation.";
 const CODE: &str = "no-useless-rename";
+const FIX_DESC: &str = HINT;
 
 impl LintRule for NoUselessRename {
   fn tags(&self) -> Tags {
@@ -25,18 +27,50 @@ impl LintRule for NoUselessRename {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoUselessRenameHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some(
+      "Disallows renaming imports, exports and destructured bindings to the same name.",
+    )
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoUselessRenameHandler))
   }
 }
 
 struct NoUselessRenameHandler;
 
+fn add_diagnostic(
+  ctx: &mut Context,
+  range: SourceRange,
+  rename_range: SourceRange,
+  name: &str,
+) {
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    MESSAGE,
+    Some(HINT.to_string()),
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      kind: LintFixKind::Safe,
+      changes: vec![LintFixChange {
+        new_text: name.to_string().into(),
+        range: rename_range,
+      }],
+    }],
+  );
+}
+
 impl Handler for NoUselessRenameHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::ImportNamedSpecifier,
+      NodeKind::ObjectPat,
+      NodeKind::ExportNamedSpecifier,
+    ])
+  }
+
   fn import_named_specifier(
     &mut self,
     node: &ImportNamedSpecifier,
@@ -44,7 +78,7 @@ impl Handler for NoUselessRenameHandler {
   ) {
     if let Some(ModuleExportName::Ident(imported_name)) = node.imported {
       if imported_name.sym() == node.local.sym() {
-        ctx.add_diagnostic_with_hint(node.range(), CODE, MESSAGE, HINT);
+        add_diagnostic(ctx, node.range(), node.range(), imported_name.sym());
       }
     }
   }
@@ -64,7 +98,7 @@ impl Handler for NoUselessRenameHandler {
       };
 
       if prop_value.id.sym() == prop_key.sym() {
-        ctx.add_diagnostic_with_hint(node.range(), CODE, MESSAGE, HINT);
+        add_diagnostic(ctx, node.range(), key_val.range(), prop_key.sym());
       }
     }
   }
@@ -87,7 +121,7 @@ impl Handler for NoUselessRenameHandler {
     };
 
     if exported_id.sym() == original.sym() {
-      ctx.add_diagnostic_with_hint(node.range(), CODE, MESSAGE, HINT);
+      add_diagnostic(ctx, node.range(), node.range(), original.sym());
     }
   }
 }
@@ -114,16 +148,25 @@ mod tests {
           col: 9,
           message: MESSAGE,
           hint: HINT,
+          fix: (FIX_DESC, r#"import { foo } from "foo";"#, LintFixKind::Safe),
         }],
         r#"const { foo: foo } = obj;"#: [{
           col: 6,
           message: MESSAGE,
           hint: HINT,
+          fix: (FIX_DESC, "const { foo } = obj;"),
+        }],
+        r#"const { bar: baz, foo: foo } = obj;"#: [{
+          col: 6,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const { bar: baz, foo } = obj;"),
         }],
         r#"export { foo as foo };"#: [{
           col: 9,
           message: MESSAGE,
           hint: HINT,
+          fix: (FIX_DESC, "export { foo };"),
         }]
     );
   }
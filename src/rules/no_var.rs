@@ -1,9 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::swc::parser::token::{Keyword, Token, Word};
+use deno_ast::view::NodeKind;
 use deno_ast::view::{NodeTrait, VarDecl, VarDeclKind};
 use deno_ast::SourceRangedForSpanned;
 
@@ -12,6 +14,7 @@ pub struct NoVar;
 
 const MESSAGE: &str = "`var` keyword is not allowed.";
 const CODE: &str = "no-var";
+const FIX_DESC: &str = "Replace `var` with `let`";
 
 impl LintRule for NoVar {
   fn tags(&self) -> Tags {
@@ -22,22 +25,46 @@ impl LintRule for NoVar {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoVarHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `let` or `const` instead of `var`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoVarHandler))
   }
 }
 
 struct NoVarHandler;
 
 impl Handler for NoVarHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::VarDecl])
+  }
+
   fn var_decl(&mut self, var_decl: &VarDecl, ctx: &mut Context) {
     if var_decl.decl_kind() == VarDeclKind::Var {
-      let range = var_decl.tokens().first().unwrap().range();
-      ctx.add_diagnostic(range, CODE, MESSAGE);
+      // Not the first token, which is `declare` in `declare var foo;`.
+      let range = var_decl
+        .tokens()
+        .iter()
+        .find(|t| t.token == Token::Word(Word::Keyword(Keyword::Var)))
+        .expect("there must be a var token")
+        .range();
+      // `let` isn't hoisted and is block scoped, so this may change behavior.
+      ctx.add_diagnostic_with_fixes(
+        range,
+        CODE,
+        MESSAGE,
+        None,
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          kind: LintFixKind::Unsafe,
+          changes: vec![LintFixChange {
+            new_text: "let".into(),
+            range,
+          }],
+        }],
+      );
     }
   }
 }
@@ -58,21 +85,30 @@ mod tests {
       "var foo = 0;": [{
         col: 0,
         message: MESSAGE,
+        fix: (FIX_DESC, "let foo = 0;", LintFixKind::Unsafe),
       }],
       "let foo = 0; var bar = 1;": [{
         col: 13,
         message: MESSAGE,
+        fix: (FIX_DESC, "let foo = 0; let bar = 1;"),
       }],
       "let foo = 0; var bar = 1; var x = 2;": [
         {
           col: 13,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = 0; let bar = 1; var x = 2;"),
         },
         {
           col: 26,
           message: MESSAGE,
+          fix: (FIX_DESC, "let foo = 0; var bar = 1; let x = 2;"),
         }
-      ]
+      ],
+      "declare var foo: string;": [{
+        col: 8,
+        message: MESSAGE,
+        fix: (FIX_DESC, "declare let foo: string;"),
+      }]
     );
   }
 }
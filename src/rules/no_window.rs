@@ -3,13 +3,14 @@ use super::Context;
 use super::LintRule;
 use crate::diagnostic::LintFix;
 use crate::diagnostic::LintFixChange;
+use crate::diagnostic::LintFixKind;
+use crate::globals::Environment;
 use crate::handler::Handler;
-use crate::handler::Traverse;
 use crate::tags;
 use crate::tags::Tags;
-use crate::Program;
 
 use deno_ast::view as ast_view;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
@@ -31,12 +32,12 @@ impl LintRule for NoWindow {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoWindowGlobalHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows the `window` global, which was removed in Deno 2.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoWindowGlobalHandler))
   }
 }
 
@@ -51,6 +52,7 @@ impl NoWindowGlobalHandler {
       Some(HINT.to_string()),
       vec![LintFix {
         description: FIX_DESC.into(),
+        kind: LintFixKind::Unsafe,
         changes: vec![LintFixChange {
           new_text: "globalThis".into(),
           range,
@@ -61,12 +63,17 @@ impl NoWindowGlobalHandler {
 }
 
 impl Handler for NoWindowGlobalHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr, NodeKind::ExprStmt])
+  }
+
   fn member_expr(&mut self, expr: &ast_view::MemberExpr, ctx: &mut Context) {
     use deno_ast::view::Expr;
     if_chain! {
       if let Expr::Ident(ident) = &expr.obj;
       if ident.sym() == "window";
       if ctx.scope().is_global(&ident.inner.to_id());
+      if !ctx.environments().contains(&Environment::Browser);
       then {
         self.add_diagnostic(ctx, ident.range());
       }
@@ -79,6 +86,7 @@ impl Handler for NoWindowGlobalHandler {
       if let Expr::Ident(ident) = &expr.expr;
       if ident.sym() == "window";
       if ctx.scope().is_global(&ident.inner.to_id());
+      if !ctx.environments().contains(&Environment::Browser);
       then {
         self.add_diagnostic(ctx, ident.range());
       }
@@ -121,7 +129,7 @@ mod tests {
       r#"window.fetch()"#: [
         {
           col: 0,
-          fix: (FIX_DESC, "globalThis.fetch()"),
+          fix: (FIX_DESC, "globalThis.fetch()", LintFixKind::Unsafe),
         }
       ],
       r#"window["fetch"]()"#: [
@@ -4,13 +4,14 @@ use super::Context;
 use super::LintRule;
 use crate::diagnostic::LintFix;
 use crate::diagnostic::LintFixChange;
+use crate::diagnostic::LintFixKind;
+use crate::globals::Environment;
 use crate::handler::Handler;
-use crate::handler::Traverse;
 use crate::tags;
 use crate::tags::Tags;
-use crate::Program;
 
 use deno_ast::view as ast_view;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
 use once_cell::sync::Lazy;
@@ -34,12 +35,12 @@ impl LintRule for NoWindowPrefix {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoWindowPrefixHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows accessing Web APIs through `window`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoWindowPrefixHandler))
   }
 }
 
@@ -229,6 +230,10 @@ fn extract_symbol<'a>(expr: &'a ast_view::MemberExpr) -> Option<&'a str> {
 struct NoWindowPrefixHandler;
 
 impl Handler for NoWindowPrefixHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr])
+  }
+
   fn member_expr(
     &mut self,
     member_expr: &ast_view::MemberExpr,
@@ -245,6 +250,7 @@ impl Handler for NoWindowPrefixHandler {
       let obj_symbol = obj_ident.sym();
       if obj_symbol == "window";
       if ctx.scope().is_global(&obj_ident.inner.to_id());
+      if !ctx.environments().contains(&Environment::Browser);
       if let Some(prop_symbol) = extract_symbol(member_expr);
       if PROPERTY_DENY_LIST.contains(prop_symbol);
       then {
@@ -255,6 +261,7 @@ impl Handler for NoWindowPrefixHandler {
           Some(HINT.into()),
           vec![LintFix {
             description: FIX_DESC.into(),
+            kind: LintFixKind::Unsafe,
             changes: vec![LintFixChange {
               new_text: "globalThis".into(),
               range: obj_ident.range(),
@@ -393,7 +400,7 @@ mod tests {
       r#"window.fetch()"#: [
         {
           col: 0,
-          fix: (FIX_DESC, "globalThis.fetch()"),
+          fix: (FIX_DESC, "globalThis.fetch()", LintFixKind::Unsafe),
         }
       ],
       r#"window["fetch"]()"#: [
@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::view as ast_view;
+use deno_ast::view::NodeKind;
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -22,18 +22,22 @@ impl LintRule for NoWith {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoWithHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows `with` statements.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(NoWithHandler))
   }
 }
 
 struct NoWithHandler;
 
 impl Handler for NoWithHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::WithStmt])
+  }
+
   fn with_stmt(&mut self, with_stmt: &ast_view::WithStmt, ctx: &mut Context) {
     ctx.add_diagnostic(with_stmt.range(), CODE, MESSAGE);
   }
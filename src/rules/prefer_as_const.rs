@@ -1,9 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{
   ArrayPat, BindingIdent, Expr, Lit, ObjectPat, Pat, TsAsExpr, TsLit, TsType,
   TsTypeAnn, TsTypeAssertion, VarDecl,
@@ -12,6 +13,7 @@ use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
 
 const CODE: &str = "prefer-as-const";
+const FIX_DESC: &str = "Replace the literal type with `as const`";
 
 #[derive(Display)]
 enum PreferAsConstMessage {
@@ -39,73 +41,89 @@ impl LintRule for PreferAsConst {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    PreferAsConstHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `as const` instead of repeating literal types.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(PreferAsConstHandler))
   }
 }
 
 struct PreferAsConstHandler;
 
-fn add_diagnostic_helper(range: SourceRange, ctx: &mut Context) {
-  ctx.add_diagnostic_with_hint(
+fn add_diagnostic_helper(
+  range: SourceRange,
+  kind: LintFixKind,
+  changes: Vec<LintFixChange>,
+  ctx: &mut Context,
+) {
+  ctx.add_diagnostic_with_fixes(
     range,
     CODE,
     PreferAsConstMessage::ExpectedConstAssertion,
-    PreferAsConstHint::AddAsConst,
+    Some(PreferAsConstHint::AddAsConst.to_string()),
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      kind,
+      changes,
+    }],
   );
 }
 
-fn compare(
-  type_ann: &TsType,
-  expr: &Expr,
-  range: SourceRange,
-  ctx: &mut Context,
-) {
+/// Whether the type is the literal type of the expression.
+fn is_literal_type_of(type_ann: &TsType, expr: &Expr) -> bool {
   if let TsType::TsLitType(lit_type) = type_ann {
     if let Expr::Lit(expr_lit) = expr {
       match (expr_lit, &lit_type.lit) {
         (Lit::Str(value_literal), TsLit::Str(type_literal)) => {
-          if value_literal.value() == type_literal.value() {
-            add_diagnostic_helper(range, ctx)
-          }
+          return value_literal.value() == type_literal.value();
         }
         (Lit::Num(value_literal), TsLit::Number(type_literal)) => {
-          if (value_literal.value() - type_literal.value()).abs() < f64::EPSILON
-          {
-            add_diagnostic_helper(range, ctx)
-          }
+          return (value_literal.value() - type_literal.value()).abs()
+            < f64::EPSILON;
         }
         _ => {}
       }
     }
   }
+  false
 }
 
-impl Handler for PreferAsConstHandler {
-  fn ts_as_expr(&mut self, as_expr: &TsAsExpr, ctx: &mut Context) {
-    compare(
-      &as_expr.type_ann,
-      &as_expr.expr,
-      as_expr.type_ann.range(),
+/// Reports an assertion to a literal type, which is replaced with `const`.
+fn check_assertion(type_ann: &TsType, expr: &Expr, ctx: &mut Context) {
+  if is_literal_type_of(type_ann, expr) {
+    add_diagnostic_helper(
+      type_ann.range(),
+      LintFixKind::Safe,
+      vec![LintFixChange {
+        new_text: "const".into(),
+        range: type_ann.range(),
+      }],
       ctx,
     );
   }
+}
+
+impl Handler for PreferAsConstHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::TsAsExpr,
+      NodeKind::TsTypeAssertion,
+      NodeKind::VarDecl,
+    ])
+  }
+
+  fn ts_as_expr(&mut self, as_expr: &TsAsExpr, ctx: &mut Context) {
+    check_assertion(&as_expr.type_ann, &as_expr.expr, ctx);
+  }
 
   fn ts_type_assertion(
     &mut self,
     type_assertion: &TsTypeAssertion,
     ctx: &mut Context,
   ) {
-    compare(
-      &type_assertion.type_ann,
-      &type_assertion.expr,
-      type_assertion.type_ann.range(),
-      ctx,
-    );
+    check_assertion(&type_assertion.type_ann, &type_assertion.expr, ctx);
   }
 
   fn var_decl(&mut self, var_decl: &VarDecl, ctx: &mut Context) {
@@ -115,8 +133,25 @@ impl Handler for PreferAsConstHandler {
         | Pat::Object(ObjectPat { type_ann, .. })
         | Pat::Ident(BindingIdent { type_ann, .. }) = &decl.name
         {
-          if let Some(TsTypeAnn { type_ann, .. }) = &type_ann {
-            compare(type_ann, init, type_ann.range(), ctx);
+          if let Some(ann @ TsTypeAnn { type_ann, .. }) = &type_ann {
+            if is_literal_type_of(type_ann, init) {
+              // The annotation is moved, so this is left as a suggestion.
+              add_diagnostic_helper(
+                type_ann.range(),
+                LintFixKind::Suggestion,
+                vec![
+                  LintFixChange {
+                    new_text: "".into(),
+                    range: ann.range(),
+                  },
+                  LintFixChange {
+                    new_text: " as const".into(),
+                    range: init.range().end().range(),
+                  },
+                ],
+                ctx,
+              );
+            }
           }
         }
       }
@@ -170,6 +205,7 @@ mod tests {
           col: 26,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = { bar: 'baz' as const };", LintFixKind::Safe),
         }
       ],
       "let foo = { bar: 1 as 1 };": [
@@ -177,6 +213,7 @@ mod tests {
           col: 22,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = { bar: 1 as const };"),
         }
       ],
       "let [x]: 'bar' = 'bar';": [
@@ -184,6 +221,7 @@ mod tests {
           col: 9,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let [x] = 'bar' as const;", LintFixKind::Suggestion),
         }
       ],
       "let {x}: 'bar' = 'bar';": [
@@ -191,6 +229,7 @@ mod tests {
           col: 9,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let {x} = 'bar' as const;"),
         }
       ],
       "let foo: 'bar' = 'bar';": [
@@ -198,6 +237,7 @@ mod tests {
           col: 9,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = 'bar' as const;"),
         }
       ],
       "let foo: 2 = 2;": [
@@ -205,6 +245,7 @@ mod tests {
           col: 9,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = 2 as const;"),
         }
       ],
       "let foo: 'bar' = 'bar' as 'bar';": [
@@ -212,6 +253,7 @@ mod tests {
           col: 26,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo: 'bar' = 'bar' as const;"),
         }
       ],
       "let foo = <'bar'>'bar';": [
@@ -219,6 +261,7 @@ mod tests {
           col: 11,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = <const>'bar';"),
         }
       ],
       "let foo = <4>4;": [
@@ -226,6 +269,7 @@ mod tests {
           col: 11,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = <const>4;"),
         }
       ],
       "let foo = 'bar' as 'bar';": [
@@ -233,6 +277,7 @@ mod tests {
           col: 19,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = 'bar' as const;"),
         }
       ],
       "let foo = 5 as 5;": [
@@ -240,6 +285,7 @@ mod tests {
           col: 15,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = 5 as const;"),
         }
       ],
       "let foo: 1.23456 = 1.23456;": [
@@ -247,6 +293,7 @@ mod tests {
           col: 9,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = 1.23456 as const;"),
         }
       ],
       "let foo: 2 = 2, bar: 3 = 3;": [
@@ -254,11 +301,13 @@ mod tests {
           col: 9,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = 2 as const, bar: 3 = 3;"),
         },
         {
           col: 21,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo: 2 = 2, bar = 3 as const;"),
         }
       ],
 
@@ -268,6 +317,7 @@ mod tests {
           col: 25,
           message: PreferAsConstMessage::ExpectedConstAssertion,
           hint: PreferAsConstHint::AddAsConst,
+          fix: (FIX_DESC, "let foo = () => { let x = 'x' as const; };"),
         }
       ],
     };
@@ -22,6 +22,10 @@ impl LintRule for PreferAscii {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires source code to be ASCII.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -2,6 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
 use crate::tags::{self, Tags};
 use crate::Program;
 use crate::ProgramRef;
@@ -22,7 +23,7 @@ use deno_ast::SourceRangedForSpanned;
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::iter;
 use std::mem;
 use std::rc::Rc;
@@ -44,6 +45,8 @@ enum PreferConstHint {
   UseConst,
 }
 
+const FIX_DESC: &str = "Replace `let` with `const`";
+
 impl LintRule for PreferConst {
   fn tags(&self) -> Tags {
     &[tags::RECOMMENDED]
@@ -53,6 +56,10 @@ impl LintRule for PreferConst {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `const` for variables that are never reassigned.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -69,6 +76,7 @@ impl LintRule for PreferConst {
       context,
       mem::take(&mut collector.scopes),
       mem::take(&mut collector.var_groups),
+      mem::take(&mut collector.let_decls),
     );
     match program {
       ProgramRef::Module(m) => visitor.visit_module(m),
@@ -251,6 +259,16 @@ impl DisjointSet {
   }
 }
 
+/// A `let` declaration whose variables are all initialized, so it can be
+/// replaced with `const` if none of them is reassigned.
+#[derive(Debug)]
+struct LetDecl {
+  /// the range of the `let` keyword
+  keyword_range: SourceRange,
+  /// the ranges of the declared variables
+  idents: Vec<SourceRange>,
+}
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
 enum ScopeRange {
   Global,
@@ -262,6 +280,7 @@ struct VariableCollector {
   scopes: BTreeMap<ScopeRange, Scope>,
   cur_scope: ScopeRange,
   var_groups: DisjointSet,
+  let_decls: Vec<LetDecl>,
 }
 
 impl VariableCollector {
@@ -270,9 +289,30 @@ impl VariableCollector {
       scopes: BTreeMap::new(),
       cur_scope: ScopeRange::Global,
       var_groups: DisjointSet::new(),
+      let_decls: Vec::new(),
     }
   }
 
+  /// Records a `let` declaration, unless some of its variables aren't
+  /// initialized. Variables declared in the head of `for-in` and `for-of`
+  /// are always initialized.
+  fn add_let_decl(&mut self, var_decl: &VarDecl, is_for_in_of_head: bool) {
+    if var_decl.declare
+      || !(is_for_in_of_head || var_decl.decls.iter().all(|d| d.init.is_some()))
+    {
+      return;
+    }
+    let start = var_decl.start();
+    let mut idents = Vec::new();
+    for decl in &var_decl.decls {
+      extract_idents_from_pat(&mut idents, &decl.name);
+    }
+    self.let_decls.push(LetDecl {
+      keyword_range: SourceRange::new(start, start + "let".len()),
+      idents: idents.into_iter().map(|i| i.range()).collect(),
+    });
+  }
+
   fn insert_var(&mut self, ident: &Ident, status: VarStatus) {
     self.var_groups.add_root(ident.range(), status);
     let mut scope = self.scopes.get(&self.cur_scope).unwrap().borrow_mut();
@@ -405,6 +445,7 @@ impl Visit for VariableCollector {
               VarStatus::Declared
             };
             a.insert_vars(&idents, status);
+            a.add_let_decl(var_decl, false);
           }
         }
         Some(VarDeclOrExpr::Expr(expr)) => {
@@ -435,6 +476,7 @@ impl Visit for VariableCollector {
           for decl in &var_decl.decls {
             a.extract_decl_idents(&decl.name, true);
           }
+          a.add_let_decl(var_decl, true);
         }
       }
 
@@ -455,6 +497,7 @@ impl Visit for VariableCollector {
           for decl in &var_decl.decls {
             a.extract_decl_idents(&decl.name, true);
           }
+          a.add_let_decl(var_decl, true);
         }
       }
 
@@ -598,6 +641,7 @@ impl Visit for VariableCollector {
       for decl in &var_decl.decls {
         self.extract_decl_idents(&decl.name, decl.init.is_some());
       }
+      self.add_let_decl(var_decl, false);
     }
   }
 }
@@ -606,6 +650,7 @@ struct PreferConstVisitor<'c, 'view> {
   scopes: BTreeMap<ScopeRange, Scope>,
   cur_scope: ScopeRange,
   var_groups: DisjointSet,
+  let_decls: Vec<LetDecl>,
   context: &'c mut Context<'view>,
   scope_analysis_error_occurred: bool,
 }
@@ -704,26 +749,59 @@ impl<'c, 'view> PreferConstVisitor<'c, 'view> {
     context: &'c mut Context<'view>,
     scopes: BTreeMap<ScopeRange, Scope>,
     var_groups: DisjointSet,
+    let_decls: Vec<LetDecl>,
   ) -> Self {
     Self {
       context,
       scopes,
       var_groups,
+      let_decls,
       cur_scope: ScopeRange::Global,
       scope_analysis_error_occurred: false,
     }
   }
 
-  fn report(&mut self, range: SourceRange) {
+  fn report(&mut self, range: SourceRange, fixes: Vec<LintFix>) {
     let range_text = range.text_fast(self.context.text_info()).to_string();
-    self.context.add_diagnostic_with_hint(
+    self.context.add_diagnostic_with_fixes(
       range,
       CODE,
       PreferConstMessage::NeverReassigned(range_text),
-      PreferConstHint::UseConst,
+      Some(PreferConstHint::UseConst.to_string()),
+      fixes,
     );
   }
 
+  /// Reports the variables that are never reassigned. The fix replacing
+  /// `let` with `const` is attached to the first variable of a declaration,
+  /// if none of its variables is reassigned.
+  fn report_all(&mut self) {
+    let ranges = self.var_groups.dump();
+    let reported: HashSet<SourceRange> = ranges.iter().copied().collect();
+    let mut fixes: HashMap<SourceRange, LintFix> = HashMap::new();
+    for let_decl in &self.let_decls {
+      if let Some(first) = let_decl.idents.first() {
+        if let_decl.idents.iter().all(|i| reported.contains(i)) {
+          fixes.insert(
+            *first,
+            LintFix {
+              description: FIX_DESC.into(),
+              kind: LintFixKind::Safe,
+              changes: vec![LintFixChange {
+                new_text: "const".into(),
+                range: let_decl.keyword_range,
+              }],
+            },
+          );
+        }
+      }
+    }
+    for range in ranges {
+      let fixes = fixes.remove(&range).into_iter().collect();
+      self.report(range, fixes);
+    }
+  }
+
   fn with_child_scope<F, S>(&mut self, node: &S, op: F)
   where
     S: SourceRangedForSpanned,
@@ -801,17 +879,13 @@ impl<'c, 'view> Visit for PreferConstVisitor<'c, 'view> {
   fn visit_module(&mut self, module: &Module) {
     module.visit_children_with(self);
     // After visiting all nodes, reports errors.
-    for range in self.var_groups.dump() {
-      self.report(range);
-    }
+    self.report_all();
   }
 
   fn visit_script(&mut self, script: &Script) {
     script.visit_children_with(self);
     // After visiting all nodes, reports errors.
-    for range in self.var_groups.dump() {
-      self.report(range);
-    }
+    self.report_all();
   }
 
   fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
@@ -2009,6 +2083,7 @@ mod prefer_const_tests {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const x = 1;", LintFixKind::Safe),
         }
       ],
       r#"let x = 1; foo(x);"#: [
@@ -2016,6 +2091,7 @@ mod prefer_const_tests {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const x = 1; foo(x);"),
         }
       ],
       r#"for (let i in [1,2,3]) { foo(i); }"#: [
@@ -2023,6 +2099,7 @@ mod prefer_const_tests {
           col: 9,
           message: variant!(PreferConstMessage, NeverReassigned, "i"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "for (const i in [1,2,3]) { foo(i); }"),
         }
       ],
       r#"for (let x of [1,2,3]) { foo(x); }"#: [
@@ -2030,6 +2107,7 @@ mod prefer_const_tests {
           col: 9,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "for (const x of [1,2,3]) { foo(x); }"),
         }
       ],
       r#"(function() { let x = 1; foo(x); })();"#: [
@@ -2037,6 +2115,7 @@ mod prefer_const_tests {
           col: 18,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "(function() { const x = 1; foo(x); })();"),
         }
       ],
       r#"(function() { for (let i in [1,2,3]) { foo(i); } })();"#: [
@@ -2044,6 +2123,7 @@ mod prefer_const_tests {
           col: 23,
           message: variant!(PreferConstMessage, NeverReassigned, "i"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "(function() { for (const i in [1,2,3]) { foo(i); } })();"),
         }
       ],
       r#"(function() { for (let x of [1,2,3]) { foo(x); } })();"#: [
@@ -2051,6 +2131,7 @@ mod prefer_const_tests {
           col: 23,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "(function() { for (const x of [1,2,3]) { foo(x); } })();"),
         }
       ],
       r#"let f = (function() { let g = x; })(); f = 1;"#: [
@@ -2058,6 +2139,7 @@ mod prefer_const_tests {
           col: 26,
           message: variant!(PreferConstMessage, NeverReassigned, "g"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "let f = (function() { const g = x; })(); f = 1;"),
         }
       ],
       r#"let x = 0; { let x = 1; foo(x); } x = 0;"#: [
@@ -2065,6 +2147,7 @@ mod prefer_const_tests {
           col: 17,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "let x = 0; { const x = 1; foo(x); } x = 0;"),
         }
       ],
       r#"for (let i = 0; i < 10; ++i) { let x = 1; foo(x); }"#: [
@@ -2072,6 +2155,7 @@ mod prefer_const_tests {
           col: 35,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "for (let i = 0; i < 10; ++i) { const x = 1; foo(x); }"),
         }
       ],
       r#"for (let i in [1,2,3]) { let x = 1; foo(x); }"#: [
@@ -2079,11 +2163,13 @@ mod prefer_const_tests {
           col: 9,
           message: variant!(PreferConstMessage, NeverReassigned, "i"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "for (const i in [1,2,3]) { let x = 1; foo(x); }"),
         },
         {
           col: 29,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "for (let i in [1,2,3]) { const x = 1; foo(x); }"),
         }
       ],
       r#"
@@ -2142,6 +2228,7 @@ var foo = function() {
           col: 5,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const [a] = [1]"),
         }
       ],
       r#"let {a} = obj"#: [
@@ -2149,6 +2236,7 @@ var foo = function() {
           col: 5,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const {a} = obj"),
         }
       ],
       r#"let {a = 0, b} = obj, c = a; b = a;"#: [
@@ -2170,6 +2258,7 @@ var foo = function() {
           col: 27,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "/*eslint use-x:error*/ const x = 1"),
         }
       ],
       r#"/*eslint use-x:error*/ { let x = 1 }"#: [
@@ -2177,6 +2266,7 @@ var foo = function() {
           col: 29,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "/*eslint use-x:error*/ { const x = 1 }"),
         }
       ],
       r#"let { foo, bar } = baz;"#: [
@@ -2184,6 +2274,7 @@ var foo = function() {
           col: 6,
           message: variant!(PreferConstMessage, NeverReassigned, "foo"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const { foo, bar } = baz;"),
         },
         {
           col: 11,
@@ -2196,6 +2287,7 @@ var foo = function() {
           col: 23,
           message: variant!(PreferConstMessage, NeverReassigned, "y"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const x = [1,2]; const [,y] = x;"),
         }
       ],
       r#"const x = [1,2,3]; let [y,,z] = x;"#: [
@@ -2203,6 +2295,7 @@ var foo = function() {
           col: 24,
           message: variant!(PreferConstMessage, NeverReassigned, "y"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const x = [1,2,3]; const [y,,z] = x;"),
         },
         {
           col: 27,
@@ -2236,6 +2329,7 @@ var foo = function() {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const x = 'x', y = 'y';"),
         },
         {
           col: 13,
@@ -2255,6 +2349,7 @@ var foo = function() {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const x = 1, y = 'y'; let z = 1;"),
         },
         {
           col: 11,
@@ -2265,6 +2360,7 @@ var foo = function() {
           col: 24,
           message: variant!(PreferConstMessage, NeverReassigned, "z"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "let x = 1, y = 'y'; const z = 1;"),
         }
       ],
       r#"let { a, b, c } = obj; let { x, y, z } = anotherObj; x = 2;"#: [
@@ -2272,6 +2368,7 @@ var foo = function() {
           col: 6,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const { a, b, c } = obj; let { x, y, z } = anotherObj; x = 2;"),
         },
         {
           col: 9,
@@ -2289,6 +2386,7 @@ var foo = function() {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "x"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const x = 'x', y = 'y'; function someFunc() { let a = 1, b = 2; foo(a, b) }"),
         },
         {
           col: 13,
@@ -2299,6 +2397,7 @@ var foo = function() {
           col: 48,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "let x = 'x', y = 'y'; function someFunc() { const a = 1, b = 2; foo(a, b) }"),
         },
         {
           col: 55,
@@ -2311,11 +2410,13 @@ var foo = function() {
           col: 4,
           message: variant!(PreferConstMessage, NeverReassigned, "someFunc"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "const someFunc = () => { let a = 1, b = 2; foo(a, b) }"),
         },
         {
           col: 27,
           message: variant!(PreferConstMessage, NeverReassigned, "a"),
           hint: PreferConstHint::UseConst,
+          fix: (FIX_DESC, "let someFunc = () => { const a = 1, b = 2; foo(a, b) }"),
         },
         {
           col: 34,
@@ -1,11 +1,12 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::{TsModuleDecl, TsModuleName};
-use deno_ast::SourceRanged;
+use deno_ast::{SourceRange, SourceRanged};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
@@ -14,6 +15,7 @@ pub struct PreferNamespaceKeyword;
 
 const CODE: &str = "prefer-namespace-keyword";
 const MESSAGE: &str = "`module` keyword in module declaration is not allowed";
+const FIX_DESC: &str = "Replace `module` with `namespace`";
 
 impl LintRule for PreferNamespaceKeyword {
   fn tags(&self) -> Tags {
@@ -24,18 +26,22 @@ impl LintRule for PreferNamespaceKeyword {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    PreferNamespaceKeywordHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `namespace` instead of `module` for namespaces.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(PreferNamespaceKeywordHandler))
   }
 }
 
 struct PreferNamespaceKeywordHandler;
 
 impl Handler for PreferNamespaceKeywordHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsModuleDecl])
+  }
+
   fn ts_module_decl(&mut self, mod_decl: &TsModuleDecl, ctx: &mut Context) {
     if let TsModuleName::Str(_) = &mod_decl.id {
       return;
@@ -45,9 +51,23 @@ impl Handler for PreferNamespaceKeywordHandler {
 
     let snippet = mod_decl.text_fast(ctx.text_info());
     if let Some(capt) = KEYWORD.captures(snippet) {
-      let keyword = capt.name("keyword").unwrap().as_str();
-      if keyword == "module" && !mod_decl.global() {
-        ctx.add_diagnostic(mod_decl.range(), CODE, MESSAGE)
+      let keyword = capt.name("keyword").unwrap();
+      if keyword.as_str() == "module" && !mod_decl.global() {
+        let start = mod_decl.start() + keyword.start();
+        ctx.add_diagnostic_with_fixes(
+          mod_decl.range(),
+          CODE,
+          MESSAGE,
+          None,
+          vec![LintFix {
+            description: FIX_DESC.into(),
+            kind: LintFixKind::Safe,
+            changes: vec![LintFixChange {
+              new_text: "namespace".into(),
+              range: SourceRange::new(start, start + keyword.len()),
+            }],
+          }],
+        );
       }
     }
   }
@@ -73,11 +93,36 @@ mod tests {
   fn prefer_namespace_keyword_invalid() {
     assert_lint_err! {
       PreferNamespaceKeyword,
-      r#"module foo {}"#: [{ col: 0, message: MESSAGE }],
+      r#"module foo {}"#: [
+        {
+          col: 0,
+          message: MESSAGE,
+          fix: (FIX_DESC, "namespace foo {}", LintFixKind::Safe),
+        }
+      ],
       r#"
       declare module foo {
         declare module bar {}
-      }"#: [{ line: 2, col: 6, message: MESSAGE}, { line: 3, col: 8, message: MESSAGE }],
+      }"#: [
+        {
+          line: 2,
+          col: 6,
+          message: MESSAGE,
+          fix: (FIX_DESC, r#"
+      declare namespace foo {
+        declare module bar {}
+      }"#),
+        },
+        {
+          line: 3,
+          col: 8,
+          message: MESSAGE,
+          fix: (FIX_DESC, r#"
+      declare module foo {
+        declare namespace bar {}
+      }"#),
+        }
+      ],
     }
   }
 }
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::Tags;
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::view::NodeTrait;
 use deno_ast::Scope;
 use deno_ast::{view as ast_view, SourceRanged};
@@ -71,12 +71,12 @@ impl LintRule for PreferPrimordials {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    PreferPrimordialsHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires primordials instead of global intrinsics.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(PreferPrimordialsHandler))
   }
 }
 
@@ -360,6 +360,20 @@ fn is_null_proto(object_lit: &ast_view::ObjectLit) -> bool {
 struct PreferPrimordialsHandler;
 
 impl Handler for PreferPrimordialsHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::Ident,
+      NodeKind::MemberExpr,
+      NodeKind::ObjectLit,
+      NodeKind::ExprOrSpread,
+      NodeKind::ForOfStmt,
+      NodeKind::YieldExpr,
+      NodeKind::ArrayPat,
+      NodeKind::Regex,
+      NodeKind::BinExpr,
+    ])
+  }
+
   fn ident(&mut self, ident: &ast_view::Ident, ctx: &mut Context) {
     fn inside_var_decl_lhs_or_member_expr_or_prop_or_type_ref(
       orig: ast_view::Node,
@@ -6,7 +6,8 @@ use crate::swc_util::StringRepr;
 use crate::tags::{self, Tags};
 
 use deno_ast::swc::parser::token::{Token, Word};
-use deno_ast::view::{NodeTrait, Program};
+use deno_ast::view::NodeKind;
+use deno_ast::view::NodeTrait;
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
 use deno_ast::SourceRangedForSpanned;
@@ -60,12 +61,12 @@ impl LintRule for RequireAwait {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    RequireAwaitHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows async functions without `await`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(RequireAwaitHandler))
   }
 }
 
@@ -127,6 +128,17 @@ fn find_async_token_range(
 struct RequireAwaitHandler;
 
 impl Handler for RequireAwaitHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::FnDecl,
+      NodeKind::FnExpr,
+      NodeKind::ArrowExpr,
+      NodeKind::MethodProp,
+      NodeKind::ClassMethod,
+      NodeKind::PrivateMethod,
+    ])
+  }
+
   fn fn_decl(&mut self, fn_decl: &deno_ast::view::FnDecl, ctx: &mut Context) {
     let function_info = FunctionInfo {
       kind: FunctionKind::Function(Some(
@@ -31,6 +31,10 @@ impl LintRule for RequireYield {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows generator functions without `yield`.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
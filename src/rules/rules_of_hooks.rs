@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::tags::{self, Tags};
-use crate::Program;
 use deno_ast::view::{CallExpr, Callee, Expr, Node, Pat};
 use deno_ast::SourceRanged;
 
@@ -21,13 +20,12 @@ impl LintRule for RulesOfHooks {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    let mut handler = RulesOfHooksHandler::new();
-    handler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Enforces the rules of React hooks.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(RulesOfHooksHandler::new()))
   }
 }
 
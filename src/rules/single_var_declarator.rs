@@ -2,18 +2,20 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
 use crate::Program;
 use crate::ProgramRef;
-use deno_ast::swc::ast::VarDecl;
+use deno_ast::swc::ast::{Decl, ExportDecl, ModuleItem, Stmt, VarDecl};
 use deno_ast::swc::visit::noop_visit_type;
-use deno_ast::swc::visit::Visit;
-use deno_ast::SourceRangedForSpanned;
+use deno_ast::swc::visit::{Visit, VisitWith};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
 use derive_more::Display;
 
 #[derive(Debug)]
 pub struct SingleVarDeclarator;
 
 const CODE: &str = "single-var-declarator";
+const FIX_DESC: &str = "Split into one declaration per variable";
 
 #[derive(Display)]
 enum SingleVarDeclaratorMessage {
@@ -26,6 +28,10 @@ impl LintRule for SingleVarDeclarator {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows declaring several variables in one declaration.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -48,20 +54,99 @@ impl<'c, 'view> SingleVarDeclaratorVisitor<'c, 'view> {
   fn new(context: &'c mut Context<'view>) -> Self {
     Self { context }
   }
+
+  /// `stmt_range` is the range of the statement declaring the variables,
+  /// when the declaration can be split into several statements.
+  fn check(&mut self, var_decl: &VarDecl, stmt_range: Option<SourceRange>) {
+    if var_decl.decls.len() <= 1 {
+      return;
+    }
+    let fixes = stmt_range
+      .map(|stmt_range| self.split_fix(var_decl, stmt_range))
+      .into_iter()
+      .collect();
+    self.context.add_diagnostic_with_fixes(
+      var_decl.range(),
+      CODE,
+      SingleVarDeclaratorMessage::Unexpected,
+      None,
+      fixes,
+    );
+  }
+
+  fn split_fix(&self, var_decl: &VarDecl, stmt_range: SourceRange) -> LintFix {
+    let text_info = self.context.text_info();
+    let first_decl = var_decl.decls.first().unwrap().range();
+    let last_decl = var_decl.decls.last().unwrap().range();
+    // Keywords like `export const `, repeated for each declaration.
+    let prefix =
+      SourceRange::new(stmt_range.start, first_decl.start).text_fast(text_info);
+    let line_start =
+      text_info.line_start(text_info.line_index(stmt_range.start));
+    let indent =
+      SourceRange::new(line_start, stmt_range.start).text_fast(text_info);
+    let separator = if indent.trim().is_empty() {
+      format!(";\n{}", indent)
+    } else {
+      "; ".to_string()
+    };
+    let new_text = var_decl
+      .decls
+      .iter()
+      .map(|decl| format!("{}{}", prefix, decl.range().text_fast(text_info)))
+      .collect::<Vec<_>>()
+      .join(&separator);
+    LintFix {
+      description: FIX_DESC.into(),
+      kind: LintFixKind::Safe,
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range: SourceRange::new(stmt_range.start, last_decl.end),
+      }],
+    }
+  }
+
+  /// Visits a statement of a block, a module or a script, where a
+  /// declaration can be split into several statements. It can't be split
+  /// when it's the body of a statement, e.g. `if (x) var a, b;`.
+  fn visit_list_stmt(&mut self, stmt: &Stmt) {
+    match stmt {
+      Stmt::Decl(Decl::Var(var_decl)) => {
+        self.check(var_decl, Some(var_decl.range()))
+      }
+      _ => stmt.visit_with(self),
+    }
+  }
 }
 
 impl<'c, 'view> Visit for SingleVarDeclaratorVisitor<'c, 'view> {
   noop_visit_type!();
 
-  fn visit_var_decl(&mut self, var_decl: &VarDecl) {
-    if var_decl.decls.len() > 1 {
-      self.context.add_diagnostic(
-        var_decl.range(),
-        CODE,
-        SingleVarDeclaratorMessage::Unexpected,
-      );
+  fn visit_stmts(&mut self, stmts: &[Stmt]) {
+    for stmt in stmts {
+      self.visit_list_stmt(stmt);
+    }
+  }
+
+  fn visit_module_items(&mut self, items: &[ModuleItem]) {
+    for item in items {
+      match item {
+        ModuleItem::Stmt(stmt) => self.visit_list_stmt(stmt),
+        ModuleItem::ModuleDecl(_) => item.visit_with(self),
+      }
     }
   }
+
+  fn visit_export_decl(&mut self, export_decl: &ExportDecl) {
+    match &export_decl.decl {
+      Decl::Var(var_decl) => self.check(var_decl, Some(export_decl.range())),
+      _ => export_decl.visit_children_with(self),
+    }
+  }
+
+  fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+    self.check(var_decl, None);
+  }
 }
 
 #[cfg(test)]
@@ -76,16 +161,52 @@ mod tests {
       {
         col: 0,
         message: SingleVarDeclaratorMessage::Unexpected,
+        fix: (FIX_DESC, "const a1 = \"a\";\nconst b1 = \"b\";\nconst c1 = \"c\";", LintFixKind::Safe),
       }],
       r#"let a2 = "a", b2 = "b", c2 = "c";"#: [
       {
         col: 0,
         message: SingleVarDeclaratorMessage::Unexpected,
+        fix: (FIX_DESC, "let a2 = \"a\";\nlet b2 = \"b\";\nlet c2 = \"c\";"),
       }],
       r#"var a3 = "a", b3 = "b", c3 = "c";"#: [
       {
         col: 0,
         message: SingleVarDeclaratorMessage::Unexpected,
+        fix: (FIX_DESC, "var a3 = \"a\";\nvar b3 = \"b\";\nvar c3 = \"c\";"),
+      }],
+      "  export let a: number, b = 1": [
+      {
+        col: 9,
+        message: SingleVarDeclaratorMessage::Unexpected,
+        fix: (FIX_DESC, "  export let a: number;\n  export let b = 1"),
+      }],
+      "if (x) { var a, b; }": [
+      {
+        col: 9,
+        message: SingleVarDeclaratorMessage::Unexpected,
+        fix: (FIX_DESC, "if (x) { var a; var b; }"),
+      }],
+      "for (let i = 0, j = 0; i < j; i++) {}": [
+      {
+        col: 5,
+        message: SingleVarDeclaratorMessage::Unexpected,
+      }],
+      "if (x) var a = 1, b = 2;": [
+      {
+        col: 7,
+        message: SingleVarDeclaratorMessage::Unexpected,
+      }],
+      "label: var a = 1, b = 2;": [
+      {
+        col: 7,
+        message: SingleVarDeclaratorMessage::Unexpected,
+      }],
+      "switch (x) { case 0: var a, b; }": [
+      {
+        col: 21,
+        message: SingleVarDeclaratorMessage::Unexpected,
+        fix: (FIX_DESC, "switch (x) { case 0: var a; var b; }"),
       }],
     }
   }
@@ -36,6 +36,10 @@ impl LintRule for TripleSlashReference {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Disallows triple-slash reference directives.")
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
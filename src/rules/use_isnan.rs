@@ -1,9 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
+use crate::handler::Handler;
+use crate::tags;
 use crate::tags::Tags;
-use crate::{tags, Program};
+use deno_ast::view::NodeKind;
 use deno_ast::view::{BinExpr, BinaryOp, Expr, Ident, SwitchStmt};
 use deno_ast::SourceRanged;
 use derive_more::Display;
@@ -12,6 +14,7 @@ use derive_more::Display;
 pub struct UseIsNaN;
 
 const CODE: &str = "use-isnan";
+const FIX_DESC: &str = "Use Number.isNaN";
 
 #[derive(Display)]
 enum UseIsNaNMessage {
@@ -38,12 +41,12 @@ impl LintRule for UseIsNaN {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    UseIsNaNHandler.traverse(program, context);
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `isNaN()` to check for `NaN`.")
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler>> {
+    Some(Box::new(UseIsNaNHandler))
   }
 }
 
@@ -53,7 +56,48 @@ fn is_nan_identifier(ident: &Ident) -> bool {
   *ident.sym() == *"NaN"
 }
 
+/// Reports a comparison of `operand` with `NaN`. Equality checks are fixed
+/// by calling `Number.isNaN`, which changes the result as the comparison is
+/// always false (or always true for inequality).
+fn add_comparison_diagnostic(
+  bin_expr: &BinExpr,
+  operand: &Expr,
+  ctx: &mut Context,
+) {
+  let negate = match bin_expr.op() {
+    BinaryOp::EqEq | BinaryOp::EqEqEq => "",
+    BinaryOp::NotEq | BinaryOp::NotEqEq => "!",
+    _ => {
+      ctx.add_diagnostic(bin_expr.range(), CODE, UseIsNaNMessage::Comparison);
+      return;
+    }
+  };
+  let new_text = format!(
+    "{}Number.isNaN({})",
+    negate,
+    operand.text_fast(ctx.text_info())
+  );
+  ctx.add_diagnostic_with_fixes(
+    bin_expr.range(),
+    CODE,
+    UseIsNaNMessage::Comparison,
+    None,
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      kind: LintFixKind::Unsafe,
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range: bin_expr.range(),
+      }],
+    }],
+  );
+}
+
 impl Handler for UseIsNaNHandler {
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr, NodeKind::SwitchStmt])
+  }
+
   fn bin_expr(&mut self, bin_expr: &BinExpr, ctx: &mut Context) {
     if bin_expr.op() == BinaryOp::EqEq
       || bin_expr.op() == BinaryOp::NotEq
@@ -66,20 +110,12 @@ impl Handler for UseIsNaNHandler {
     {
       if let Expr::Ident(ident) = bin_expr.left {
         if is_nan_identifier(ident) {
-          ctx.add_diagnostic(
-            bin_expr.range(),
-            CODE,
-            UseIsNaNMessage::Comparison,
-          );
+          add_comparison_diagnostic(bin_expr, &bin_expr.right, ctx);
         }
       }
       if let Expr::Ident(ident) = bin_expr.right {
         if is_nan_identifier(ident) {
-          ctx.add_diagnostic(
-            bin_expr.range(),
-            CODE,
-            UseIsNaNMessage::Comparison,
-          );
+          add_comparison_diagnostic(bin_expr, &bin_expr.left, ctx);
         }
       }
     }
@@ -153,6 +189,18 @@ mod tests {
     assert_lint_err! {
       UseIsNaN,
       "42 === NaN": [
+      {
+        col: 0,
+        message: UseIsNaNMessage::Comparison,
+        fix: (FIX_DESC, "Number.isNaN(42)", LintFixKind::Unsafe),
+      }],
+      "if (NaN != a.b) {}": [
+      {
+        col: 4,
+        message: UseIsNaNMessage::Comparison,
+        fix: (FIX_DESC, "if (!Number.isNaN(a.b)) {}"),
+      }],
+      "x < NaN": [
       {
         col: 0,
         message: UseIsNaNMessage::Comparison,
@@ -29,6 +29,10 @@ impl LintRule for ValidTypeof {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `typeof` to be compared with valid type names.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
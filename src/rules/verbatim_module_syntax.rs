@@ -1,7 +1,7 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::diagnostic::{LintFix, LintFixChange};
+use crate::diagnostic::{LintFix, LintFixChange, LintFixKind};
 use crate::tags::{self, Tags};
 use crate::Program;
 use deno_ast::swc::ast::{
@@ -108,6 +108,7 @@ impl VerbatimModuleSyntax {
         Some(Hint::ChangeImportToImportType.to_string()),
         vec![LintFix {
           description: FIX_DESC.into(),
+          kind: LintFixKind::Safe,
           changes,
         }],
       );
@@ -120,6 +121,7 @@ impl VerbatimModuleSyntax {
           Some(Hint::AddTypeKeyword.to_string()),
           vec![LintFix {
             description: FIX_DESC.into(),
+            kind: LintFixKind::Safe,
             changes: vec![LintFixChange {
               new_text: "type ".into(),
               range: specifier.start().range(),
@@ -189,6 +191,7 @@ impl VerbatimModuleSyntax {
         Some(Hint::ChangeExportToExportType.to_string()),
         vec![LintFix {
           description: FIX_DESC.into(),
+          kind: LintFixKind::Safe,
           changes,
         }],
       );
@@ -201,6 +204,7 @@ impl VerbatimModuleSyntax {
           Some(Hint::AddTypeKeyword.to_string()),
           vec![LintFix {
             description: FIX_DESC.into(),
+            kind: LintFixKind::Safe,
             changes: vec![LintFixChange {
               new_text: "type ".into(),
               range: specifier.start().range(),
@@ -221,6 +225,10 @@ impl LintRule for VerbatimModuleSyntax {
     CODE
   }
 
+  fn description(&self) -> Option<&'static str> {
+    Some("Requires `type` imports and exports for names only used as types.")
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -403,7 +411,7 @@ mod tests {
           col: 0,
           message: Message::AllImportIdentsUsedInTypes,
           hint: Hint::ChangeImportToImportType,
-          fix: (FIX_DESC, "import type { Type } from 'module'; type Test = Type;"),
+          fix: (FIX_DESC, "import type { Type } from 'module'; type Test = Type;", LintFixKind::Safe),
         }
       ],
       "import { Type, Other } from 'module'; type Test = Type | Other;": [
@@ -0,0 +1,410 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Serialization of diagnostics as a [SARIF 2.1.0] log, the format read by
+//! code scanning dashboards.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use crate::diagnostic::{rule_docs_url, LintDiagnostic, LintFix, LintSeverity};
+use crate::rules::{get_all_project_rules, get_all_rules, LintRule};
+use deno_ast::{SourceRange, SourceTextInfo};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+pub const SARIF_VERSION: &str = "2.1.0";
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The tool named as the producer of the results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SarifTool {
+  pub name: String,
+  pub version: String,
+  pub information_uri: Option<String>,
+}
+
+impl SarifTool {
+  /// This crate, for tools that don't need to be named themselves.
+  pub fn deno_lint() -> Self {
+    Self {
+      name: "deno_lint".to_string(),
+      version: env!("CARGO_PKG_VERSION").to_string(),
+      information_uri: Some(env!("CARGO_PKG_REPOSITORY").to_string()),
+    }
+  }
+}
+
+/// Creates a SARIF log with a single run containing the diagnostics.
+///
+/// The run describes `rules`, e.g. the ones returned by `get_all_rules`, and
+/// the rules of any diagnostics that aren't among them, like `parse-error`.
+/// Fixes of the diagnostics are included regardless of their kind, which is
+/// stored in the `kind` property of each fix.
+pub fn sarif_log(
+  tool: &SarifTool,
+  rules: &[Box<dyn LintRule>],
+  diagnostics: &[LintDiagnostic],
+) -> Value {
+  let builtin_descriptions = builtin_rule_descriptions();
+  let mut custom_docs_urls: HashMap<&str, &str> = HashMap::new();
+  for diagnostic in diagnostics {
+    if let Some(url) = &diagnostic.details.custom_docs_url {
+      custom_docs_urls
+        .entry(diagnostic.details.code.as_str())
+        .or_insert(url);
+    }
+  }
+  // Only built-in rules are documented at `rule_docs_url`.
+  let help_uri = |code: &str| {
+    custom_docs_urls
+      .get(code)
+      .map(|url| url.to_string())
+      .or_else(|| {
+        builtin_descriptions
+          .contains_key(code)
+          .then(|| rule_docs_url(code))
+      })
+  };
+
+  let mut rule_indexes: HashMap<&str, usize> = HashMap::new();
+  let mut sarif_rules = Vec::new();
+  for rule in rules {
+    if rule_indexes.contains_key(rule.code()) {
+      continue;
+    }
+    rule_indexes.insert(rule.code(), sarif_rules.len());
+    let tags: Vec<_> = rule.tags().iter().map(|tag| tag.display()).collect();
+    let mut sarif_rule =
+      sarif_rule(rule.code(), rule.description(), help_uri(rule.code()));
+    sarif_rule["defaultConfiguration"] = json!({
+      "level": level(rule.default_severity()),
+    });
+    sarif_rule["properties"] = json!({ "tags": tags });
+    sarif_rules.push(sarif_rule);
+  }
+  for diagnostic in diagnostics {
+    let code = diagnostic.details.code.as_str();
+    if rule_indexes.contains_key(code) {
+      continue;
+    }
+    rule_indexes.insert(code, sarif_rules.len());
+    sarif_rules.push(sarif_rule(
+      code,
+      builtin_descriptions.get(code).copied(),
+      help_uri(code),
+    ));
+  }
+
+  let results: Vec<_> = diagnostics
+    .iter()
+    .map(|d| sarif_result(d, rule_indexes[d.details.code.as_str()]))
+    .collect();
+
+  let mut driver = Map::new();
+  driver.insert("name".to_string(), tool.name.clone().into());
+  driver.insert("version".to_string(), tool.version.clone().into());
+  if let Some(information_uri) = &tool.information_uri {
+    driver.insert("informationUri".to_string(), information_uri.clone().into());
+  }
+  driver.insert("rules".to_string(), sarif_rules.into());
+
+  json!({
+    "$schema": SARIF_SCHEMA,
+    "version": SARIF_VERSION,
+    "runs": [{
+      "tool": { "driver": driver },
+      "columnKind": "unicodeCodePoints",
+      "results": results,
+    }],
+  })
+}
+
+/// Descriptions of the built-in rules, by code.
+fn builtin_rule_descriptions() -> HashMap<&'static str, &'static str> {
+  let rules = get_all_rules()
+    .into_iter()
+    .map(|rule| (rule.code(), rule.description()));
+  let project_rules = get_all_project_rules()
+    .into_iter()
+    .map(|rule| (rule.code(), rule.description()));
+  rules
+    .chain(project_rules)
+    .filter_map(|(code, description)| Some((code, description?)))
+    .collect()
+}
+
+fn sarif_rule(
+  code: &str,
+  description: Option<&str>,
+  help_uri: Option<String>,
+) -> Value {
+  let mut sarif_rule = json!({ "id": code });
+  if let Some(description) = description {
+    sarif_rule["shortDescription"] = json!({ "text": description });
+    sarif_rule["fullDescription"] = json!({ "text": description });
+    sarif_rule["help"] = match &help_uri {
+      Some(url) => json!({
+        "text": format!("{}\n\nDocumentation: {}", description, url),
+        "markdown": format!("{}\n\n[Documentation]({})", description, url),
+      }),
+      None => json!({ "text": description }),
+    };
+  }
+  if let Some(url) = help_uri {
+    sarif_rule["helpUri"] = url.into();
+  }
+  sarif_rule
+}
+
+fn sarif_result(diagnostic: &LintDiagnostic, rule_index: usize) -> Value {
+  let details = &diagnostic.details;
+  let mut message = details.message.clone();
+  if let Some(hint) = &details.hint {
+    message.push_str("\n\nhint: ");
+    message.push_str(hint);
+  }
+
+  let mut physical_location = json!({
+    "artifactLocation": { "uri": diagnostic.specifier.as_str() },
+  });
+  if let Some(range) = &diagnostic.range {
+    physical_location["region"] = region(&range.text_info, range.range);
+  }
+
+  let mut result = json!({
+    "ruleId": details.code,
+    "ruleIndex": rule_index,
+    "level": level(details.severity),
+    "message": { "text": message },
+    "locations": [{ "physicalLocation": physical_location }],
+  });
+  // Fixes can only be located in files with a range.
+  if let Some(range) = &diagnostic.range {
+    if !details.fixes.is_empty() {
+      result["fixes"] = details
+        .fixes
+        .iter()
+        .map(|fix| sarif_fix(diagnostic, &range.text_info, fix))
+        .collect::<Vec<_>>()
+        .into();
+    }
+  }
+  result
+}
+
+fn sarif_fix(
+  diagnostic: &LintDiagnostic,
+  text_info: &SourceTextInfo,
+  fix: &LintFix,
+) -> Value {
+  let replacements: Vec<_> = fix
+    .changes
+    .iter()
+    .map(|change| {
+      json!({
+        "deletedRegion": region(text_info, change.range),
+        "insertedContent": { "text": change.new_text },
+      })
+    })
+    .collect();
+  json!({
+    "description": { "text": fix.description },
+    "artifactChanges": [{
+      "artifactLocation": { "uri": diagnostic.specifier.as_str() },
+      "replacements": replacements,
+    }],
+    "properties": { "kind": fix.kind },
+  })
+}
+
+/// Region with 1-based lines and columns, counted in code points.
+fn region(text_info: &SourceTextInfo, range: SourceRange) -> Value {
+  let start = text_info.line_and_column_index(range.start);
+  let end = text_info.line_and_column_index(range.end);
+  json!({
+    "startLine": start.line_index + 1,
+    "startColumn": start.column_index + 1,
+    "endLine": end.line_index + 1,
+    "endColumn": end.column_index + 1,
+  })
+}
+
+fn level(severity: LintSeverity) -> &'static str {
+  match severity {
+    LintSeverity::Off => "none",
+    LintSeverity::Info => "note",
+    LintSeverity::Warning => "warning",
+    LintSeverity::Error => "error",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::diagnostic::PARSE_ERROR_CODE;
+  use crate::rules::no_debugger::NoDebugger;
+  use crate::rules::no_var::NoVar;
+  use crate::test_util::{lint_file, linter};
+  use deno_ast::ModuleSpecifier;
+
+  fn lint(source: &str) -> Vec<LintDiagnostic> {
+    let linter = linter(
+      vec![Box::new(NoVar), Box::new(NoDebugger)],
+      Default::default(),
+    );
+    lint_file(&linter, source, Default::default())
+  }
+
+  #[test]
+  fn sarif_log_results() {
+    let diagnostics = lint("// ü\nconst ü = 1; var a = ü;");
+    let rules: Vec<Box<dyn LintRule>> =
+      vec![Box::new(NoDebugger), Box::new(NoVar)];
+    let log = sarif_log(&SarifTool::deno_lint(), &rules, &diagnostics);
+
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "deno_lint");
+    assert_eq!(
+      run["tool"]["driver"]["rules"],
+      json!([
+        {
+          "id": "no-debugger",
+          "shortDescription": { "text": "Disallows `debugger` statements." },
+          "fullDescription": { "text": "Disallows `debugger` statements." },
+          "help": {
+            "text": "Disallows `debugger` statements.\n\nDocumentation: https://docs.deno.com/lint/rules/no-debugger",
+            "markdown": "Disallows `debugger` statements.\n\n[Documentation](https://docs.deno.com/lint/rules/no-debugger)",
+          },
+          "helpUri": "https://docs.deno.com/lint/rules/no-debugger",
+          "defaultConfiguration": { "level": "error" },
+          "properties": { "tags": ["recommended"] },
+        },
+        {
+          "id": "no-var",
+          "shortDescription": { "text": "Requires `let` or `const` instead of `var`." },
+          "fullDescription": { "text": "Requires `let` or `const` instead of `var`." },
+          "help": {
+            "text": "Requires `let` or `const` instead of `var`.\n\nDocumentation: https://docs.deno.com/lint/rules/no-var",
+            "markdown": "Requires `let` or `const` instead of `var`.\n\n[Documentation](https://docs.deno.com/lint/rules/no-var)",
+          },
+          "helpUri": "https://docs.deno.com/lint/rules/no-var",
+          "defaultConfiguration": { "level": "error" },
+          "properties": { "tags": ["recommended"] },
+        },
+      ])
+    );
+    let region = json!({
+      "startLine": 2,
+      "startColumn": 14,
+      "endLine": 2,
+      "endColumn": 17,
+    });
+    assert_eq!(
+      run["results"],
+      json!([{
+        "ruleId": "no-var",
+        "ruleIndex": 1,
+        "level": "error",
+        "message": { "text": "`var` keyword is not allowed." },
+        "locations": [{
+          "physicalLocation": {
+            "artifactLocation": { "uri": "file:///lint_test.ts" },
+            "region": region,
+          },
+        }],
+        "fixes": [{
+          "description": { "text": "Replace `var` with `let`" },
+          "artifactChanges": [{
+            "artifactLocation": { "uri": "file:///lint_test.ts" },
+            "replacements": [{
+              "deletedRegion": region,
+              "insertedContent": { "text": "let" },
+            }],
+          }],
+          "properties": { "kind": "unsafe" },
+        }],
+      }])
+    );
+  }
+
+  #[test]
+  fn sarif_log_unknown_rules() {
+    let mut diagnostics = lint("debugger;");
+    diagnostics[0].details.hint = Some("Remove it".to_string());
+    diagnostics[0].details.severity = LintSeverity::Warning;
+    let mut parse_error = diagnostics[0].clone();
+    parse_error.specifier = ModuleSpecifier::parse("file:///b.ts").unwrap();
+    parse_error.range = None;
+    parse_error.details.code = PARSE_ERROR_CODE.to_string();
+    parse_error.details.fixes = vec![];
+    diagnostics.push(parse_error);
+    let log = sarif_log(&SarifTool::deno_lint(), &[], &diagnostics);
+
+    let run = &log["runs"][0];
+    assert_eq!(
+      run["tool"]["driver"]["rules"],
+      json!([
+        {
+          "id": "no-debugger",
+          "shortDescription": { "text": "Disallows `debugger` statements." },
+          "fullDescription": { "text": "Disallows `debugger` statements." },
+          "help": {
+            "text": "Disallows `debugger` statements.\n\nDocumentation: https://docs.deno.com/lint/rules/no-debugger",
+            "markdown": "Disallows `debugger` statements.\n\n[Documentation](https://docs.deno.com/lint/rules/no-debugger)",
+          },
+          "helpUri": "https://docs.deno.com/lint/rules/no-debugger",
+        },
+        { "id": "parse-error" },
+      ])
+    );
+    assert_eq!(run["results"][0]["level"], "warning");
+    assert_eq!(
+      run["results"][0]["message"]["text"],
+      "`debugger` statement is not allowed\n\nhint: Remove it"
+    );
+    assert_eq!(run["results"][1]["ruleIndex"], 1);
+    assert_eq!(
+      run["results"][1]["locations"],
+      json!([{
+        "physicalLocation": {
+          "artifactLocation": { "uri": "file:///b.ts" },
+        },
+      }])
+    );
+  }
+
+  #[test]
+  fn sarif_log_other_rules() {
+    #[derive(Debug)]
+    struct PluginRule;
+
+    impl LintRule for PluginRule {
+      fn code(&self) -> &'static str {
+        "my-plugin/no-foo"
+      }
+    }
+
+    let mut diagnostics = lint("debugger;");
+    diagnostics[0].details.code = "my-plugin/no-bar".to_string();
+    diagnostics[0].details.custom_docs_url =
+      Some("https://example.com/no-bar".to_string());
+    let rules: Vec<Box<dyn LintRule>> = vec![Box::new(PluginRule)];
+    let log = sarif_log(&SarifTool::deno_lint(), &rules, &diagnostics);
+
+    // Only built-in rules link to the docs of deno_lint.
+    assert_eq!(
+      log["runs"][0]["tool"]["driver"]["rules"],
+      json!([
+        {
+          "id": "my-plugin/no-foo",
+          "defaultConfiguration": { "level": "error" },
+          "properties": { "tags": [] },
+        },
+        {
+          "id": "my-plugin/no-bar",
+          "helpUri": "https://example.com/no-bar",
+        },
+      ])
+    );
+  }
+}
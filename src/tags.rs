@@ -26,3 +26,175 @@ pub const REACT: Tag = Tag("react");
 pub const JSX: Tag = Tag("jsx");
 
 pub const ALL_TAGS: Tags = &[RECOMMENDED, FRESH, JSR, REACT, JSX];
+
+/// Expression selecting rules by their tags, e.g. `react & !fresh` or
+/// `recommended | jsr`.
+///
+/// `!` binds tighter than `&`, which binds tighter than `|`, and parentheses
+/// can be used for grouping. A single tag name is also an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagExpr {
+  Tag(&'static str),
+  Not(Box<TagExpr>),
+  And(Box<TagExpr>, Box<TagExpr>),
+  Or(Box<TagExpr>, Box<TagExpr>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum TagExprError {
+  #[display(fmt = "Unknown tag \"{}\"", _0)]
+  UnknownTag(String),
+  #[display(fmt = "Invalid tag expression \"{}\": {}", expr, message)]
+  Syntax { expr: String, message: String },
+}
+
+impl std::error::Error for TagExprError {}
+
+impl TagExpr {
+  pub fn parse(expr: &str) -> Result<Self, TagExprError> {
+    let mut parser = TagExprParser {
+      expr,
+      tokens: tokenize(expr),
+      pos: 0,
+    };
+    let parsed = parser.parse_or()?;
+    match parser.tokens.get(parser.pos) {
+      None => Ok(parsed),
+      Some(token) => Err(parser.error(format!("unexpected \"{}\"", token))),
+    }
+  }
+
+  /// Whether a rule with the tags is selected by the expression.
+  pub fn matches(&self, tags: Tags) -> bool {
+    match self {
+      TagExpr::Tag(name) => tags.iter().any(|tag| tag.display() == *name),
+      TagExpr::Not(expr) => !expr.matches(tags),
+      TagExpr::And(left, right) => left.matches(tags) && right.matches(tags),
+      TagExpr::Or(left, right) => left.matches(tags) || right.matches(tags),
+    }
+  }
+}
+
+fn tokenize(expr: &str) -> Vec<&str> {
+  let mut tokens = Vec::new();
+  let mut rest = expr.trim_start();
+  while let Some(c) = rest.chars().next() {
+    let len = if matches!(c, '&' | '|' | '!' | '(' | ')') {
+      1
+    } else {
+      rest
+        .find(|c: char| c.is_whitespace() || "&|!()".contains(c))
+        .unwrap_or(rest.len())
+    };
+    tokens.push(&rest[..len]);
+    rest = rest[len..].trim_start();
+  }
+  tokens
+}
+
+struct TagExprParser<'a> {
+  expr: &'a str,
+  tokens: Vec<&'a str>,
+  pos: usize,
+}
+
+impl<'a> TagExprParser<'a> {
+  fn error(&self, message: String) -> TagExprError {
+    TagExprError::Syntax {
+      expr: self.expr.to_string(),
+      message,
+    }
+  }
+
+  fn eat(&mut self, token: &str) -> bool {
+    if self.tokens.get(self.pos) == Some(&token) {
+      self.pos += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn parse_or(&mut self) -> Result<TagExpr, TagExprError> {
+    let mut expr = self.parse_and()?;
+    while self.eat("|") {
+      expr = TagExpr::Or(Box::new(expr), Box::new(self.parse_and()?));
+    }
+    Ok(expr)
+  }
+
+  fn parse_and(&mut self) -> Result<TagExpr, TagExprError> {
+    let mut expr = self.parse_unary()?;
+    while self.eat("&") {
+      expr = TagExpr::And(Box::new(expr), Box::new(self.parse_unary()?));
+    }
+    Ok(expr)
+  }
+
+  fn parse_unary(&mut self) -> Result<TagExpr, TagExprError> {
+    if self.eat("!") {
+      return Ok(TagExpr::Not(Box::new(self.parse_unary()?)));
+    }
+    if self.eat("(") {
+      let expr = self.parse_or()?;
+      if !self.eat(")") {
+        return Err(self.error("missing \")\"".to_string()));
+      }
+      return Ok(expr);
+    }
+    match self.tokens.get(self.pos) {
+      Some(token) if !matches!(*token, "&" | "|" | ")") => {
+        self.pos += 1;
+        ALL_TAGS
+          .iter()
+          .find(|tag| tag.display() == *token)
+          .map(|tag| TagExpr::Tag(tag.display()))
+          .ok_or_else(|| TagExprError::UnknownTag(token.to_string()))
+      }
+      Some(token) => Err(self.error(format!("unexpected \"{}\"", token))),
+      None => Err(self.error("expected a tag".to_string())),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn tag_expr() {
+    let expr = TagExpr::parse("react & !fresh").unwrap();
+    assert!(expr.matches(&[REACT, JSX]));
+    assert!(!expr.matches(&[REACT, JSX, FRESH]));
+    assert!(!expr.matches(&[RECOMMENDED]));
+
+    // `&` binds tighter than `|`.
+    let expr = TagExpr::parse("jsr | react & fresh").unwrap();
+    assert!(expr.matches(&[JSR]));
+    assert!(!expr.matches(&[REACT]));
+    let expr = TagExpr::parse("(jsr | react) & fresh").unwrap();
+    assert!(!expr.matches(&[JSR]));
+    assert!(expr.matches(&[REACT, FRESH]));
+
+    assert_eq!(
+      TagExpr::parse("recommended").unwrap(),
+      TagExpr::Tag("recommended")
+    );
+    assert_eq!(
+      TagExpr::parse("react & !strict"),
+      Err(TagExprError::UnknownTag("strict".to_string()))
+    );
+    assert_eq!(
+      TagExpr::parse("react &").unwrap_err().to_string(),
+      "Invalid tag expression \"react &\": expected a tag"
+    );
+    assert_eq!(
+      TagExpr::parse("(react | jsx").unwrap_err().to_string(),
+      "Invalid tag expression \"(react | jsx\": missing \")\""
+    );
+    assert_eq!(
+      TagExpr::parse("react jsx").unwrap_err().to_string(),
+      "Invalid tag expression \"react jsx\": unexpected \"jsx\""
+    );
+  }
+}
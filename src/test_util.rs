@@ -2,12 +2,14 @@
 
 use crate::ast_parser;
 use crate::diagnostic::LintDiagnostic;
+use crate::diagnostic::LintFixKind;
+use crate::globals::Globals;
 use crate::linter::LintConfig;
 use crate::linter::LintFileOptions;
 use crate::linter::Linter;
 use crate::linter::LinterOptions;
-use crate::rules::get_all_rules;
 use crate::rules::LintRule;
+use crate::rules::RuleRegistry;
 use deno_ast::diagnostics::Diagnostic;
 use deno_ast::view as ast_view;
 use deno_ast::MediaType;
@@ -249,6 +251,30 @@ impl LintErrTester {
 pub struct LintErrFix {
   pub description: String,
   pub fixed_code: String,
+  /// Only checked if it's given, e.g. `fix: (description, code, kind)`.
+  pub kind: Option<LintFixKind>,
+}
+
+impl From<(&'static str, &'static str)> for LintErrFix {
+  fn from((description, fixed_code): (&'static str, &'static str)) -> Self {
+    Self {
+      description: description.to_string(),
+      fixed_code: fixed_code.to_string(),
+      kind: None,
+    }
+  }
+}
+
+impl From<(&'static str, &'static str, LintFixKind)> for LintErrFix {
+  fn from(
+    (description, fixed_code, kind): (&'static str, &'static str, LintFixKind),
+  ) -> Self {
+    Self {
+      description: description.to_string(),
+      fixed_code: fixed_code.to_string(),
+      kind: Some(kind),
+    }
+  }
 }
 
 #[derive(Default)]
@@ -296,11 +322,8 @@ impl LintErrBuilder {
     self
   }
 
-  pub fn fix(&mut self, value: (&'static str, &'static str)) -> &mut Self {
-    self.fixes.push(LintErrFix {
-      description: value.0.to_string(),
-      fixed_code: value.1.to_string(),
-    });
+  pub fn fix(&mut self, value: impl Into<LintErrFix>) -> &mut Self {
+    self.fixes.push(value.into());
     self
   }
 
@@ -323,12 +346,10 @@ fn lint(
 ) -> (ParsedSource, Vec<LintDiagnostic>) {
   let linter = Linter::new(LinterOptions {
     rules: vec![rule],
-    all_rule_codes: get_all_rules()
-      .into_iter()
-      .map(|rule| rule.code())
-      .collect(),
+    all_rule_codes: RuleRegistry::new().all_rule_codes(),
     custom_ignore_diagnostic_directive: None,
     custom_ignore_file_directive: None,
+    ..Default::default()
   });
 
   let specifier = ModuleSpecifier::parse(specifier).unwrap();
@@ -340,6 +361,7 @@ fn lint(
     config: LintConfig {
       default_jsx_factory: Some("React.createElement".to_owned()),
       default_jsx_fragment_factory: Some("React.Fragment".to_owned()),
+      globals: Default::default(),
     },
   });
   match lint_result {
@@ -351,6 +373,39 @@ fn lint(
   }
 }
 
+/// Creates a linter running `rules`, with the rest of `options`.
+pub fn linter(rules: Vec<Box<dyn LintRule>>, options: LinterOptions) -> Linter {
+  Linter::new(LinterOptions { rules, ..options })
+}
+
+/// Options to lint `source` as the TypeScript file `specifier`, with the
+/// default configuration.
+pub fn lint_file_options(specifier: &str, source: &str) -> LintFileOptions {
+  LintFileOptions {
+    specifier: ModuleSpecifier::parse(specifier).unwrap(),
+    source_code: source.to_string(),
+    media_type: MediaType::TypeScript,
+    config: LintConfig {
+      default_jsx_factory: None,
+      default_jsx_fragment_factory: None,
+      globals: Default::default(),
+    },
+  }
+}
+
+/// Lints `source` as `file:///lint_test.ts` with `globals`.
+#[track_caller]
+pub fn lint_file(
+  linter: &Linter,
+  source: &str,
+  globals: Globals,
+) -> Vec<LintDiagnostic> {
+  let mut options = lint_file_options("file:///lint_test.ts", source);
+  options.config.globals = globals;
+  let (_, diagnostics) = linter.lint_file(options).expect("Failed to lint");
+  diagnostics
+}
+
 pub fn assert_diagnostic(
   diagnostic: &LintDiagnostic,
   code: &str,
@@ -433,7 +488,8 @@ fn assert_diagnostic_2(
     .details
     .fixes
     .iter()
-    .map(|fix| LintErrFix {
+    .enumerate()
+    .map(|(i, fix)| LintErrFix {
       description: fix.description.to_string(),
       fixed_code: deno_ast::apply_text_changes(
         text_info.text_str(),
@@ -446,6 +502,10 @@ fn assert_diagnostic_2(
           })
           .collect(),
       ),
+      kind: fixes
+        .get(i)
+        .and_then(|expected| expected.kind)
+        .map(|_| fix.kind),
     })
     .collect::<Vec<_>>();
   assert_eq!(actual_fixes, fixes, "Quick fixes did not match.");
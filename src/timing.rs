@@ -0,0 +1,163 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+/// Name of the phase in which files are parsed.
+pub const PARSE_PHASE: &str = "parse";
+/// Name of the phase in which scope analysis is computed.
+pub const SCOPE_PHASE: &str = "scope";
+/// Name of the phase in which control-flow analysis is computed.
+pub const CONTROL_FLOW_PHASE: &str = "control-flow";
+/// Name of the phase in which ignore directives are parsed and applied.
+pub const IGNORE_DIRECTIVES_PHASE: &str = "ignore-directives";
+/// Name of the phase in which all the rules are run.
+pub const RULES_PHASE: &str = "rules";
+
+/// Time spent in each phase of linting and in each of the rules, aggregated
+/// over all the files linted since profiling was enabled with
+/// `Linter::enable_timing`.
+///
+/// The time of a rule doesn't include scope or control-flow analysis, even
+/// when the rule is the first one to use it.
+#[derive(Debug, Clone, Default)]
+pub struct TimingReport {
+  files: usize,
+  phases: HashMap<&'static str, Duration>,
+  rules: HashMap<&'static str, Duration>,
+}
+
+impl TimingReport {
+  /// Number of files linted.
+  pub fn files(&self) -> usize {
+    self.files
+  }
+
+  /// Time spent in each phase, slowest first.
+  pub fn phases(&self) -> Vec<(&'static str, Duration)> {
+    sorted_by_duration(&self.phases)
+  }
+
+  /// Time spent in each rule, slowest first.
+  pub fn rules(&self) -> Vec<(&'static str, Duration)> {
+    sorted_by_duration(&self.rules)
+  }
+
+  /// Adds the times of `other`, e.g. of another `Linter`.
+  pub fn merge(&mut self, other: &TimingReport) {
+    self.files += other.files;
+    for (phase, duration) in &other.phases {
+      self.add_phase(phase, *duration);
+    }
+    for (code, duration) in &other.rules {
+      self.add_rule(code, *duration);
+    }
+  }
+
+  pub(crate) fn add_file(&mut self) {
+    self.files += 1;
+  }
+
+  pub(crate) fn add_phase(&mut self, phase: &'static str, duration: Duration) {
+    *self.phases.entry(phase).or_default() += duration;
+  }
+
+  pub(crate) fn add_rule(&mut self, code: &'static str, duration: Duration) {
+    *self.rules.entry(code).or_default() += duration;
+  }
+}
+
+fn sorted_by_duration(
+  durations: &HashMap<&'static str, Duration>,
+) -> Vec<(&'static str, Duration)> {
+  let mut durations = durations
+    .iter()
+    .map(|(name, duration)| (*name, *duration))
+    .collect::<Vec<_>>();
+  durations.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+  durations
+}
+
+/// Prints tables of phases and rules similar to ESLint's `TIMING` output.
+impl fmt::Display for TimingReport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fn table(
+      f: &mut fmt::Formatter<'_>,
+      title: &str,
+      rows: Vec<(&'static str, Duration)>,
+    ) -> fmt::Result {
+      let total = rows.iter().map(|(_, d)| d.as_secs_f64()).sum::<f64>();
+      let width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(title.len());
+      writeln!(
+        f,
+        "{:<width$} | {:>10} | {:>8}",
+        title, "Time (ms)", "Relative"
+      )?;
+      writeln!(f, "{:-<width$}-|-{:->10}-|-{:->8}", "", "", "")?;
+      for (name, duration) in rows {
+        let relative = if total > 0.0 {
+          duration.as_secs_f64() / total * 100.0
+        } else {
+          0.0
+        };
+        writeln!(
+          f,
+          "{:<width$} | {:>10.3} | {:>7.1}%",
+          name,
+          duration.as_secs_f64() * 1000.0,
+          relative
+        )?;
+      }
+      Ok(())
+    }
+
+    writeln!(f, "Files: {}", self.files)?;
+    writeln!(f)?;
+    table(f, "Phase", self.phases())?;
+    writeln!(f)?;
+    table(f, "Rule", self.rules())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn timing_report() {
+    let mut report = TimingReport::default();
+    report.add_file();
+    report.add_phase(PARSE_PHASE, Duration::from_millis(3));
+    report.add_rule("no-debugger", Duration::from_millis(1));
+    report.add_rule("no-undef", Duration::from_millis(2));
+    report.add_rule("no-debugger", Duration::from_millis(2));
+
+    assert_eq!(
+      report.rules(),
+      vec![
+        ("no-debugger", Duration::from_millis(3)),
+        ("no-undef", Duration::from_millis(2)),
+      ]
+    );
+    assert_eq!(
+      report.to_string(),
+      r#"Files: 1
+
+Phase |  Time (ms) | Relative
+------|------------|---------
+parse |      3.000 |   100.0%
+
+Rule        |  Time (ms) | Relative
+------------|------------|---------
+no-debugger |      3.000 |    60.0%
+no-undef    |      2.000 |    40.0%
+"#
+    );
+  }
+}